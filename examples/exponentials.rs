@@ -1,4 +1,5 @@
-use finite_field::FieldElement;
+use finite_field::{FieldElement, FieldElementBig};
+use crypto_bigint::U256;
 
 fn main () {
     // negative exponents for signed bases.
@@ -20,7 +21,11 @@ fn main () {
     let one: FieldElement<u16> = FieldElement::new(1, 31);
     // exp = a^(-5)
     let exp = one / a.pow(5);
-    assert_eq!(exp, one / (a * a * a * a * a)) 
+    assert_eq!(exp, one / (a * a * a * a * a));
 
+    // negative exponents for big integers.
+    let a = FieldElementBig::new(U256::from(15u8), U256::from(31u8));
+    let one = FieldElementBig::new(U256::ONE, U256::from(31u8));
+    assert_eq!(a.pow_i64(-5), one / a.pow(U256::from(5u8)));
     
 }
\ No newline at end of file
@@ -0,0 +1,223 @@
+use std::{fmt, ops::{Add, Div, Mul, Neg, Sub}};
+use crate::{FieldElement, FieldError, FiniteField};
+
+/// Barrett reduction for one machine-word modulus: `⌊(2¹²⁸ − 1) / p⌋` is
+/// computed once, after which reducing a product takes two wide
+/// multiplications and a few subtractions instead of a hardware `%`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BarrettContext {
+    order: u64,
+    mu: u128,
+}
+
+/// An element minted by a [`BarrettContext`], an alternative backend for
+/// `FieldElement<u64>` workloads with a fixed modulus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BarrettElement {
+    n: u64,
+    context: BarrettContext,
+}
+
+/// The high half of the 256-bit product `a * b`.
+fn mul_hi(a: u128, b: u128) -> u128 {
+    let (a_lo, a_hi) = (a as u64 as u128, a >> 64);
+    let (b_lo, b_hi) = (b as u64 as u128, b >> 64);
+    let lo_hi = a_lo * b_hi;
+    let hi_lo = a_hi * b_lo;
+    let cross = ((a_lo * b_lo) >> 64) + (lo_hi as u64 as u128) + (hi_lo as u64 as u128);
+    a_hi * b_hi + (lo_hi >> 64) + (hi_lo >> 64) + (cross >> 64)
+}
+
+impl BarrettContext {
+    pub fn new(order: u64) -> Result<Self, FieldError> {
+        if order == 0 {
+            return Err(FieldError::ZeroModulus);
+        }
+        Ok(BarrettContext { order, mu: u128::MAX / order as u128 })
+    }
+
+    pub fn order(&self) -> u64 {
+        self.order
+    }
+
+    /// Returns `x mod order` for any `x`. The quotient estimate
+    /// `⌊x·μ / 2¹²⁸⌋` falls short by at most three, so a few conditional
+    /// subtractions finish the job.
+    pub fn reduce(&self, x: u128) -> u64 {
+        let p = self.order as u128;
+        let mut r = x - mul_hi(x, self.mu) * p;
+        while r >= p {
+            r -= p;
+        }
+        r as u64
+    }
+
+    /// Reduces `n` with [`reduce`](Self::reduce).
+    pub fn element(&self, n: u64) -> BarrettElement {
+        BarrettElement { n: self.reduce(n as u128), context: *self }
+    }
+
+    pub fn zero(&self) -> BarrettElement {
+        self.element(0)
+    }
+
+    pub fn one(&self) -> BarrettElement {
+        self.element(1)
+    }
+
+    /// Wraps the representative of an existing element, which is already
+    /// reduced, failing if its order differs.
+    pub fn import(&self, e: &FieldElement<u64>) -> Result<BarrettElement, FieldError> {
+        if e.get_order() != self.order {
+            return Err(FieldError::MismatchedOrders);
+        }
+        Ok(BarrettElement { n: e.get_num(), context: *self })
+    }
+}
+
+impl BarrettElement {
+    pub fn get_num(&self) -> u64 {
+        self.n
+    }
+
+    pub fn get_order(&self) -> u64 {
+        self.context.order
+    }
+
+    pub fn context(&self) -> BarrettContext {
+        self.context
+    }
+
+    pub fn pow(self, mut exp: u64) -> Self {
+        let mut result = self.context.one();
+        let mut base = self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base;
+            }
+            base = base.square();
+            exp >>= 1;
+        }
+        result
+    }
+
+    pub fn square(self) -> Self {
+        self * self
+    }
+
+    /// Returns the inverse by Fermat's little theorem, or `None` for zero.
+    pub fn inverse(&self) -> Option<Self> {
+        if self.n == 0 {
+            None
+        } else {
+            Some(self.pow(self.get_order() - 2))
+        }
+    }
+
+    pub fn to_field_element(&self) -> FieldElement<u64> {
+        FieldElement::new(self.n, self.get_order())
+    }
+}
+
+impl Add for BarrettElement {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        assert_eq!(self.context, rhs.context);
+        let p = self.get_order();
+        let n = if p - self.n > rhs.n { self.n + rhs.n } else { rhs.n - (p - self.n) };
+        BarrettElement { n, context: self.context }
+    }
+}
+
+impl Sub for BarrettElement {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        assert_eq!(self.context, rhs.context);
+        let n = if self.n >= rhs.n { self.n - rhs.n } else { self.get_order() - (rhs.n - self.n) };
+        BarrettElement { n, context: self.context }
+    }
+}
+
+impl Mul for BarrettElement {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        assert_eq!(self.context, rhs.context);
+        BarrettElement { n: self.context.reduce(self.n as u128 * rhs.n as u128), context: self.context }
+    }
+}
+
+impl Div for BarrettElement {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        self.mul(rhs.inverse().expect("Zero is not valid denominator."))
+    }
+}
+
+impl Neg for BarrettElement {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.context.zero() - self
+    }
+}
+
+impl FiniteField for BarrettElement {
+    fn zero(&self) -> Self {
+        self.context.zero()
+    }
+
+    fn one(&self) -> Self {
+        self.context.one()
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        BarrettElement::inverse(self)
+    }
+
+    fn from_u64(&self, n: u64) -> Self {
+        self.context.element(n)
+    }
+}
+
+impl fmt::Display for BarrettElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (mod {})", self.n, self.get_order())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::check_arithmetic;
+
+    #[test]
+    fn reduce_matches_rem() {
+        for p in [1u64, 2, 7, 1 << 32, 0xffffffff00000001, 18446744073709551557, u64::MAX] {
+            let ctx = BarrettContext::new(p).unwrap();
+            for x in [0u128, 1, p as u128 - 1, p as u128, (p as u128 - 1).pow(2), u128::MAX, 0xdeadbeef_cafebabe_12345678_9abcdef0] {
+                assert_eq!(ctx.reduce(x) as u128, x % p as u128, "{} mod {}", x, p);
+            }
+        }
+        assert_eq!(BarrettContext::new(0), Err(FieldError::ZeroModulus));
+    }
+
+    #[test]
+    fn arithmetic_matches_field_element() {
+        // the largest 64-bit prime, so products come close to 2¹²⁸
+        let p = 18446744073709551557u64;
+        let ctx = BarrettContext::new(p).unwrap();
+        for (a, b) in [(p - 1, p - 2), (u64::MAX, 0xcafebabe), (1 << 32, 1 << 32)] {
+            check_arithmetic(ctx.element(a), ctx.element(b), |e| e.to_field_element());
+        }
+        let x = ctx.element(p - 2);
+        assert_eq!(x.pow(p - 1), ctx.one());
+        assert_eq!(ctx.import(&x.to_field_element()), Ok(x));
+        assert_eq!(ctx.import(&FieldElement::new(1, 7)), Err(FieldError::MismatchedOrders));
+        assert_eq!(x.to_string(), format!("{} (mod {})", p - 2, p));
+    }
+}
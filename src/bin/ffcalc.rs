@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+use finite_field::FieldElement;
+use finite_field::eval::{self, parse};
+use finite_field::utils::discrete_log;
+use primes::is_prime;
+
+const HELP: &str = "\
+commands:
+  mod <p>             set the prime modulus (below 2^32), clearing variables
+  <name> = <expr>     bind a variable
+  <expr>              evaluate, e.g. 3*x^2 + 5/y - 7
+  inv <expr>          multiplicative inverse
+  sqrt <expr>         square roots
+  log <base>, <expr>  discrete logarithm of <expr> to <base>
+  vars                list bound variables
+  help                show this message
+  quit                exit";
+
+struct Calculator {
+    order: u64,
+    vars: HashMap<String, FieldElement<u64>>,
+}
+
+impl Calculator {
+    fn eval(&self, input: &str) -> Result<FieldElement<u64>, String> {
+        eval::eval(input, self.order, &self.vars).map_err(|e| e.to_string())
+    }
+
+    fn sqrt(&self, a: FieldElement<u64>) -> Vec<FieldElement<u64>> {
+        match a.sqrt() {
+            Some(root) => {
+                let mut roots = vec![root, root.zero() - root];
+                roots.sort_by_key(|r| r.get_num());
+                roots.dedup();
+                roots
+            }
+            None => Vec::new(),
+        }
+    }
+
+    fn run(&mut self, line: &str) -> Result<String, String> {
+        let (command, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        match command {
+            "help" => Ok(HELP.to_string()),
+            "vars" => {
+                let mut names: Vec<_> = self.vars.iter()
+                    .map(|(k, v)| format!("{} = {}", k, v.get_num()))
+                    .collect();
+                names.sort();
+                if names.is_empty() {
+                    return Ok("no variables bound".to_string());
+                }
+                Ok(names.join("\n"))
+            }
+            "mod" => {
+                let p: u64 = rest.trim().parse().map_err(|_| format!("invalid modulus '{}'", rest.trim()))?;
+                if p >= 1 << 32 || !is_prime(p) {
+                    return Err(format!("{} is not a prime below 2^32", p));
+                }
+                self.order = p;
+                self.vars.clear();
+                Ok(format!("working in GF({})", p))
+            }
+            "inv" => {
+                let a = self.eval(rest)?;
+                if a == a.zero() {
+                    return Err("zero has no inverse".to_string());
+                }
+                Ok((a.one() / a).get_num().to_string())
+            }
+            "sqrt" => {
+                let roots = self.sqrt(self.eval(rest)?);
+                if roots.is_empty() {
+                    return Err("not a quadratic residue".to_string());
+                }
+                let roots: Vec<_> = roots.iter().map(|r| r.get_num().to_string()).collect();
+                Ok(roots.join(", "))
+            }
+            "log" => {
+                let (base, value) = rest.split_once(',').ok_or("usage: log <base>, <expr>")?;
+                let base = self.eval(base)?;
+                let value = self.eval(value)?;
+                discrete_log(base, value)
+                    .map(|k| k.to_string())
+                    .ok_or_else(|| "no solution".to_string())
+            }
+            _ => match line.split_once('=') {
+                Some((name, expr)) => {
+                    let name = name.trim();
+                    match parse(name) {
+                        Ok(eval::Expr::Var(_)) => {}
+                        _ => return Err(format!("invalid variable name '{}'", name)),
+                    }
+                    let value = self.eval(expr)?;
+                    self.vars.insert(name.to_string(), value);
+                    Ok(format!("{} = {}", name, value.get_num()))
+                }
+                None => self.eval(line).map(|v| v.get_num().to_string()),
+            },
+        }
+    }
+}
+
+fn main() {
+    let mut calc = Calculator {
+        order: 17,
+        vars: HashMap::new(),
+    };
+    println!("ffcalc: finite field calculator, working in GF(17). Type 'help' for commands.");
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("GF({})> ", calc.order);
+        io::stdout().flush().unwrap();
+        let line = match lines.next() {
+            Some(Ok(line)) => line,
+            _ => break,
+        };
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if line == "quit" || line == "exit" {
+            break;
+        }
+        match calc.run(line) {
+            Ok(out) => println!("{}", out),
+            Err(err) => println!("error: {}", err),
+        }
+    }
+}
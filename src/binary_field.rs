@@ -0,0 +1,252 @@
+use std::{fmt, ops::{Add, Div, Mul, Neg, Sub}};
+use crate::{FieldError, FiniteField};
+
+/// An element of `GF(2^M) = GF(2)[x]/(f)` for an irreducible `f` of degree
+/// `M ≤ 64`, as the bits of its coefficients, lowest degree first.
+///
+/// Like the order of a [`FieldElement`](crate::FieldElement), the reduction
+/// polynomial is carried by each element and must agree between operands.
+/// It is given in full, `x^M` included, so `0x11b` is the AES polynomial.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub struct BinaryFieldElement<const M: usize> {
+    n: u64,
+    poly: u128,
+}
+
+/// Multiplies two polynomials over `GF(2)` without reducing, in constant
+/// time.
+pub(crate) fn clmul(a: u64, b: u64) -> u128 {
+    let a = a as u128;
+    (0..64).fold(0, |acc, i| acc ^ ((a << i) & ((b >> i & 1) as u128).wrapping_neg()))
+}
+
+/// Reduces a polynomial of degree below `2M` modulo `poly` of degree `M`.
+fn reduce<const M: usize>(mut x: u128, poly: u128) -> u64 {
+    for i in (M..2 * M).rev() {
+        if x >> i & 1 == 1 {
+            x ^= poly << (i - M);
+        }
+    }
+    x as u64
+}
+
+fn mul_mod<const M: usize>(a: u64, b: u64, poly: u128) -> u64 {
+    reduce::<M>(clmul(a, b), poly)
+}
+
+/// Rabin's test: `f` of degree `M` is irreducible if and only if
+/// `x^(2^M) ≡ x mod f` and `gcd(x^(2^(M/q)) − x, f) = 1` for every prime
+/// `q` dividing `M`.
+fn is_irreducible<const M: usize>(poly: u128) -> bool {
+    if M == 1 {
+        return true;
+    }
+    let frobenius = |k: usize| (0..k).fold(2, |x, _| mul_mod::<M>(x, x, poly));
+    if frobenius(M) != 2 {
+        return false;
+    }
+    primes::factors_uniq(M as u64).into_iter().all(|q| {
+        gcd(frobenius(M / q as usize) ^ 2, poly) == 1
+    })
+}
+
+fn gcd(a: u64, b: u128) -> u128 {
+    let (mut a, mut b) = (b, a as u128);
+    while b != 0 {
+        while a != 0 && a.leading_zeros() <= b.leading_zeros() {
+            a ^= b << (b.leading_zeros() - a.leading_zeros());
+        }
+        std::mem::swap(&mut a, &mut b);
+    }
+    a
+}
+
+impl<const M: usize> BinaryFieldElement<M> {
+    /// Panics unless `poly` has degree `M` and `n` has degree below `M`.
+    /// Irreducibility is not checked; use `try_new` for that.
+    pub fn new(n: u64, poly: u128) -> Self {
+        assert!((1..=64).contains(&M));
+        assert_eq!(128 - poly.leading_zeros() as usize, M + 1);
+        assert!(M == 64 || n >> M == 0);
+        BinaryFieldElement { n, poly }
+    }
+
+    /// Like `new`, but returns an error instead of panicking, and rejects
+    /// reducible polynomials.
+    pub fn try_new(n: u64, poly: u128) -> Result<Self, FieldError> {
+        if poly == 0 {
+            return Err(FieldError::ZeroModulus);
+        }
+        if !(1..=64).contains(&M) || 128 - poly.leading_zeros() as usize != M + 1 || !is_irreducible::<M>(poly) {
+            return Err(FieldError::ReducibleModulus);
+        }
+        if M < 64 && n >> M != 0 {
+            return Err(FieldError::OutOfRange);
+        }
+        Ok(BinaryFieldElement { n, poly })
+    }
+
+    pub fn get_num(&self) -> u64 {
+        self.n
+    }
+
+    pub fn get_poly(&self) -> u128 {
+        self.poly
+    }
+
+    pub fn zero(self) -> Self {
+        BinaryFieldElement { n: 0, poly: self.poly }
+    }
+
+    pub fn one(self) -> Self {
+        BinaryFieldElement { n: 1, poly: self.poly }
+    }
+
+    pub fn pow(self, mut exp: u64) -> Self {
+        let mut result = self.one();
+        let mut base = self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    pub fn square(self) -> Self {
+        self * self
+    }
+
+    /// Returns `a^(2^M − 2)`, the inverse of nonzero `a`, or `None` for
+    /// zero.
+    pub fn inverse(&self) -> Option<Self> {
+        if self.n == 0 {
+            None
+        } else {
+            Some(self.pow((u64::MAX >> (64 - M)) ^ 1))
+        }
+    }
+}
+
+impl<const M: usize> Add for BinaryFieldElement<M> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self {
+        assert_eq!(self.poly, rhs.poly);
+        BinaryFieldElement { n: self.n ^ rhs.n, poly: self.poly }
+    }
+}
+
+/// Subtraction is addition in characteristic 2.
+impl<const M: usize> Sub for BinaryFieldElement<M> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self {
+        self + rhs
+    }
+}
+
+impl<const M: usize> Mul for BinaryFieldElement<M> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        assert_eq!(self.poly, rhs.poly);
+        BinaryFieldElement { n: mul_mod::<M>(self.n, rhs.n, self.poly), poly: self.poly }
+    }
+}
+
+impl<const M: usize> Div for BinaryFieldElement<M> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Mul::mul(self, rhs.inverse().expect("Zero is not valid denominator."))
+    }
+}
+
+impl<const M: usize> Neg for BinaryFieldElement<M> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self
+    }
+}
+
+impl<const M: usize> FiniteField for BinaryFieldElement<M> {
+    fn zero(&self) -> Self {
+        BinaryFieldElement::zero(*self)
+    }
+
+    fn one(&self) -> Self {
+        BinaryFieldElement::one(*self)
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        BinaryFieldElement::inverse(self)
+    }
+
+    /// Integers map through the prime subfield `GF(2)`, so only the parity
+    /// of `n` matters.
+    fn from_u64(&self, n: u64) -> Self {
+        BinaryFieldElement { n: n & 1, poly: self.poly }
+    }
+}
+
+impl<const M: usize> fmt::Display for BinaryFieldElement<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x} (mod {:#x})", self.n, self.poly)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fields::Gf256;
+
+    #[test]
+    fn matches_aes_field() {
+        for a in 0..=255u8 {
+            for b in [0u8, 1, 2, 0x53, 0x83, 0xff] {
+                let (x, y) = (BinaryFieldElement::<8>::new(a as u64, 0x11b), BinaryFieldElement::<8>::new(b as u64, 0x11b));
+                assert_eq!((x * y).get_num() as u8, (Gf256::new(a) * Gf256::new(b)).value());
+                assert_eq!((x + y).get_num() as u8, a ^ b);
+            }
+        }
+        let x = BinaryFieldElement::<8>::new(0x53, 0x11b);
+        assert_eq!(x.inverse().unwrap().get_num(), 0xca);
+        assert_eq!(x.to_string(), "0x53 (mod 0x11b)");
+    }
+
+    #[test]
+    fn inverse_works() {
+        // x⁴ + x + 1
+        for n in 1..16 {
+            let x = BinaryFieldElement::<4>::new(n, 0x13);
+            assert_eq!(x * x.inverse().unwrap(), x.one());
+            assert_eq!(x.pow(15), x.one());
+        }
+        // x⁶⁴ + x⁴ + x³ + x + 1
+        let poly = 1 << 64 | 0x1b;
+        let x = BinaryFieldElement::<64>::try_new(0xdeadbeef_cafebabe, poly).unwrap();
+        assert_eq!(x * x.inverse().unwrap(), x.one());
+        assert_eq!(x / x, x.one());
+        assert_eq!(x.zero().inverse(), None);
+    }
+
+    #[test]
+    fn try_new_works() {
+        assert!(BinaryFieldElement::<4>::try_new(3, 0x13).is_ok());
+        assert!(BinaryFieldElement::<1>::try_new(1, 0b10).is_ok());
+        assert!(BinaryFieldElement::<8>::try_new(0, 0x11d).is_ok());
+        // x⁴ + 1 = (x + 1)⁴ and x⁴ + x² + 1 = (x² + x + 1)²
+        assert_eq!(BinaryFieldElement::<4>::try_new(3, 0x11), Err(FieldError::ReducibleModulus));
+        assert_eq!(BinaryFieldElement::<4>::try_new(3, 0x15), Err(FieldError::ReducibleModulus));
+        assert_eq!(BinaryFieldElement::<4>::try_new(3, 0x11b), Err(FieldError::ReducibleModulus));
+        assert_eq!(BinaryFieldElement::<4>::try_new(16, 0x13), Err(FieldError::OutOfRange));
+        assert_eq!(BinaryFieldElement::<4>::try_new(0, 0), Err(FieldError::ZeroModulus));
+    }
+}
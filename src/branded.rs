@@ -0,0 +1,156 @@
+//! Prime fields whose elements carry an invariant lifetime brand, so values
+//! from two runtime moduli cannot be passed to the same operator at all.
+//!
+//! Each call to [`with_field`] creates a fresh brand that cannot escape the
+//! closure, which turns the `assert_eq!(order, order)` panics of
+//! [`FieldElement`] into type errors:
+//!
+//! ```compile_fail
+//! use finite_field::branded::with_field;
+//!
+//! with_field(7u32, |f| with_field(11u32, |g| {
+//!     let _ = f.element(1) + g.element(1);
+//! }));
+//! ```
+
+use std::{fmt::Debug, marker::PhantomData, ops::{Add, Div, Mul, Sub}};
+use crate::{FieldElement, FiniteField, Widening};
+
+type Brand<'id> = PhantomData<fn(&'id ()) -> &'id ()>;
+
+/// A handle to the field of a given order, valid within one [`with_field`]
+/// call.
+#[derive(Debug, Clone, Copy)]
+pub struct Field<'id, T> {
+    order: T,
+    brand: Brand<'id>,
+}
+
+/// An element of the field branded `'id`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Element<'id, T> {
+    inner: FieldElement<T>,
+    brand: Brand<'id>,
+}
+
+/// Runs `f` with a freshly branded field of order `order`.
+pub fn with_field<T, R>(order: T, f: impl for<'id> FnOnce(Field<'id, T>) -> R) -> R {
+    f(Field { order, brand: PhantomData })
+}
+
+impl<'id, T> Field<'id, T>
+    where T: Widening,
+{
+    pub fn order(&self) -> T {
+        self.order
+    }
+
+    pub fn element(&self, n: T) -> Element<'id, T> {
+        self.wrap(FieldElement::new(n, self.order))
+    }
+
+    pub fn zero(&self) -> Element<'id, T> {
+        self.element(T::zero())
+    }
+
+    pub fn one(&self) -> Element<'id, T> {
+        self.element(T::one())
+    }
+
+    /// Brands an existing element, or returns `None` if it belongs to a
+    /// field of another order.
+    pub fn brand(&self, e: FieldElement<T>) -> Option<Element<'id, T>> {
+        if e.get_order() == self.order {
+            Some(self.wrap(e))
+        } else {
+            None
+        }
+    }
+
+    fn wrap(&self, inner: FieldElement<T>) -> Element<'id, T> {
+        Element { inner, brand: PhantomData }
+    }
+}
+
+impl<T: Copy> Element<'_, T> {
+    /// Returns the underlying unbranded element.
+    pub fn inner(&self) -> FieldElement<T> {
+        self.inner
+    }
+
+    pub fn get_num(&self) -> T {
+        self.inner.get_num()
+    }
+}
+
+macro_rules! impl_branded_op {
+    ($op:ident, $method:ident) => {
+        impl<'id, T> $op for Element<'id, T>
+            where T: Widening + Debug,
+        {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                Element { inner: self.inner.$method(rhs.inner), brand: PhantomData }
+            }
+        }
+    };
+}
+
+impl_branded_op!(Add, add);
+impl_branded_op!(Sub, sub);
+impl_branded_op!(Mul, mul);
+impl_branded_op!(Div, div);
+
+impl<T> FiniteField for Element<'_, T>
+    where T: Widening + Debug,
+{
+    fn zero(&self) -> Self {
+        Element { inner: self.inner.zero(), brand: PhantomData }
+    }
+
+    fn one(&self) -> Self {
+        Element { inner: self.inner.one(), brand: PhantomData }
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        self.inner.inverse().map(|inner| Element { inner, brand: PhantomData })
+    }
+
+    fn from_u64(&self, n: u64) -> Self {
+        Element { inner: self.inner.from_u64(n), brand: PhantomData }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Polynomial;
+
+    #[test]
+    fn branded_arithmetic_works() {
+        let n = with_field(7u32, |f| {
+            let a = f.element(3);
+            let b = f.element(5);
+            assert_eq!(a + b, f.one());
+            assert_eq!(a - b, f.element(5));
+            assert_eq!((a * b).inner(), FieldElement::new(1, 7));
+            assert_eq!(a / b * b, a);
+            (a * a).get_num()
+        });
+        assert_eq!(n, 2);
+    }
+
+    #[test]
+    fn brand_checks_order() {
+        with_field(7u32, |f| {
+            assert_eq!(f.brand(FieldElement::new(3, 7)), Some(f.element(3)));
+            assert_eq!(f.brand(FieldElement::new(3, 11)), None);
+
+            let p = Polynomial::new(vec![f.one(), f.one()]);
+            let q = p.clone() * p;
+            assert_eq!(q.coefficients(), &[f.one(), f.element(2), f.one()]);
+        });
+    }
+}
@@ -0,0 +1,181 @@
+use std::{fmt, ops::{Add, Div, Mul, Neg, Sub}};
+use crypto_bigint::modular::runtime_mod::{DynResidue, DynResidueParams};
+use crypto_bigint::{NonZero, Uint};
+use crate::{FieldElementBig, FieldError, FiniteField};
+use crate::field_element_bigint::{residue_params, to_decimal};
+
+/// Montgomery parameters for one runtime modulus, computed once and shared
+/// by every element minted from the context.
+///
+/// [`FieldElementBig`] derives these parameters again in every `new`;
+/// elements of a context share them and skip that work.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldContext<const LIMBS: usize> {
+    params: DynResidueParams<LIMBS>,
+}
+
+/// An element minted by a [`FieldContext`], kept in Montgomery form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContextElement<const LIMBS: usize> {
+    residue: DynResidue<LIMBS>,
+}
+
+impl<const LIMBS: usize> FieldContext<LIMBS> {
+    /// Derives the parameters once, rejecting the same orders as
+    /// [`FieldElementBig::try_new`].
+    pub fn new(order: Uint<LIMBS>) -> Result<Self, FieldError> {
+        Ok(FieldContext { params: residue_params(&order)? })
+    }
+
+    pub fn order(&self) -> Uint<LIMBS> {
+        *self.params.modulus()
+    }
+
+    /// Reduces `n` and converts it to Montgomery form under the shared
+    /// parameters, without deriving them again.
+    pub fn element(&self, n: Uint<LIMBS>) -> ContextElement<LIMBS> {
+        let n = n % NonZero::new(self.order()).unwrap();
+        ContextElement { residue: DynResidue::new(&n, self.params) }
+    }
+
+    pub fn zero(&self) -> ContextElement<LIMBS> {
+        ContextElement { residue: DynResidue::zero(self.params) }
+    }
+
+    pub fn one(&self) -> ContextElement<LIMBS> {
+        ContextElement { residue: DynResidue::one(self.params) }
+    }
+
+    /// Rebuilds an element on the context's parameters so it can be mixed
+    /// with the context's own elements, failing if its order differs.
+    pub fn import(&self, e: &FieldElementBig<LIMBS>) -> Result<ContextElement<LIMBS>, FieldError> {
+        if e.get_order() != self.order() {
+            return Err(FieldError::MismatchedOrders);
+        }
+        Ok(self.element(e.get_num()))
+    }
+}
+
+impl<const LIMBS: usize> ContextElement<LIMBS> {
+    pub fn get_num(&self) -> Uint<LIMBS> {
+        self.residue.retrieve()
+    }
+
+    pub fn get_order(&self) -> Uint<LIMBS> {
+        *self.residue.params().modulus()
+    }
+
+    pub fn context(&self) -> FieldContext<LIMBS> {
+        FieldContext { params: *self.residue.params() }
+    }
+
+    pub fn pow(self, exp: Uint<LIMBS>) -> Self {
+        ContextElement { residue: self.residue.pow(&exp) }
+    }
+
+    pub fn square(self) -> Self {
+        ContextElement { residue: self.residue.square() }
+    }
+
+    /// Returns the inverse by Fermat's little theorem, or `None` for zero.
+    pub fn inverse(&self) -> Option<Self> {
+        if self.get_num() == Uint::ZERO {
+            None
+        } else {
+            Some(self.pow(self.get_order().wrapping_sub(&Uint::from(2u8))))
+        }
+    }
+
+    pub fn to_big(&self) -> FieldElementBig<LIMBS> {
+        FieldElementBig::new(self.get_num(), self.get_order())
+    }
+}
+
+macro_rules! impl_context_op {
+    ($op:ident, $method:ident) => {
+        impl<const LIMBS: usize> $op for ContextElement<LIMBS> {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self {
+                assert_eq!(self.residue.params(), rhs.residue.params());
+                ContextElement { residue: self.residue.$method(rhs.residue) }
+            }
+        }
+    };
+}
+
+impl_context_op!(Add, add);
+impl_context_op!(Sub, sub);
+impl_context_op!(Mul, mul);
+
+impl<const LIMBS: usize> Div for ContextElement<LIMBS> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        self.mul(rhs.inverse().expect("Zero is not valid denominator."))
+    }
+}
+
+impl<const LIMBS: usize> Neg for ContextElement<LIMBS> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        ContextElement { residue: self.residue.neg() }
+    }
+}
+
+impl<const LIMBS: usize> FiniteField for ContextElement<LIMBS> {
+    fn zero(&self) -> Self {
+        self.context().zero()
+    }
+
+    fn one(&self) -> Self {
+        self.context().one()
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        ContextElement::inverse(self)
+    }
+
+    fn from_u64(&self, n: u64) -> Self {
+        self.context().element(Uint::from_u64(n))
+    }
+}
+
+impl<const LIMBS: usize> fmt::Display for ContextElement<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (mod {})", to_decimal(&self.get_num()), to_decimal(&self.get_order()))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::check_arithmetic;
+    use crypto_bigint::U256;
+
+    fn secp256k1() -> FieldContext<4> {
+        FieldContext::new(U256::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f")).unwrap()
+    }
+
+    #[test]
+    fn arithmetic_matches_field_element_big() {
+        let ctx = secp256k1();
+        let x = ctx.element(U256::MAX);
+        for y in [U256::from(7u8), ctx.order().wrapping_sub(&U256::ONE), U256::ONE.shl_vartime(255)] {
+            check_arithmetic(x, ctx.element(y), |e| e.to_big());
+        }
+        assert_eq!(x.square(), x * x);
+        assert_eq!(ctx.import(&x.to_big()), Ok(x));
+        assert_eq!(x.to_big(), FieldElementBig::new(U256::MAX, ctx.order()));
+    }
+
+    #[test]
+    fn new_rejects_bad_moduli() {
+        assert_eq!(FieldContext::new(U256::ZERO), Err(FieldError::ZeroModulus));
+        assert_eq!(FieldContext::new(U256::from(10u8)), Err(FieldError::EvenModulus));
+        let other = FieldElementBig::new(U256::ONE, U256::from(7u8));
+        assert_eq!(secp256k1().import(&other), Err(FieldError::MismatchedOrders));
+    }
+}
@@ -0,0 +1,82 @@
+//! Conversions between the primitive and bigint backends.
+
+use crypto_bigint::{Limb, Uint};
+use crate::{FieldElement, FieldElementBig, FieldError};
+
+/// Returns `x` as a `u128`, or `None` if it does not fit.
+fn uint_to_u128<const LIMBS: usize>(x: &Uint<LIMBS>) -> Option<u128> {
+    if x.bits() > 128 {
+        return None;
+    }
+    Some(x.as_words().iter().rev().fold(0u128, |acc, &w| (acc << Limb::BITS) | w as u128))
+}
+
+/// Lifts a primitive element into a bigint field with the same modulus, or
+/// fails with [`FieldError::EvenModulus`], since the bigint backend needs an
+/// odd modulus.
+macro_rules! impl_lift {
+    ($($t:ty),*) => {
+        $(
+            impl<const LIMBS: usize> TryFrom<FieldElement<$t>> for FieldElementBig<LIMBS> {
+                type Error = FieldError;
+
+                fn try_from(e: FieldElement<$t>) -> Result<Self, Self::Error> {
+                    FieldElementBig::try_new(Uint::from_u64(e.get_num() as u64), Uint::from_u64(e.get_order() as u64))
+                }
+            }
+        )*
+    };
+}
+
+impl_lift!(u8, u16, u32, u64);
+
+/// Narrows a bigint element whose modulus fits in the primitive type, or
+/// fails with [`FieldError::OutOfRange`].
+macro_rules! impl_narrow {
+    ($($t:ty),*) => {
+        $(
+            impl<const LIMBS: usize> TryFrom<FieldElementBig<LIMBS>> for FieldElement<$t> {
+                type Error = FieldError;
+
+                fn try_from(e: FieldElementBig<LIMBS>) -> Result<Self, Self::Error> {
+                    let narrow = |x: &Uint<LIMBS>| {
+                        uint_to_u128(x).and_then(|x| <$t>::try_from(x).ok()).ok_or(FieldError::OutOfRange)
+                    };
+                    FieldElement::try_new(narrow(&e.get_num())?, narrow(&e.get_order())?)
+                }
+            }
+        )*
+    };
+}
+
+impl_narrow!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crypto_bigint::U256;
+
+    #[test]
+    fn lift_works() {
+        let p = 18446744073709551557u64;
+        let a: FieldElement<u64> = FieldElement::new(p - 2, p);
+        let big: FieldElementBig<4> = a.try_into().unwrap();
+        assert_eq!(big, FieldElementBig::new(U256::from(p - 2), U256::from(p)));
+        assert_eq!((big * big).get_num(), U256::from((a * a).get_num()));
+        let gf2 = FieldElement::new(1u8, 2);
+        assert_eq!(FieldElementBig::<4>::try_from(gf2), Err(FieldError::EvenModulus));
+    }
+
+    #[test]
+    fn narrow_works() {
+        let big = FieldElementBig::new(U256::from(200u8), U256::from(251u8));
+        assert_eq!(FieldElement::<u8>::try_from(big), Ok(FieldElement::new(200, 251)));
+        assert_eq!(FieldElement::<i8>::try_from(big), Err(FieldError::OutOfRange));
+        let p = U256::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+        assert_eq!(FieldElement::<u128>::try_from(FieldElementBig::new(U256::ONE, p)), Err(FieldError::OutOfRange));
+        let q = U256::from_u128(u128::MAX - 158);
+        let c = FieldElementBig::new(U256::from(7u8), q);
+        assert_eq!(FieldElement::<u128>::try_from(c), Ok(FieldElement::new(7, u128::MAX - 158)));
+    }
+}
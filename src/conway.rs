@@ -0,0 +1,244 @@
+//! Conway polynomials, the standard moduli for `GF(p^n)` used by SageMath,
+//! Magma and GAP, so that fields built here agree element for element with
+//! theirs.
+//!
+//! The Conway polynomial `C(p, n)` is the least primitive polynomial of
+//! degree `n` whose root `α` maps to a root of `C(p, d)` under
+//! `α ↦ α^((p^n − 1)/(p^d − 1))` for every `d` dividing `n`. Polynomials
+//! are ordered by their coefficients from `x^(n−1)` down, with the
+//! coefficient of `x^(n−i)` negated for odd `i`. In degree one this is
+//! `x − g` for the least primitive root `g`, which is computed for any
+//! prime; higher degrees are tabulated for every prime power
+//! `p^n ≤ 10⁵`.
+
+use std::fmt::Debug;
+use num::NumCast;
+use crate::{FieldElement, Polynomial, Widening};
+use crate::ntt::generator;
+
+/// `(p, n, coefficients)`, constant term first, for `n ≥ 2`.
+const CONWAY_POLYNOMIALS: &[(u64, usize, &[u16])] = &[
+    (2, 2, &[1, 1, 1]),
+    (2, 3, &[1, 1, 0, 1]),
+    (2, 4, &[1, 1, 0, 0, 1]),
+    (2, 5, &[1, 0, 1, 0, 0, 1]),
+    (2, 6, &[1, 1, 0, 1, 1, 0, 1]),
+    (2, 7, &[1, 1, 0, 0, 0, 0, 0, 1]),
+    (2, 8, &[1, 0, 1, 1, 1, 0, 0, 0, 1]),
+    (2, 9, &[1, 0, 0, 0, 1, 0, 0, 0, 0, 1]),
+    (2, 10, &[1, 1, 1, 1, 0, 1, 1, 0, 0, 0, 1]),
+    (2, 11, &[1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+    (2, 12, &[1, 1, 0, 1, 0, 1, 1, 1, 0, 0, 0, 0, 1]),
+    (2, 13, &[1, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+    (2, 14, &[1, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1]),
+    (2, 15, &[1, 0, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+    (2, 16, &[1, 0, 1, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+
+    (3, 2, &[2, 2, 1]),
+    (3, 3, &[1, 2, 0, 1]),
+    (3, 4, &[2, 0, 0, 2, 1]),
+    (3, 5, &[1, 2, 0, 0, 0, 1]),
+    (3, 6, &[2, 2, 1, 0, 2, 0, 1]),
+    (3, 7, &[1, 0, 2, 0, 0, 0, 0, 1]),
+    (3, 8, &[2, 2, 2, 0, 1, 2, 0, 0, 1]),
+    (3, 9, &[1, 1, 2, 2, 0, 0, 0, 0, 0, 1]),
+    (3, 10, &[2, 1, 0, 0, 2, 2, 2, 0, 0, 0, 1]),
+
+    (5, 2, &[2, 4, 1]),
+    (5, 3, &[3, 3, 0, 1]),
+    (5, 4, &[2, 4, 4, 0, 1]),
+    (5, 5, &[3, 4, 0, 0, 0, 1]),
+    (5, 6, &[2, 0, 1, 4, 1, 0, 1]),
+    (5, 7, &[3, 3, 0, 0, 0, 0, 0, 1]),
+
+    (7, 2, &[3, 6, 1]),
+    (7, 3, &[4, 0, 6, 1]),
+    (7, 4, &[3, 4, 5, 0, 1]),
+    (7, 5, &[4, 1, 0, 0, 0, 1]),
+
+    (11, 2, &[2, 7, 1]),
+    (11, 3, &[9, 2, 0, 1]),
+    (11, 4, &[2, 10, 8, 0, 1]),
+
+    (13, 2, &[2, 12, 1]),
+    (13, 3, &[11, 2, 0, 1]),
+    (13, 4, &[2, 12, 3, 0, 1]),
+
+    (17, 2, &[3, 16, 1]),
+    (17, 3, &[14, 1, 0, 1]),
+    (17, 4, &[3, 10, 7, 0, 1]),
+
+    (19, 2, &[2, 18, 1]),
+    (19, 3, &[17, 4, 0, 1]),
+
+    (23, 2, &[5, 21, 1]),
+    (23, 3, &[18, 2, 0, 1]),
+
+    (29, 2, &[2, 24, 1]),
+    (29, 3, &[27, 2, 0, 1]),
+
+    (31, 2, &[3, 29, 1]),
+    (31, 3, &[28, 1, 0, 1]),
+
+    (37, 2, &[2, 33, 1]),
+    (37, 3, &[35, 6, 0, 1]),
+
+    (41, 2, &[6, 38, 1]),
+    (41, 3, &[35, 1, 0, 1]),
+
+    (43, 2, &[3, 42, 1]),
+    (43, 3, &[40, 1, 0, 1]),
+
+    (47, 2, &[5, 45, 1]),
+
+    (53, 2, &[2, 49, 1]),
+    (59, 2, &[2, 58, 1]),
+    (61, 2, &[2, 60, 1]),
+    (67, 2, &[2, 63, 1]),
+    (71, 2, &[7, 69, 1]),
+    (73, 2, &[5, 70, 1]),
+    (79, 2, &[3, 78, 1]),
+    (83, 2, &[2, 82, 1]),
+    (89, 2, &[3, 82, 1]),
+    (97, 2, &[5, 96, 1]),
+    (101, 2, &[2, 97, 1]),
+    (103, 2, &[5, 102, 1]),
+    (107, 2, &[2, 103, 1]),
+    (109, 2, &[6, 108, 1]),
+    (113, 2, &[3, 101, 1]),
+    (127, 2, &[3, 126, 1]),
+    (131, 2, &[2, 127, 1]),
+    (137, 2, &[3, 131, 1]),
+    (139, 2, &[2, 138, 1]),
+    (149, 2, &[2, 145, 1]),
+    (151, 2, &[6, 149, 1]),
+    (157, 2, &[5, 152, 1]),
+    (163, 2, &[2, 159, 1]),
+    (167, 2, &[5, 166, 1]),
+    (173, 2, &[2, 169, 1]),
+    (179, 2, &[2, 172, 1]),
+    (181, 2, &[2, 177, 1]),
+    (191, 2, &[19, 190, 1]),
+    (193, 2, &[5, 192, 1]),
+    (197, 2, &[2, 192, 1]),
+    (199, 2, &[3, 193, 1]),
+    (211, 2, &[2, 207, 1]),
+    (223, 2, &[3, 221, 1]),
+    (227, 2, &[2, 220, 1]),
+    (229, 2, &[6, 228, 1]),
+    (233, 2, &[3, 232, 1]),
+    (239, 2, &[7, 237, 1]),
+    (241, 2, &[7, 238, 1]),
+    (251, 2, &[6, 242, 1]),
+    (257, 2, &[3, 251, 1]),
+    (263, 2, &[5, 261, 1]),
+    (269, 2, &[2, 268, 1]),
+    (271, 2, &[6, 269, 1]),
+    (277, 2, &[5, 274, 1]),
+    (281, 2, &[3, 280, 1]),
+    (283, 2, &[3, 282, 1]),
+    (293, 2, &[2, 292, 1]),
+    (307, 2, &[5, 306, 1]),
+    (311, 2, &[17, 310, 1]),
+    (313, 2, &[10, 310, 1]),
+];
+
+/// Returns the Conway polynomial of degree `n` over the prime field of
+/// order `p`, or `None` if `p` is not prime or, for `n ≥ 2`, the
+/// polynomial is not in the table.
+pub fn conway_polynomial<T>(p: T, n: usize) -> Option<Polynomial<FieldElement<T>>>
+    where T: Widening + Debug,
+{
+    let order = p.to_u64()?;
+    if n == 1 {
+        if !primes::is_prime(order) {
+            return None;
+        }
+        let g = generator(p);
+        return Some(Polynomial::new(vec![g.zero() - g, g.one()]));
+    }
+    let &(_, _, coeffs) = CONWAY_POLYNOMIALS.iter().find(|&&(q, m, _)| q == order && m == n)?;
+    Some(Polynomial::new(coeffs.iter().map(|&c| FieldElement::new(NumCast::from(c).unwrap(), p)).collect()))
+}
+
+/// Iterates over the degrees available for `p`, in increasing order:
+/// degree one for any prime, then those tabulated.
+pub fn conway_degrees(p: u64) -> impl Iterator<Item = usize> {
+    let linear = primes::is_prime(p).then_some(1);
+    linear.into_iter().chain(CONWAY_POLYNOMIALS.iter().filter(move |&&(q, _, _)| q == p).map(|&(_, n, _)| n))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::irreducible::is_irreducible;
+    use crate::sequence::{is_primitive, mul_mod};
+
+    fn poly(nums: &[u32], order: u32) -> Polynomial<FieldElement<u32>> {
+        Polynomial::new(nums.iter().map(|&n| FieldElement::new(n, order)).collect())
+    }
+
+    #[test]
+    fn known_values() {
+        // as printed by Sage's conway_polynomial
+        assert_eq!(conway_polynomial(2, 8), Some(poly(&[1, 0, 1, 1, 1, 0, 0, 0, 1], 2)));
+        assert_eq!(conway_polynomial(3, 2), Some(poly(&[2, 2, 1], 3)));
+        assert_eq!(conway_polynomial(3, 4), Some(poly(&[2, 0, 0, 2, 1], 3)));
+        assert_eq!(conway_polynomial(5, 1), Some(poly(&[3, 1], 5)));
+        assert_eq!(conway_polynomial(7, 2), Some(poly(&[3, 6, 1], 7)));
+        assert_eq!(conway_polynomial(2u8, 16).unwrap().degree(), Some(16));
+        assert_eq!(conway_polynomial(2, 17), None);
+        assert_eq!(conway_polynomial(4, 1), None);
+        assert_eq!(conway_polynomial(7, 1), Some(poly(&[4, 1], 7)));
+        assert_eq!(conway_polynomial(53, 2), Some(poly(&[2, 49, 1], 53)));
+        // 2 is the least primitive root of 1000003
+        assert_eq!(conway_polynomial(1000003, 1), Some(poly(&[1000001, 1], 1000003)));
+        assert_eq!(conway_polynomial(2, 1), Some(poly(&[1, 1], 2)));
+        assert_eq!(conway_degrees(313).collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(conway_degrees(2).count(), 16);
+        assert_eq!(conway_degrees(7).collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+    }
+
+    /// Evaluates `g` at `a` in `GF(p)[x]/(f)` by Horner's rule.
+    fn evaluate(g: &[FieldElement<u32>], a: &[FieldElement<u32>], f: &[FieldElement<u32>]) -> Vec<FieldElement<u32>> {
+        g.iter().rev().fold(vec![f[0].zero(); f.len() - 1], |acc, &c| {
+            let mut acc = mul_mod(&acc, a, f);
+            acc[0] = acc[0] + c;
+            acc
+        })
+    }
+
+    fn pow(a: &[FieldElement<u32>], mut exp: u64, f: &[FieldElement<u32>]) -> Vec<FieldElement<u32>> {
+        let mut result = vec![f[0].zero(); f.len() - 1];
+        result[0] = f[0].one();
+        let mut base = a.to_vec();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = mul_mod(&result, &base, f);
+            }
+            base = mul_mod(&base, &base, f);
+            exp >>= 1;
+        }
+        result
+    }
+
+    #[test]
+    fn table_is_consistent() {
+        let linear = (2..317).filter(|&p| primes::is_prime(p)).map(|p| (p, 1));
+        for (p, n) in linear.chain(CONWAY_POLYNOMIALS.iter().map(|&(p, n, _)| (p, n))) {
+            let c = conway_polynomial(p as u32, n).unwrap();
+            assert!(is_irreducible(&c) && is_primitive(&c), "C({}, {})", p, n);
+            let f = c.coefficients();
+            let mut x = vec![f[0].zero(); n.max(2)];
+            x[1] = f[0].one();
+            let x = mul_mod(&x, &[f[0].one()], f);
+            for d in (1..n).filter(|d| n % d == 0) {
+                let e = (p.pow(n as u32) - 1) / (p.pow(d as u32) - 1);
+                let sub = conway_polynomial(p as u32, d).unwrap();
+                let image = evaluate(sub.coefficients(), &pow(&x, e, f), f);
+                assert!(image.iter().all(|c| c.get_num() == 0), "C({}, {}) over C({}, {})", p, n, p, d);
+            }
+        }
+    }
+}
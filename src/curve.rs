@@ -0,0 +1,170 @@
+//! Short Weierstrass curves `y² = x³ + ax + b` over prime fields, with
+//! quadratic twists and validation of user-supplied parameters.
+
+use std::{error::Error, fmt::{self, Debug, Display}};
+use num::ToPrimitive;
+use primes::{factors, is_prime};
+use crate::{FieldElement, Widening};
+use crate::schoof::count_points;
+
+/// Largest embedding degree rejected by [`Curve::validate`]; pairing-based
+/// attacks move the discrete log into `F_{p^k}` for such small `k`.
+const MAX_EMBEDDING_DEGREE: u32 = 20;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CurveError {
+    Singular,
+    OrderMismatch { expected: u64, actual: u64 },
+    CompositeOrder(u64),
+    Anomalous,
+    SmallEmbeddingDegree(u32),
+}
+
+impl Display for CurveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CurveError::Singular => write!(f, "curve is singular"),
+            CurveError::OrderMismatch { expected, actual } => {
+                write!(f, "curve has {} points, expected {}", actual, expected)
+            }
+            CurveError::CompositeOrder(n) => write!(f, "subgroup order {} is not prime", n),
+            CurveError::Anomalous => write!(f, "subgroup order equals the field order"),
+            CurveError::SmallEmbeddingDegree(k) => write!(f, "embedding degree {} is too small", k),
+        }
+    }
+}
+
+impl Error for CurveError {}
+
+/// Security of the quadratic twist, as reported by [`Curve::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TwistReport {
+    pub order: u64,
+    pub largest_prime_factor: u64,
+    /// Approximate cost of a discrete log in the largest prime-order
+    /// subgroup of the twist, in bits.
+    pub security_bits: u32,
+}
+
+/// The curve `y² = x³ + ax + b`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Curve<T> {
+    a: FieldElement<T>,
+    b: FieldElement<T>,
+}
+
+impl<T> Curve<T>
+    where T: Widening + Debug,
+{
+    pub fn new(a: FieldElement<T>, b: FieldElement<T>) -> Self {
+        assert_eq!(a.get_order(), b.get_order());
+        Curve { a, b }
+    }
+
+    pub fn a(&self) -> FieldElement<T> {
+        self.a
+    }
+
+    pub fn b(&self) -> FieldElement<T> {
+        self.b
+    }
+
+    /// Returns `4a³ + 27b²`, which vanishes exactly for singular curves.
+    pub fn discriminant(&self) -> FieldElement<T> {
+        let order = self.a.get_order();
+        let (a, b) = (self.a, self.b);
+        FieldElement::from_signed(4, order) * a * a * a + FieldElement::from_signed(27, order) * b * b
+    }
+
+    pub fn is_singular(&self) -> bool {
+        self.discriminant() == self.a.zero()
+    }
+
+    /// Returns `#E(F_p)`, counted with Schoof's algorithm.
+    pub fn order(&self) -> u64 {
+        count_points(self.a, self.b)
+    }
+
+    /// Returns the quadratic twist `y² = x³ + d²a·x + d³b` for a non-residue
+    /// `d`, whose order is `2p + 2 − #E(F_p)`.
+    pub fn twist(&self) -> Self {
+        let d = FieldElement::find_nonresidue(self.a.get_order()).expect("order must be an odd prime");
+        Curve {
+            a: d * d * self.a,
+            b: d * d * d * self.b,
+        }
+    }
+
+    /// Checks that the curve is non-singular, that `#E(F_p) = n·h` with `n`
+    /// prime, and that the subgroup of order `n` resists the anomalous and
+    /// small-embedding-degree attacks. On success, reports how strong the
+    /// twist is against invalid-curve attacks.
+    pub fn validate(&self, n: u64, cofactor: u64) -> Result<TwistReport, CurveError> {
+        if self.is_singular() {
+            return Err(CurveError::Singular);
+        }
+        let p = self.a.get_order().to_u64().expect("order does not fit in u64");
+        let actual = self.order();
+        match n.checked_mul(cofactor) {
+            Some(expected) if expected == actual => {}
+            expected => {
+                return Err(CurveError::OrderMismatch { expected: expected.unwrap_or(u64::MAX), actual });
+            }
+        }
+        if !is_prime(n) {
+            return Err(CurveError::CompositeOrder(n));
+        }
+        if n == p {
+            return Err(CurveError::Anomalous);
+        }
+        let mut power = 1u128;
+        for k in 1..=MAX_EMBEDDING_DEGREE {
+            power = power * p as u128 % n as u128;
+            if power == 1 {
+                return Err(CurveError::SmallEmbeddingDegree(k));
+            }
+        }
+
+        let order = 2 * p + 2 - actual;
+        let largest_prime_factor = factors(order).into_iter().max().unwrap_or(1);
+        Ok(TwistReport {
+            order,
+            largest_prime_factor,
+            security_bits: largest_prime_factor.to_f64().unwrap().log2() as u32 / 2,
+        })
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn curve(a: u64, b: u64, p: u64) -> Curve<u64> {
+        Curve::new(FieldElement::new(a, p), FieldElement::new(b, p))
+    }
+
+    #[test]
+    fn twist_works() {
+        let e = curve(2, 3, 1009);
+        let twist = e.twist();
+        assert!(!twist.is_singular());
+        assert_eq!(e.order() + twist.order(), 2 * 1009 + 2);
+    }
+
+    #[test]
+    fn validate_works() {
+        assert_eq!(curve(0, 0, 101).validate(101, 1), Err(CurveError::Singular));
+
+        let e = curve(2, 3, 10007);
+        assert_eq!(e.order(), 18 * 547);
+        assert_eq!(
+            e.validate(547, 1),
+            Err(CurveError::OrderMismatch { expected: 547, actual: 18 * 547 })
+        );
+        assert_eq!(e.validate(18 * 547, 1), Err(CurveError::CompositeOrder(18 * 547)));
+        let report = e.validate(547, 18).unwrap();
+        assert_eq!(report, TwistReport { order: 10170, largest_prime_factor: 113, security_bits: 3 });
+        assert_eq!(report.order, e.twist().order());
+    }
+}
@@ -0,0 +1,38 @@
+use std::{error::Error, fmt};
+
+/// Errors returned by the fallible field APIs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldError {
+    ZeroModulus,
+    MismatchedOrders,
+    NotInvertible,
+    OutOfRange,
+    NonPrimeModulus,
+    /// A Montgomery backend was given an even modulus, including the prime
+    /// 2, which has no inverse modulo a power of two.
+    EvenModulus,
+    /// A polynomial modulus factors, so the quotient ring is not a field.
+    ReducibleModulus,
+    /// A string was not in the expected form, such as `n mod p`.
+    Parse,
+    /// A byte encoding did not have the modulus byte width.
+    InvalidLength,
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldError::ZeroModulus => write!(f, "modulus is zero"),
+            FieldError::MismatchedOrders => write!(f, "elements belong to fields of different orders"),
+            FieldError::NotInvertible => write!(f, "element is not invertible"),
+            FieldError::OutOfRange => write!(f, "value is not in the range [0, order)"),
+            FieldError::NonPrimeModulus => write!(f, "modulus is not prime"),
+            FieldError::EvenModulus => write!(f, "modulus is even, but Montgomery form needs an odd modulus"),
+            FieldError::ReducibleModulus => write!(f, "modulus polynomial is not irreducible"),
+            FieldError::Parse => write!(f, "expected a field element such as `n mod p`"),
+            FieldError::InvalidLength => write!(f, "encoding does not match the modulus byte width"),
+        }
+    }
+}
+
+impl Error for FieldError {}
@@ -0,0 +1,310 @@
+//! Parsing and evaluation of arithmetic expressions over a prime field.
+//!
+//! Expressions use `+ - * / ^`, parentheses, integer literals and variable
+//! names, with the usual precedence; `-x^2` is `-(x^2)`. Exponents are
+//! integer literals and may be negative, e.g. `x^-1`.
+
+use std::{collections::HashMap, error::Error, fmt::{self, Debug, Display}};
+use num::NumCast;
+use crate::{FieldElement, Widening};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvalError {
+    UnexpectedChar(usize, char),
+    UnexpectedEnd,
+    InvalidNumber(String),
+    UnknownVariable(String),
+    DivisionByZero,
+    /// The order is zero or negative.
+    InvalidOrder,
+    /// The order exceeds `i128::MAX`, the range of literals and exponents.
+    OrderTooLarge,
+    /// The named variable belongs to a field of another order.
+    MismatchedOrder(String),
+}
+
+impl Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvalError::UnexpectedChar(pos, c) => write!(f, "unexpected '{}' at position {}", c, pos),
+            EvalError::UnexpectedEnd => write!(f, "unexpected end of expression"),
+            EvalError::InvalidNumber(s) => write!(f, "invalid number '{}'", s),
+            EvalError::UnknownVariable(name) => write!(f, "unknown variable '{}'", name),
+            EvalError::DivisionByZero => write!(f, "division by zero"),
+            EvalError::InvalidOrder => write!(f, "order is not positive"),
+            EvalError::OrderTooLarge => write!(f, "order does not fit in i128"),
+            EvalError::MismatchedOrder(name) => write!(f, "variable '{}' has a different order", name),
+        }
+    }
+}
+
+impl Error for EvalError {}
+
+/// A parsed expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    Num(i128),
+    Var(String),
+    Neg(Box<Expr>),
+    Add(Box<Expr>, Box<Expr>),
+    Sub(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>),
+    Div(Box<Expr>, Box<Expr>),
+    Pow(Box<Expr>, i128),
+}
+
+struct Parser<'a> {
+    chars: Vec<(usize, char)>,
+    pos: usize,
+    input: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Parser {
+            chars: input.char_indices().filter(|(_, c)| !c.is_whitespace()).collect(),
+            pos: 0,
+            input,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).map(|&(_, c)| c)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), EvalError> {
+        match self.chars.get(self.pos) {
+            Some(&(_, c)) if c == expected => {
+                self.pos += 1;
+                Ok(())
+            }
+            Some(&(i, c)) => Err(EvalError::UnexpectedChar(i, c)),
+            None => Err(EvalError::UnexpectedEnd),
+        }
+    }
+
+    fn expr(&mut self) -> Result<Expr, EvalError> {
+        let mut lhs = self.term()?;
+        while let Some(op) = self.peek().filter(|c| *c == '+' || *c == '-') {
+            self.pos += 1;
+            let rhs = self.term()?;
+            lhs = if op == '+' {
+                Expr::Add(Box::new(lhs), Box::new(rhs))
+            } else {
+                Expr::Sub(Box::new(lhs), Box::new(rhs))
+            };
+        }
+        Ok(lhs)
+    }
+
+    fn term(&mut self) -> Result<Expr, EvalError> {
+        let mut lhs = self.unary()?;
+        while let Some(op) = self.peek().filter(|c| *c == '*' || *c == '/') {
+            self.pos += 1;
+            let rhs = self.unary()?;
+            lhs = if op == '*' {
+                Expr::Mul(Box::new(lhs), Box::new(rhs))
+            } else {
+                Expr::Div(Box::new(lhs), Box::new(rhs))
+            };
+        }
+        Ok(lhs)
+    }
+
+    fn unary(&mut self) -> Result<Expr, EvalError> {
+        if self.peek() == Some('-') {
+            self.pos += 1;
+            return Ok(Expr::Neg(Box::new(self.unary()?)));
+        }
+        let base = self.atom()?;
+        if self.peek() == Some('^') {
+            self.pos += 1;
+            return Ok(Expr::Pow(Box::new(base), self.exponent()?));
+        }
+        Ok(base)
+    }
+
+    fn exponent(&mut self) -> Result<i128, EvalError> {
+        if self.peek() == Some('(') {
+            self.pos += 1;
+            let exp = self.exponent()?;
+            self.expect(')')?;
+            return Ok(exp);
+        }
+        let negative = self.peek() == Some('-');
+        if negative {
+            self.pos += 1;
+        }
+        let exp = self.number()?;
+        Ok(if negative { -exp } else { exp })
+    }
+
+    fn number(&mut self) -> Result<i128, EvalError> {
+        let (start, _) = *self.chars.get(self.pos).ok_or(EvalError::UnexpectedEnd)?;
+        let mut end = start;
+        while let Some(&(i, c)) = self.chars.get(self.pos).filter(|(_, c)| c.is_ascii_digit()) {
+            end = i + c.len_utf8();
+            self.pos += 1;
+        }
+        if end == start {
+            let (i, c) = self.chars[self.pos];
+            return Err(EvalError::UnexpectedChar(i, c));
+        }
+        let digits = &self.input[start..end];
+        digits.parse().map_err(|_| EvalError::InvalidNumber(digits.to_string()))
+    }
+
+    fn atom(&mut self) -> Result<Expr, EvalError> {
+        match self.chars.get(self.pos) {
+            None => Err(EvalError::UnexpectedEnd),
+            Some(&(_, '(')) => {
+                self.pos += 1;
+                let inner = self.expr()?;
+                self.expect(')')?;
+                Ok(inner)
+            }
+            Some(&(_, c)) if c.is_ascii_digit() => Ok(Expr::Num(self.number()?)),
+            Some(&(start, c)) if c.is_alphabetic() || c == '_' => {
+                let mut name = String::new();
+                while let Some(c) = self.peek().filter(|c| c.is_alphanumeric() || *c == '_') {
+                    name.push(c);
+                    self.pos += 1;
+                }
+                // whitespace is skipped, so reject names split by spaces
+                if self.input[start..].starts_with(&name) {
+                    Ok(Expr::Var(name))
+                } else {
+                    Err(EvalError::UnexpectedChar(start + name.len(), ' '))
+                }
+            }
+            Some(&(i, c)) => Err(EvalError::UnexpectedChar(i, c)),
+        }
+    }
+}
+
+/// Parses an expression into its syntax tree.
+pub fn parse(input: &str) -> Result<Expr, EvalError> {
+    let mut parser = Parser::new(input);
+    let expr = parser.expr()?;
+    match parser.chars.get(parser.pos) {
+        None => Ok(expr),
+        Some(&(i, c)) => Err(EvalError::UnexpectedChar(i, c)),
+    }
+}
+
+impl Expr {
+    /// Evaluates the expression in the field of the given order, which must
+    /// be positive and fit in `i128`. Variables must belong to that field.
+    pub fn eval<T>(&self, order: T, vars: &HashMap<String, FieldElement<T>>) -> Result<FieldElement<T>, EvalError>
+        where T: Widening + Debug,
+    {
+        if order <= T::zero() {
+            return Err(EvalError::InvalidOrder);
+        }
+        let p = order.to_i128().ok_or(EvalError::OrderTooLarge)?;
+        self.eval_in(FieldElement::new(T::zero(), order), p, vars)
+    }
+
+    fn eval_in<T>(&self, zero: FieldElement<T>, p: i128, vars: &HashMap<String, FieldElement<T>>) -> Result<FieldElement<T>, EvalError>
+        where T: Widening + Debug,
+    {
+        let order = zero.get_order();
+        let recurse = |e: &Expr| e.eval_in(zero, p, vars);
+        Ok(match self {
+            Expr::Num(n) => FieldElement::from_signed(*n, order),
+            Expr::Var(name) => {
+                let value = *vars.get(name).ok_or_else(|| EvalError::UnknownVariable(name.clone()))?;
+                if value.get_order() != order {
+                    return Err(EvalError::MismatchedOrder(name.clone()));
+                }
+                value
+            }
+            Expr::Neg(a) => zero - recurse(a)?,
+            Expr::Add(a, b) => recurse(a)? + recurse(b)?,
+            Expr::Sub(a, b) => recurse(a)? - recurse(b)?,
+            Expr::Mul(a, b) => recurse(a)? * recurse(b)?,
+            Expr::Div(a, b) => {
+                let den = recurse(b)?;
+                if den == zero {
+                    return Err(EvalError::DivisionByZero);
+                }
+                recurse(a)? / den
+            }
+            Expr::Pow(a, exp) => {
+                let base = recurse(a)?;
+                if base == zero {
+                    return match exp {
+                        0 => Ok(zero.one()),
+                        e if *e > 0 => Ok(zero),
+                        _ => Err(EvalError::DivisionByZero),
+                    };
+                }
+                let e: T = NumCast::from(exp.rem_euclid(p - 1)).unwrap();
+                base.pow(e)
+            }
+        })
+    }
+}
+
+/// Parses and evaluates `input` in the field of the given order.
+pub fn eval<T>(input: &str, order: T, vars: &HashMap<String, FieldElement<T>>) -> Result<FieldElement<T>, EvalError>
+    where T: Widening + Debug,
+{
+    parse(input)?.eval(order, vars)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&str, u64)]) -> HashMap<String, FieldElement<u64>> {
+        pairs.iter().map(|&(k, v)| (k.to_string(), FieldElement::new(v, 31))).collect()
+    }
+
+    #[test]
+    fn eval_works() {
+        let vars = vars(&[("x", 4), ("y", 3)]);
+        let expected = FieldElement::new(17, 31) + FieldElement::new(5, 31) / FieldElement::new(3, 31)
+            - FieldElement::new(7, 31);
+        assert_eq!(eval("3*x^2 + 5/y - 7", 31u64, &vars), Ok(expected));
+        assert_eq!(eval("-x^2", 31u64, &vars), Ok(FieldElement::new(15, 31)));
+        assert_eq!(eval("(x + y) * (x - y)", 31u64, &vars), Ok(FieldElement::new(7, 31)));
+        assert_eq!(eval("x^-1 * x", 31u64, &vars), Ok(FieldElement::new(1, 31)));
+        assert_eq!(eval("2 - 3 - 4", 31u64, &vars), Ok(FieldElement::new(26, 31)));
+        assert_eq!(eval("8 / 2 / 2", 31u64, &vars), Ok(FieldElement::new(2, 31)));
+    }
+
+    #[test]
+    fn eval_errors() {
+        let vars = vars(&[("x", 0)]);
+        assert_eq!(eval("1 / x", 31u64, &vars), Err(EvalError::DivisionByZero));
+        assert_eq!(eval("x^(-2)", 31u64, &vars), Err(EvalError::DivisionByZero));
+        assert_eq!(eval("z + 1", 31u64, &vars), Err(EvalError::UnknownVariable("z".to_string())));
+        assert_eq!(eval("1 +", 31u64, &vars), Err(EvalError::UnexpectedEnd));
+        assert_eq!(eval("1 + )", 31u64, &vars), Err(EvalError::UnexpectedChar(4, ')')));
+        assert_eq!(eval("(1", 31u64, &vars), Err(EvalError::UnexpectedEnd));
+    }
+
+    #[test]
+    fn bad_orders_fail() {
+        assert_eq!(eval("1 + 2", 0u64, &HashMap::new()), Err(EvalError::InvalidOrder));
+        assert_eq!(eval("1 + 2", -7i32, &HashMap::new()), Err(EvalError::InvalidOrder));
+        assert_eq!(eval("1 + 2", u128::MAX - 158, &HashMap::new()), Err(EvalError::OrderTooLarge));
+        let vars = vars(&[("x", 4)]);
+        assert_eq!(eval("x + 1", 7u64, &vars), Err(EvalError::MismatchedOrder("x".to_string())));
+        assert_eq!(eval("x + 1", 31u64, &vars), Ok(FieldElement::new(5, 31)));
+    }
+
+    #[test]
+    fn parse_works() {
+        assert_eq!(
+            parse("a*b^3"),
+            Ok(Expr::Mul(
+                Box::new(Expr::Var("a".to_string())),
+                Box::new(Expr::Pow(Box::new(Expr::Var("b".to_string())), 3)),
+            ))
+        );
+        assert!(parse("x y").is_err());
+    }
+}
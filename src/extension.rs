@@ -0,0 +1,288 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use crate::{FiniteField, Matrix, Polynomial};
+use crate::polynomial::{div_rem_poly, mul_poly, sub_poly, trim};
+
+/// An element of the extension `F[x]/(f)` of a base field `F` by a monic
+/// irreducible `f` of degree `N`, written `c₀ + c₁x + … + c_{N−1}x^{N−1}`.
+///
+/// Like the order of a [`FieldElement`](crate::FieldElement), the modulus
+/// is carried by each element and must agree between operands; it is stored
+/// as the coefficients below `x^N`. Since the type is itself a
+/// [`FiniteField`], extensions of extensions compose into towers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExtensionField<F, const N: usize> {
+    coeffs: [F; N],
+    modulus: [F; N],
+}
+
+impl<F: FiniteField, const N: usize> ExtensionField<F, N> {
+    /// Builds the element with the given coefficients, lowest degree first,
+    /// modulo `modulus`, which is made monic. Panics unless the modulus has
+    /// degree `N`; irreducibility is not checked.
+    pub fn new(coeffs: [F; N], modulus: &Polynomial<F>) -> Self {
+        assert!(N > 0 && modulus.degree() == Some(N), "modulus must have degree N");
+        let f = modulus.coefficients();
+        let lead_inv = f[N].inverse().unwrap();
+        ExtensionField { coeffs, modulus: std::array::from_fn(|i| f[i] * lead_inv) }
+    }
+
+    /// Embeds a base field element as a constant.
+    pub fn from_base(&self, c: F) -> Self {
+        let mut coeffs = [c.zero(); N];
+        coeffs[0] = c;
+        ExtensionField { coeffs, modulus: self.modulus }
+    }
+
+    pub fn coefficients(&self) -> &[F; N] {
+        &self.coeffs
+    }
+
+    /// Returns the monic modulus, `x^N` included.
+    pub fn modulus(&self) -> Polynomial<F> {
+        let mut f = self.modulus.to_vec();
+        f.push(self.base_one());
+        Polynomial::new(f)
+    }
+
+    pub fn zero(self) -> Self {
+        ExtensionField { coeffs: [self.base_one().zero(); N], modulus: self.modulus }
+    }
+
+    pub fn one(self) -> Self {
+        self.from_base(self.base_one())
+    }
+
+    /// Returns the generator `x` of the extension.
+    pub fn x(self) -> Self {
+        let mut t = self.zero();
+        if N > 1 {
+            t.coeffs[1] = self.base_one();
+        } else {
+            t.coeffs[0] = t.coeffs[0] - self.modulus[0];
+        }
+        t
+    }
+
+    pub fn pow(self, mut exp: u64) -> Self {
+        let mut result = self.one();
+        let mut base = self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    pub fn square(self) -> Self {
+        self * self
+    }
+
+    /// Returns the inverse by the extended Euclidean algorithm on `a` and
+    /// the modulus, or `None` for zero, or when `a` shares a factor with a
+    /// reducible modulus.
+    pub fn inverse(&self) -> Option<Self> {
+        let (mut r0, mut r1) = (self.modulus().coefficients().to_vec(), trim(self.coeffs.to_vec()));
+        let (mut s0, mut s1) = (Vec::new(), vec![self.base_one()]);
+        while !r1.is_empty() {
+            let (q, r) = div_rem_poly(&r0, &r1);
+            let s = sub_poly(&s0, &mul_poly(&q, &s1));
+            (r0, r1) = (r1, r);
+            (s0, s1) = (s1, s);
+        }
+        if r0.len() != 1 {
+            return None;
+        }
+        let g_inv = r0[0].inverse()?;
+        let mut inv = *self;
+        for (i, c) in inv.coeffs.iter_mut().enumerate() {
+            *c = s0.get(i).map_or(g_inv.zero(), |&s| s * g_inv);
+        }
+        Some(inv)
+    }
+
+    /// Returns the matrix of `y ↦ a·y` over `F` in the basis
+    /// `1, x, …, x^{N−1}`, whose column `i` holds the coefficients of `a·xⁱ`.
+    pub fn multiplication_matrix(&self) -> Matrix<F> {
+        let mut m = Matrix::zero(N, N, self.base_one());
+        let mut column = *self;
+        for i in 0..N {
+            for (r, &c) in column.coeffs.iter().enumerate() {
+                m.set(r, i, c);
+            }
+            column = column * self.x();
+        }
+        m
+    }
+
+    /// Returns the norm to `F`, the product of the conjugates of `a`,
+    /// computed as the determinant of its multiplication matrix.
+    pub fn norm(&self) -> F {
+        self.multiplication_matrix().determinant()
+    }
+
+    /// Returns the trace to `F`, the sum of the conjugates of `a`, computed
+    /// as the trace of its multiplication matrix.
+    pub fn trace(&self) -> F {
+        let m = self.multiplication_matrix();
+        (0..N).fold(self.base_one().zero(), |acc, i| acc + m.get(i, i))
+    }
+
+    fn base_one(&self) -> F {
+        // A modulus of degree N has N lower coefficients to borrow from
+        self.modulus[0].one()
+    }
+
+    fn reduce(&self, mut prod: Vec<F>) -> Self {
+        for k in (N..prod.len()).rev() {
+            let lead = prod[k];
+            for i in 0..N {
+                prod[k - N + i] = prod[k - N + i] - lead * self.modulus[i];
+            }
+        }
+        let zero = self.base_one().zero();
+        ExtensionField { coeffs: std::array::from_fn(|i| prod.get(i).copied().unwrap_or(zero)), modulus: self.modulus }
+    }
+}
+
+impl<F: FiniteField, const N: usize> Add for ExtensionField<F, N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        assert_eq!(self.modulus, rhs.modulus);
+        ExtensionField { coeffs: std::array::from_fn(|i| self.coeffs[i] + rhs.coeffs[i]), modulus: self.modulus }
+    }
+}
+
+impl<F: FiniteField, const N: usize> Sub for ExtensionField<F, N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        assert_eq!(self.modulus, rhs.modulus);
+        ExtensionField { coeffs: std::array::from_fn(|i| self.coeffs[i] - rhs.coeffs[i]), modulus: self.modulus }
+    }
+}
+
+impl<F: FiniteField, const N: usize> Mul for ExtensionField<F, N> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        assert_eq!(self.modulus, rhs.modulus);
+        self.reduce(mul_poly(&self.coeffs, &rhs.coeffs))
+    }
+}
+
+impl<F: FiniteField, const N: usize> Div for ExtensionField<F, N> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Mul::mul(self, rhs.inverse().expect("Zero is not valid denominator."))
+    }
+}
+
+impl<F: FiniteField, const N: usize> Neg for ExtensionField<F, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.zero() - self
+    }
+}
+
+impl<F: FiniteField, const N: usize> FiniteField for ExtensionField<F, N> {
+    fn zero(&self) -> Self {
+        ExtensionField::zero(*self)
+    }
+
+    fn one(&self) -> Self {
+        ExtensionField::one(*self)
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        ExtensionField::inverse(self)
+    }
+
+    fn from_u64(&self, n: u64) -> Self {
+        self.from_base(self.base_one().from_u64(n))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FieldElement;
+    use crate::testing::{elements, modulus};
+
+    #[test]
+    fn gf4_works() {
+        // x² + x + 1 over GF(2)
+        let f = modulus(&[1, 1, 1], 2);
+        let xs = elements::<2>(&f);
+        let x = xs[0].x();
+        assert_eq!(x * x, x + x.one());
+        assert_eq!(x.pow(3), x.one());
+        for &a in &xs[1..] {
+            assert_eq!(a * a.inverse().unwrap(), a.one());
+            assert_eq!(a.pow(3), a.one());
+        }
+        assert_eq!(xs[0].inverse(), None);
+    }
+
+    #[test]
+    fn gf9_works() {
+        // x² + 1 over GF(3), given non-monic as 2x² + 2
+        let f = modulus(&[2, 0, 2], 3);
+        let xs = elements::<2>(&f);
+        let i = xs[0].x();
+        assert_eq!(i.modulus(), modulus(&[1, 0, 1], 3));
+        assert_eq!(i * i, -i.one());
+        for &a in &xs[1..] {
+            assert_eq!(a * a.inverse().unwrap(), a.one());
+            assert_eq!(a.pow(8), a.one());
+            for &b in &xs[1..] {
+                assert_eq!(a * b / b, a);
+            }
+        }
+        assert_eq!(i.from_u64(5), -i.one());
+    }
+
+    #[test]
+    fn cubic_extension_works() {
+        // x³ + x + 1 over GF(5)
+        let f = modulus(&[1, 1, 0, 1], 5);
+        let xs = elements::<3>(&f);
+        assert!(xs[1..].iter().all(|a| a.pow(124) == a.one()));
+        assert!(xs[1..].iter().all(|a| *a * a.inverse().unwrap() == a.one()));
+    }
+
+    #[test]
+    fn norm_and_trace_work() {
+        // x³ + x + 1 over GF(5)
+        let f = modulus(&[1, 1, 0, 1], 5);
+        let xs = elements::<3>(&f);
+        let x = xs[0].x();
+        // the roots of f have product −1 and sum 0
+        assert_eq!(x.norm(), FieldElement::new(4, 5));
+        assert_eq!(x.trace(), FieldElement::new(0, 5));
+        for &a in xs.iter().step_by(7) {
+            // N(a) = a^(1 + p + p²) and Tr(a) = a + a^p + a^p²
+            assert_eq!(a.from_base(a.norm()), a.pow(31));
+            assert_eq!(a.from_base(a.trace()), a + a.pow(5) + a.pow(25));
+            for &b in xs.iter().step_by(11) {
+                assert_eq!((a * b).norm(), a.norm() * b.norm());
+                assert_eq!((a + b).trace(), a.trace() + b.trace());
+            }
+        }
+    }
+
+    #[test]
+    fn reducible_modulus_has_zero_divisors() {
+        // x² − 1 = (x − 1)(x + 1) over GF(7)
+        let f = modulus(&[6, 0, 1], 7);
+        let x = ExtensionField::new([FieldElement::new(0, 7), FieldElement::new(1, 7)], &f);
+        assert_eq!((x - x.one()).inverse(), None);
+        assert_eq!(x.inverse(), Some(x));
+    }
+}
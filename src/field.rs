@@ -0,0 +1,105 @@
+use std::{fmt::Debug, ops::{Add, Div, Mul, Sub}};
+use crate::{FieldElement, FieldElementBig, Widening};
+use crypto_bigint::Uint;
+
+/// Operations shared by every field element type, so that polynomials,
+/// matrices and other generic code can be written once for all backends.
+///
+/// The modulus lives in the elements rather than in the type, so the
+/// identities are taken relative to an existing element of the field.
+pub trait FiniteField:
+    Copy + PartialEq + Debug
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self>
+{
+    /// The additive identity of this element's field.
+    fn zero(&self) -> Self;
+
+    /// The multiplicative identity of this element's field.
+    fn one(&self) -> Self;
+
+    fn is_zero(&self) -> bool {
+        *self == self.zero()
+    }
+
+    /// Returns the multiplicative inverse, or `None` for zero.
+    fn inverse(&self) -> Option<Self>;
+
+    /// Maps `n` into this element's field. Like `zero` and `one`, it takes
+    /// `self` only to learn the modulus.
+    #[allow(clippy::wrong_self_convention)]
+    fn from_u64(&self, n: u64) -> Self;
+
+    /// Raises the element to `exp` by square-and-multiply.
+    fn pow_u64(&self, mut exp: u64) -> Self {
+        let mut result = self.one();
+        let mut base = *self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        result
+    }
+}
+
+impl<T> FiniteField for FieldElement<T>
+    where T: Widening + Debug,
+{
+    fn zero(&self) -> Self {
+        FieldElement::zero(*self)
+    }
+
+    fn one(&self) -> Self {
+        FieldElement::one(*self)
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        FieldElement::inverse(self)
+    }
+
+    fn from_u64(&self, n: u64) -> Self {
+        FieldElement::from_uniform_bytes(&n.to_be_bytes(), self.get_order())
+    }
+}
+
+impl<const LIMBS: usize> FiniteField for FieldElementBig<LIMBS> {
+    fn zero(&self) -> Self {
+        self.with_num(Uint::ZERO)
+    }
+
+    fn one(&self) -> Self {
+        self.with_num(Uint::ONE)
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        FieldElementBig::inverse(self)
+    }
+
+    fn from_u64(&self, n: u64) -> Self {
+        self.with_num(Uint::from_u64(n))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crypto_bigint::U256;
+
+    /// Checks Fermat's little theorem and inversion through the trait only.
+    fn check_field<F: FiniteField>(x: F, p: u64) {
+        assert_eq!(x.pow_u64(p - 1), x.one());
+        assert_eq!(x * x.inverse().unwrap(), x.one());
+        assert_eq!(x.zero().inverse(), None);
+        assert_eq!(x.from_u64(p + 3), x.from_u64(3));
+        assert_eq!(x.from_u64(2) + x.from_u64(5), x.from_u64(7));
+    }
+
+    #[test]
+    fn generic_code_works_for_both_backends() {
+        check_field(FieldElement::new(5u32, 10007), 10007);
+        check_field(FieldElementBig::new(U256::from(5u8), U256::from(10007u16)), 10007);
+    }
+}
@@ -1,14 +1,13 @@
-use std::{fmt::Debug, ops::{Add, Div, Mul, Rem, Sub}};
-use num::{One, Zero, Num, Bounded};
-use std::ops::Shr;
-use mod_exp::mod_exp;
+use std::{fmt::{self, Debug, Display}, iter::{Product, Sum}, ops::{Add, Div, Mul, Rem, Sub}, str::FromStr};
+use num::{One, Zero, NumCast, PrimInt};
+use crate::{FieldError, FiniteField, Widening};
 
 /// This crate implements the finite fields for generic types.
 /// The mathematic definitions of finite fields are discussed in Chapter One 
 /// of Programming Bitcoin by Jimmy Song. 
 
 
-#[derive(Debug, PartialEq, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
 pub struct FieldElement<T> 
 {    
     n: T,
@@ -25,18 +24,49 @@ impl<T> FieldElement<T>
             order    
         }   
     }        
+
+    /// Like `new`, but returns an error instead of panicking when the order
+    /// is zero or `n` is outside `[0, order)`.
+    pub fn try_new(n: T, order: T) -> Result<Self, FieldError> {
+        if order == Zero::zero() {
+            Err(FieldError::ZeroModulus)
+        } else if n >= Zero::zero() && n < order {
+            Ok(FieldElement { n, order })
+        } else {
+            Err(FieldError::OutOfRange)
+        }
+    }
+}
+
+impl<T> FieldElement<T>
+    where T: PartialOrd + Zero + Add<Output = T> + Rem<Output = T> + Copy,
+{
+    /// Like `new`, but maps any `n`, including negative values of signed
+    /// types, to its canonical representative in `[0, order)`.
+    pub fn new_reduced(n: T, order: T) -> Self {
+        let r = n % order;
+        let n = if r < Zero::zero() { r + order } else { r };
+        FieldElement {
+            n,
+            order
+        }
+    }
 }
 
 impl<T> Add for FieldElement<T>
-    where T: PartialOrd + Debug + Add<Output = T> + Rem<Output = T> + Copy,
+    where T: PartialOrd + Debug + Add<Output = T> + Sub<Output = T> + Copy,
 {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
         assert_eq!(self.order, rhs.order);
-        let n = self.n + rhs.n;
         let p = self.order;
-        let n = n % p;
+        // `self.n + rhs.n` may overflow when `p` exceeds half the type's range
+        let n = if p - self.n > rhs.n {
+            self.n + rhs.n
+        } else {
+            rhs.n - (p - self.n)
+        };
         FieldElement {
             n,
             order: p     
@@ -52,16 +82,14 @@ impl<T> Sub for FieldElement<T>
     fn sub(self, rhs: Self) -> Self::Output {
         assert_eq!(self.order, rhs.order);
         let p = self.order;
-        let n: T;
-        if self.n < rhs.n {
+        let n = if self.n < rhs.n {
             let t = rhs.n - self.n;    
             let t = t % p;
-            n = p - t;
+            p - t
         } else {
             let t = self.n - rhs.n;
-            let t = t % p;
-            n = t;    
-        }
+            t % p
+        };
         FieldElement {
             n,
             order: p    
@@ -70,13 +98,12 @@ impl<T> Sub for FieldElement<T>
 } 
 
 impl<T> Mul for FieldElement<T>
-    where T: Mul<Output = T> + Rem<Output = T> + Copy,
+    where T: Widening,
 {
     type Output = Self;
     fn mul(self, rhs: Self) -> Self::Output {
-        let n =  self.n * rhs.n;
         let p = self.order;
-        let n = n % p;
+        let n = self.n.mul_mod(rhs.n, p);
         FieldElement {
             n,
             order: p    
@@ -85,8 +112,7 @@ impl<T> Mul for FieldElement<T>
 }    
 
 impl<T> Div for FieldElement<T>
-    where T: Num + PartialOrd + Shr<T, Output = T> + Copy + Bounded,
-          T: Mul<Output = T> + Rem<Output = T> + Zero,
+    where T: Widening,
 {
     type Output = Self;    
     fn div(self, rhs: Self) -> Self::Output {
@@ -94,11 +120,9 @@ impl<T> Div for FieldElement<T>
             panic!("Zero is not valid denominator.")
 
         }    
-        let a = self.n;
-        let b = rhs.n;
         let p = self.order;
-        let fermat = mod_exp(b, p - One::one() - One::one(), p);
-        let n = (a * fermat) % p;
+        let fermat = rhs.pow(p - T::one() - T::one());
+        let n = self.n.mul_mod(fermat.n, p);
 
         FieldElement {
             n,
@@ -108,13 +132,46 @@ impl<T> Div for FieldElement<T>
 }
 
 impl<T> FieldElement<T> 
-    where T: One + Sub<Output = T> + Rem<Output = T> + Add<Output = T> + Copy,
-          T: Num + PartialOrd + Shr<T, Output = T> + Bounded,
+    where T: Widening,
 {
+    pub fn square(self) -> Self {
+        FieldElement {
+            n: self.n.mul_mod(self.n, self.order),
+            order: self.order
+        }
+    }
+
+    pub fn double(self) -> Self {
+        let p = self.order;
+        let n = if p - self.n > self.n {
+            self.n + self.n
+        } else {
+            self.n - (p - self.n)
+        };
+        FieldElement {
+            n,
+            order: p
+        }
+    }
+
+    /// Raises the element to `exp`, reduced modulo `order − 1` so that
+    /// negative exponents invert. Products are formed through
+    /// [`Widening::mul_mod`], so large moduli do not overflow.
     pub fn pow(self, exp: T) -> Self {
         let p = self.order - One::one();
-        let n = (exp % p + p) % p;
-        let n = mod_exp(self.n, n, self.order);
+        let mut e = exp % p;
+        if e < T::zero() {
+            e = e + p;
+        }
+        let mut n = T::one() % self.order;
+        let mut base = self.n;
+        while e > T::zero() {
+            if e & T::one() == T::one() {
+                n = n.mul_mod(base, self.order);
+            }
+            base = base.mul_mod(base, self.order);
+            e = e >> 1;
+        }
 
         FieldElement {
             n,
@@ -123,7 +180,661 @@ impl<T> FieldElement<T>
     }    
 }
 
+/// Arithmetic with plain integers, which are first reduced modulo the
+/// element's order.
+macro_rules! impl_scalar_op {
+    ($op:ident, $method:ident) => {
+        impl<T: Widening + Debug> $op<u64> for FieldElement<T> {
+            type Output = Self;
+
+            fn $method(self, rhs: u64) -> Self::Output {
+                self.$method(self.from_u64(rhs))
+            }
+        }
+
+        impl<T: Widening + Debug> $op<FieldElement<T>> for u64 {
+            type Output = FieldElement<T>;
+
+            fn $method(self, rhs: FieldElement<T>) -> Self::Output {
+                rhs.from_u64(self).$method(rhs)
+            }
+        }
+    };
+}
+
+impl_scalar_op!(Add, add);
+impl_scalar_op!(Sub, sub);
+impl_scalar_op!(Mul, mul);
+
+/// Sums the elements. The modulus is taken from the elements themselves,
+/// so summing an empty iterator panics.
+impl<T> Sum for FieldElement<T>
+    where T: PartialOrd + Debug + Add<Output = T> + Sub<Output = T> + Copy,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.reduce(|a, b| a + b).expect("cannot sum an empty iterator")
+    }
+}
+
+impl<'a, T> Sum<&'a FieldElement<T>> for FieldElement<T>
+    where T: PartialOrd + Debug + Add<Output = T> + Sub<Output = T> + Copy,
+{
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+/// Multiplies the elements; like `sum`, panics on an empty iterator.
+impl<T: Widening> Product for FieldElement<T> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.reduce(|a, b| a * b).expect("cannot multiply an empty iterator")
+    }
+}
+
+impl<'a, T: Widening> Product<&'a FieldElement<T>> for FieldElement<T> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+impl<T: Display> Display for FieldElement<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (mod {})", self.n, self.order)
+    }
+}
+
+/// Hex digits are zero-padded to the byte width of the modulus; `{:#x}`
+/// adds a `0x` prefix.
+macro_rules! impl_hex {
+    ($trait:ident, $spec:literal) => {
+        impl<T: Widening + fmt::$trait> fmt::$trait for FieldElement<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let width = 2 * self.byte_width();
+                if f.alternate() {
+                    f.write_str("0x")?;
+                }
+                write!(f, concat!("{:0width$", $spec, "}"), self.n, width = width)
+            }
+        }
+    };
+}
+
+impl_hex!(LowerHex, "x");
+impl_hex!(UpperHex, "X");
+
+/// Splits `"n mod p"` into its two numbers, each paired with its radix:
+/// 16 for a `0x` prefix, 10 otherwise.
+pub(crate) fn split_literal(s: &str) -> Result<[(&str, u32); 2], FieldError> {
+    fn radix(s: &str) -> (&str, u32) {
+        let s = s.trim();
+        match strip_hex_prefix(s) {
+            Some(digits) => (digits, 16),
+            None => (s, 10),
+        }
+    }
+    let (n, p) = s.split_once(" mod ").ok_or(FieldError::Parse)?;
+    Ok([radix(n), radix(p)])
+}
+
+/// Strips a `0x` or `0X` prefix.
+pub(crate) fn strip_hex_prefix(s: &str) -> Option<&str> {
+    s.strip_prefix("0x").or_else(|| s.strip_prefix("0X"))
+}
+
+/// Parses `"14 mod 17"` or `"0x1f mod 0xfb"`. The number must already be
+/// reduced, as in `try_new`.
+impl<T: Widening> FromStr for FieldElement<T> {
+    type Err = FieldError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [(n, n_radix), (p, p_radix)] = split_literal(s)?;
+        let n = T::from_str_radix(n, n_radix).map_err(|_| FieldError::Parse)?;
+        let p = T::from_str_radix(p, p_radix).map_err(|_| FieldError::Parse)?;
+        FieldElement::try_new(n, p)
+    }
+}
+
+/// Rounding rule used by [`FieldElement::mod_switch`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Rounding {
+    /// Round to the nearest integer, halves rounding up.
+    Nearest,
+    Down,
+    Up,
+}
+
+impl<T> FieldElement<T>
+    where T: Widening,
+{
+    /// Scales the element from modulus `q` to modulus `to`, returning
+    /// `round(n * to / q) mod to` under the given rounding rule. The
+    /// product `n * to` is formed as a [`BigUint`](num::BigUint), since it
+    /// overflows `u128` for large orders.
+    pub fn mod_switch(&self, to: T, rounding: Rounding) -> Self {
+        use num::{BigUint, ToPrimitive};
+        let q = BigUint::from(self.order.to_u128().expect("order does not fit in u128"));
+        let to_wide = BigUint::from(to.to_u128().expect("order does not fit in u128"));
+        let num = BigUint::from(self.n.to_u128().unwrap()) * &to_wide;
+        let (floor, rem) = (&num / &q, &num % &q);
+        let n = match rounding {
+            Rounding::Nearest if rem.clone() * 2u8 >= q => floor + 1u8,
+            Rounding::Up if rem > BigUint::zero() => floor + 1u8,
+            _ => floor,
+        };
+        FieldElement {
+            n: NumCast::from((n % to_wide).to_u128().unwrap()).unwrap(),
+            order: to
+        }
+    }
+
+    /// Returns the balanced representative in `(−p/2, p/2]`.
+    pub fn lift_centered(&self) -> i128 {
+        let p = self.order.to_i128().expect("order does not fit in i128");
+        let n = self.n.to_i128().unwrap();
+        if n > p / 2 {
+            n - p
+        } else {
+            n
+        }
+    }
+
+    /// Builds the element congruent to a signed value.
+    pub fn from_signed(value: i128, order: T) -> Self {
+        let p = order.to_i128().expect("order does not fit in i128");
+        FieldElement {
+            n: NumCast::from(value.rem_euclid(p)).unwrap(),
+            order
+        }
+    }
+
+    /// Reduces a big-endian byte string modulo `order`. With at least 16
+    /// more bytes than the modulus width the result is close to uniform.
+    pub fn from_uniform_bytes(bytes: &[u8], order: T) -> Self {
+        let p = order.to_u128().expect("order does not fit in u128");
+        let digit = |n: u128| -> T { NumCast::from(n % p).unwrap() };
+        let radix = digit(256);
+        // acc·256 + b by a widening product, as acc·256 overflows for p > 2^120
+        let n = bytes.iter().fold(T::zero(), |acc, &b| {
+            let (shifted, b) = (acc.mul_mod(radix, order), digit(b as u128));
+            if order - shifted > b {
+                shifted + b
+            } else {
+                b - (order - shifted)
+            }
+        });
+        FieldElement {
+            n,
+            order
+        }
+    }
+
+    /// Returns the number of bytes needed to hold the modulus, which is the
+    /// width of the byte and hex encodings.
+    pub fn byte_width(&self) -> usize {
+        let bits = self.order.count_zeros() + self.order.count_ones() - self.order.leading_zeros();
+        (bits as usize).div_ceil(8)
+    }
+
+    /// Encodes the representative as `byte_width()` big-endian bytes.
+    pub fn to_be_bytes(&self) -> Vec<u8> {
+        let n = self.n.to_u128().unwrap().to_be_bytes();
+        n[16 - self.byte_width()..].to_vec()
+    }
+
+    pub fn to_le_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.to_be_bytes();
+        bytes.reverse();
+        bytes
+    }
+
+    /// Decodes `byte_width()` big-endian bytes, rejecting values that are
+    /// not below `order`.
+    pub fn from_be_bytes(bytes: &[u8], order: T) -> Result<Self, FieldError> {
+        let width = FieldElement { n: T::zero(), order }.byte_width();
+        if bytes.len() != width {
+            return Err(FieldError::InvalidLength);
+        }
+        let n = bytes.iter().fold(0u128, |acc, &b| (acc << 8) | b as u128);
+        FieldElement::try_new(NumCast::from(n).ok_or(FieldError::OutOfRange)?, order)
+    }
+
+    pub fn from_le_bytes(bytes: &[u8], order: T) -> Result<Self, FieldError> {
+        let mut bytes = bytes.to_vec();
+        bytes.reverse();
+        Self::from_be_bytes(&bytes, order)
+    }
+
+    /// Returns the lowercase hex encoding, `2 * byte_width()` digits long.
+    pub fn to_hex(&self) -> String
+        where T: fmt::LowerHex,
+    {
+        format!("{:x}", self)
+    }
+
+    /// Parses hex digits, with or without a `0x` or `0X` prefix as in
+    /// `FromStr`, rejecting values that are not below `order`.
+    pub fn from_hex(hex: &str, order: T) -> Result<Self, FieldError> {
+        let digits = strip_hex_prefix(hex).unwrap_or(hex);
+        let n = T::from_str_radix(digits, 16).map_err(|_| FieldError::Parse)?;
+        FieldElement::try_new(n, order)
+    }
+
+    /// Like `try_new`, but also rejects orders that fail a Miller–Rabin
+    /// primality test, so that division is guaranteed to be correct.
+    #[cfg(feature = "primality")]
+    pub fn new_checked_prime(n: T, order: T) -> Result<Self, FieldError> {
+        let element = FieldElement::try_new(n, order)?;
+        if element.is_field() {
+            Ok(element)
+        } else {
+            Err(FieldError::NonPrimeModulus)
+        }
+    }
+
+    /// Tests whether the order is prime, i.e. whether this is really an
+    /// element of a field rather than of a ring.
+    #[cfg(feature = "primality")]
+    pub fn is_field(&self) -> bool {
+        self.order.to_u128().is_some_and(|p| crate::primality::is_prime(&crypto_bigint::U128::from_u128(p)))
+    }
+
+    /// Returns the multiplicative inverse by the extended Euclidean
+    /// algorithm, or `None` when `gcd(n, order) ≠ 1`. Unlike division, this
+    /// is correct for non-prime moduli as well.
+    pub fn inverse(&self) -> Option<Self> {
+        let p = self.order.to_u128().expect("order does not fit in u128");
+        let (mut r0, mut r1) = (p, self.n.to_u128().unwrap());
+        // The Bézout coefficients alternate in sign and are bounded by p, so
+        // only their magnitudes are kept, `negative` being the sign of s0
+        let (mut s0, mut s1) = (0u128, 1u128);
+        let mut negative = true;
+        while r1 != 0 {
+            let q = r0 / r1;
+            (r0, r1) = (r1, r0 - q * r1);
+            (s0, s1) = (s1, s0 + q * s1);
+            negative = !negative;
+        }
+        if r0 != 1 {
+            return None;
+        }
+        let n = if negative && s0 != 0 { p - s0 } else { s0 };
+        Some(FieldElement {
+            n: NumCast::from(n).unwrap(),
+            order: self.order
+        })
+    }
+}
+
+impl<T> FieldElement<T>
+    where T: Widening + Debug,
+{
+    fn find_non_power(order: T, k: T) -> Option<Self> {
+        let one = T::one();
+        if order <= one + one || (order - one) % k != T::zero() {
+            return None;
+        }
+        let exp = (order - one) / k;
+        let mut a = one + one;
+        while a < order {
+            let candidate = FieldElement::new(a, order);
+            if candidate.pow(exp).n != one {
+                return Some(candidate);
+            }
+            a = a + one;
+        }
+        None
+    }
+
+    /// Embeds the fraction `a / b` as `a * b⁻¹ mod p`, or returns `None`
+    /// if `b` is divisible by `p`.
+    pub fn encode_rational(a: i64, b: u64, order: T) -> Option<Self> {
+        let p = order.to_i128().expect("order does not fit in i128");
+        let den = (b as i128) % p;
+        if den == 0 {
+            return None;
+        }
+        let num = ((a as i128) % p + p) % p;
+        let num = FieldElement::new(NumCast::from(num).unwrap(), order);
+        let den = FieldElement::new(NumCast::from(den).unwrap(), order);
+        Some(num / den)
+    }
+
+    /// Recovers the fraction `a / b` with `|a|, b ≤ √(p/2)` that encodes to
+    /// this element, by running the extended Euclidean algorithm on `(p, n)`.
+    /// Such a fraction is unique when it exists.
+    pub fn decode_rational(&self) -> Option<(i64, u64)> {
+        let p = self.order.to_i128().expect("order does not fit in i128");
+        let bound = num::integer::sqrt(p / 2);
+
+        let (mut r0, mut r1) = (p, self.n.to_i128().unwrap());
+        let (mut s0, mut s1) = (0i128, 1i128);
+        while r1 > bound {
+            let q = r0 / r1;
+            (r0, r1) = (r1, r0 - q * r1);
+            (s0, s1) = (s1, s0 - q * s1);
+        }
+        if s1.abs() > bound || num::integer::gcd(r1, s1) != 1 {
+            return None;
+        }
+        let a = if s1 < 0 { -r1 } else { r1 };
+        Some((a as i64, s1.unsigned_abs() as u64))
+    }
+
+    /// Returns the smallest quadratic non-residue, as needed to build Fp2 as
+    /// Fp[u]/(u² − β). Returns `None` for p = 2.
+    pub fn find_nonresidue(order: T) -> Option<Self> {
+        Self::find_non_power(order, T::one() + T::one())
+    }
+
+    fn check_order(&self, rhs: &Self) -> Result<(), FieldError> {
+        if self.order == rhs.order {
+            Ok(())
+        } else {
+            Err(FieldError::MismatchedOrders)
+        }
+    }
+
+    /// Adds, returning an error instead of panicking on mismatched orders.
+    pub fn checked_add(self, rhs: Self) -> Result<Self, FieldError> {
+        self.check_order(&rhs)?;
+        Ok(self + rhs)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Result<Self, FieldError> {
+        self.check_order(&rhs)?;
+        Ok(self - rhs)
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Result<Self, FieldError> {
+        self.check_order(&rhs)?;
+        Ok(self * rhs)
+    }
+
+    /// Divides through [`FieldElement::inverse`], so a non-invertible
+    /// denominator is an error rather than a panic.
+    pub fn checked_div(self, rhs: Self) -> Result<Self, FieldError> {
+        self.check_order(&rhs)?;
+        Ok(self * rhs.inverse().ok_or(FieldError::NotInvertible)?)
+    }
+
+    /// Adds without comparing the orders outside debug builds, for inner
+    /// loops where the operands are known to share a field.
+    pub fn unchecked_add(self, rhs: Self) -> Self {
+        debug_assert_eq!(self.order, rhs.order);
+        let n = if self.order - self.n > rhs.n {
+            self.n + rhs.n
+        } else {
+            rhs.n - (self.order - self.n)
+        };
+        FieldElement {
+            n,
+            order: self.order
+        }
+    }
+
+    pub fn unchecked_sub(self, rhs: Self) -> Self {
+        debug_assert_eq!(self.order, rhs.order);
+        let n = if self.n < rhs.n {
+            self.order - (rhs.n - self.n)
+        } else {
+            self.n - rhs.n
+        };
+        FieldElement {
+            n,
+            order: self.order
+        }
+    }
+
+    pub fn unchecked_mul(self, rhs: Self) -> Self {
+        debug_assert_eq!(self.order, rhs.order);
+        FieldElement {
+            n: self.n.mul_mod(rhs.n, self.order),
+            order: self.order
+        }
+    }
+
+    /// Returns the Legendre symbol `(n/p)`: `0` for zero, `1` for non-zero
+    /// squares and `−1` otherwise, by Euler's criterion. The order must be an
+    /// odd prime.
+    pub fn legendre(&self) -> i8 {
+        let one = T::one();
+        if self.n == T::zero() {
+            0
+        } else if self.pow((self.order - one) / (one + one)).n == one {
+            1
+        } else {
+            -1
+        }
+    }
+
+    pub fn is_quadratic_residue(&self) -> bool {
+        self.legendre() >= 0
+    }
+
+    /// Returns a square root, or `None` for non-residues. The order must be
+    /// prime. Uses `a^((p+1)/4)` when `p ≡ 3 mod 4`, with the exponent
+    /// taken as `⌊p/4⌋ + 1` so that it cannot overflow, and otherwise
+    /// Tonelli–Shanks, or Cipolla when the 2-adicity `s` of `p − 1` is so
+    /// large that the `O(s²)` steps of Tonelli–Shanks dominate.
+    pub fn sqrt(&self) -> Option<Self> {
+        let one = T::one();
+        let two = one + one;
+        let p = self.order;
+        if self.n == T::zero() || p == two {
+            return Some(*self);
+        }
+        if !self.is_quadratic_residue() {
+            return None;
+        }
+        if p % (two + two) == two + one {
+            return Some(self.pow(p / (two + two) + one));
+        }
+        let s = (p - one).trailing_zeros();
+        let bits = T::zero().count_zeros() - p.leading_zeros();
+        if s * s > 8 * bits {
+            self.sqrt_cipolla()
+        } else {
+            self.tonelli_shanks(s)
+        }
+    }
+
+    /// Tonelli–Shanks for a non-zero residue, where `p − 1 = q·2^s`.
+    fn tonelli_shanks(&self, s: u32) -> Option<Self> {
+        let one = T::one();
+        let q = (self.order - one).unsigned_shr(s);
+        let mut m = s;
+        let mut c = Self::find_nonresidue(self.order)?.pow(q);
+        let mut t = self.pow(q);
+        let mut r = self.pow(q / (one + one) + one);
+        while t.n != one {
+            let mut i = 0;
+            let mut t2 = t;
+            while t2.n != one {
+                t2 = t2 * t2;
+                i += 1;
+            }
+            let mut b = c;
+            for _ in 0..m - i - 1 {
+                b = b * b;
+            }
+            m = i;
+            c = b * b;
+            t = t * c;
+            r = r * b;
+        }
+        Some(r)
+    }
+
+    /// Returns a square root by Cipolla's algorithm, or `None` for
+    /// non-residues: `(a + ω)^((p+1)/2)` lies in Fp, where `ω² = a² − n` for
+    /// the first `a` making that a non-residue. The cost does not depend on
+    /// the 2-adicity of `p − 1`.
+    pub fn sqrt_cipolla(&self) -> Option<Self> {
+        let one = T::one();
+        let two = one + one;
+        if self.n == T::zero() || self.order == two {
+            return Some(*self);
+        }
+        if !self.is_quadratic_residue() {
+            return None;
+        }
+        let mut a = self.one();
+        while (a * a - *self).is_quadratic_residue() {
+            a = a + a.one();
+        }
+        let w = a * a - *self;
+        let mul = |(x1, y1): (Self, Self), (x2, y2): (Self, Self)| (x1 * x2 + y1 * y2 * w, x1 * y2 + x2 * y1);
+
+        let mut result = (self.one(), self.zero());
+        let mut base = (a, self.one());
+        // (p + 1)/2 without overflowing T for p near T::MAX
+        let mut exp = self.order / two + one;
+        while exp > T::zero() {
+            if exp & one == one {
+                result = mul(result, base);
+            }
+            base = mul(base, base);
+            exp = exp / two;
+        }
+        Some(result.0)
+    }
+
+    /// Returns some `x` with `xⁿ = a`, or `None` if there is none. The order
+    /// must be prime. Write `n = n₁n₂` where the primes of `n₁` all divide
+    /// `p − 1` and `n₂` is coprime to it. Roots are extracted one prime
+    /// factor `r` of `n₁` at a time, with multiplicity, in the
+    /// Adleman–Manders–Miller style at a cost linear in `r`, and `n₂` is
+    /// inverted modulo `p − 1`.
+    pub fn nth_root(&self, n: u64) -> Option<Self> {
+        let one = self.one();
+        if n == 0 {
+            return if *self == one { Some(one) } else { None };
+        }
+        if self.n == T::zero() {
+            return Some(*self);
+        }
+        let m = self.order - T::one();
+        // divisors of m, which fit in T
+        let exp = |e: u64| -> T { NumCast::from(e).unwrap() };
+        let d = num::integer::gcd(n, rem_u64(m, n));
+        if self.pow(m / exp(d)) != one {
+            return None;
+        }
+
+        let (mut smooth, mut coprime) = (1, n);
+        let mut g = d;
+        while g > 1 {
+            smooth *= g;
+            coprime /= g;
+            g = num::integer::gcd(coprime, rem_u64(m, coprime));
+        }
+        let mut root = *self;
+        let mut rest = smooth;
+        for r in primes::factors(smooth) {
+            rest /= r;
+            root = root.prime_root(r, m, num::integer::gcd(rest, rem_u64(m, rest)))?;
+        }
+        // a coprime too wide for T exceeds m, which then fits in u64
+        let coprime = match <T as NumCast>::from(coprime) {
+            Some(c) => c % m,
+            None => exp(coprime % m.to_u64().unwrap()),
+        };
+        let k = FieldElement::new(coprime, m).inverse()?;
+        Some(root.pow(k.get_num()))
+    }
+
+    /// Returns an `r`-th root for a prime `r` dividing `m = p − 1`, chosen
+    /// among the `r` candidates to be a `rest`-th power itself, for `rest`
+    /// dividing `m`.
+    fn prime_root(&self, r: u64, m: T, rest: u64) -> Option<Self> {
+        let one = self.one();
+        let exp = |e: u64| -> T { NumCast::from(e).unwrap() };
+        let r_t = exp(r);
+        // m = r^t·s with r ∤ s
+        let (mut s, mut t) = (m, 0);
+        while s % r_t == T::zero() {
+            s = s / r_t;
+            t += 1;
+        }
+        let mut z = one + one;
+        while z.pow(m / r_t) == one {
+            z = z + one;
+        }
+        // g generates the Sylow r-subgroup and ζ has order r
+        let g = z.pow(s);
+        let zeta = g.pow(r_t.pow(t - 1));
+
+        // with αr ≡ 1 mod s, y^r = a·e for some e in the Sylow subgroup
+        let alpha = FieldElement::new(r_t % s, s).inverse()?.get_num();
+        let y = self.pow(alpha);
+        let e = y.pow(r_t % m) / *self;
+        let mut j = T::zero();
+        for i in 0..t {
+            let h = (e / g.pow(j)).pow(r_t.pow(t - 1 - i));
+            let digit = (0..r).find(|&c| zeta.pow(exp(c)) == h)?;
+            j = j + exp(digit) * r_t.pow(i);
+        }
+        if j % r_t != T::zero() {
+            return None;
+        }
+
+        let mut root = y / g.pow(j / r_t);
+        let m_over_rest = m / exp(rest);
+        for _ in 0..r {
+            if root.pow(m_over_rest) == one {
+                return Some(root);
+            }
+            root = root * zeta;
+        }
+        None
+    }
+
+    /// Returns the smallest cubic non-residue, as needed to build Fp3 as
+    /// Fp[u]/(u³ − β). Returns `None` unless p ≡ 1 mod 3, since otherwise
+    /// every element is a cube.
+    pub fn find_cubic_nonresidue(order: T) -> Option<Self> {
+        Self::find_non_power(order, T::one() + T::one() + T::one())
+    }
+}
+
+/// Returns `m mod x`, which fits in `u64` whatever the width of `T`.
+fn rem_u64<T: PrimInt>(m: T, x: u64) -> u64 {
+    match <T as NumCast>::from(x) {
+        Some(x) => (m % x).to_u64().unwrap(),
+        // x exceeds T::MAX, so m < x
+        None => m.to_u64().unwrap(),
+    }
+}
+
 impl<T> FieldElement<T> {
+    pub fn get_num(&self) -> T
+        where T: Copy
+    {
+        self.n
+    }
+
+    pub fn get_order(&self) -> T
+        where T: Copy
+    {
+        self.order
+    }
+
+    /// Moves the element into the field of order `order` by reducing its
+    /// canonical representative modulo the new order.
+    pub fn rebase_into(&self, order: T) -> Self
+        where T: PartialOrd + Zero + Rem<Output = T> + Copy
+    {
+        FieldElement::new(self.n % order, order)
+    }
+
+    /// Moves the element into the field of order `order`, failing if its
+    /// canonical representative is not below the new order.
+    pub fn try_rebase_into(&self, order: T) -> Result<Self, FieldError>
+        where T: PartialOrd + Zero + Copy
+    {
+        FieldElement::try_new(self.n, order)
+    }
+
     pub fn one(self) -> FieldElement<T> 
         where T: One,    
     {
@@ -135,6 +846,31 @@ impl<T> FieldElement<T> {
     {
         FieldElement { n: Zero::zero(), order: self.order }    
     }    
+
+    pub fn is_zero(&self) -> bool
+        where T: Zero,
+    {
+        self.n.is_zero()
+    }
+
+    pub fn is_one(&self) -> bool
+        where T: One + PartialEq,
+    {
+        self.n.is_one()
+    }
+
+    /// Whether the canonical representative in `[0, order)` is even.
+    pub fn is_even(&self) -> bool
+        where T: Widening,
+    {
+        self.n & T::one() == T::zero()
+    }
+
+    pub fn is_odd(&self) -> bool
+        where T: Widening,
+    {
+        !self.is_even()
+    }
 }
 
 
@@ -154,6 +890,103 @@ mod tests {
         assert_eq!(a, b);
     }    
 
+    #[test]
+    fn try_new_works() {
+        assert_eq!(FieldElement::try_new(3i8, 5), Ok(FieldElement::new(3, 5)));
+        assert_eq!(FieldElement::try_new(5i8, 5), Err(FieldError::OutOfRange));
+        assert_eq!(FieldElement::try_new(-1i8, 5), Err(FieldError::OutOfRange));
+        assert_eq!(FieldElement::try_new(0u32, 0), Err(FieldError::ZeroModulus));
+    }
+
+    #[test]
+    fn display_works() {
+        assert_eq!(FieldElement::new(3u8, 7).to_string(), "3 (mod 7)");
+        assert_eq!(FieldElement::new(14i64, 17).to_string(), "14 (mod 17)");
+    }
+
+    #[test]
+    fn hex_works() {
+        let a: FieldElement<u32> = FieldElement::new(0x1f, 0xfb);
+        assert_eq!(format!("{:x}", a), "1f");
+        let b: FieldElement<u32> = FieldElement::new(0xab, 0x1_0001);
+        assert_eq!(format!("{:x}", b), "0000ab");
+        assert_eq!(format!("{:#X}", b), "0x0000AB");
+    }
+
+    #[test]
+    fn from_str_works() {
+        assert_eq!("14 mod 17".parse(), Ok(FieldElement::new(14u32, 17)));
+        assert_eq!("0x1f mod 0xfb".parse(), Ok(FieldElement::new(31u8, 251)));
+        let a: FieldElement<i64> = FieldElement::new(3, 7);
+        assert_eq!(a.to_string().replace("(mod", "mod").replace(')', "").parse(), Ok(a));
+        assert_eq!("17 mod 17".parse::<FieldElement<u32>>(), Err(FieldError::OutOfRange));
+        assert_eq!("256 mod 257".parse::<FieldElement<u8>>(), Err(FieldError::Parse));
+        assert_eq!("14 (mod 17)".parse::<FieldElement<u32>>(), Err(FieldError::Parse));
+    }
+
+    #[test]
+    fn hash_works() {
+        use std::collections::HashSet;
+        let set: HashSet<_> = (0..20u32).map(|n| FieldElement::new(n % 7, 7)).collect();
+        assert_eq!(set.len(), 7);
+        assert!(set.contains(&FieldElement::new(3, 7)));
+        assert!(!set.contains(&FieldElement::new(3, 11)));
+    }
+
+    #[test]
+    fn sum_and_product_work() {
+        let coeffs: Vec<FieldElement<u32>> = (1..=6).map(|n| FieldElement::new(n, 7)).collect();
+        assert_eq!(coeffs.iter().copied().sum::<FieldElement<u32>>(), FieldElement::new(0, 7));
+        assert_eq!(coeffs.iter().sum::<FieldElement<u32>>(), FieldElement::new(0, 7));
+        // Wilson's theorem: (p − 1)! ≡ −1
+        assert_eq!(coeffs.iter().product::<FieldElement<u32>>(), FieldElement::new(6, 7));
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_sum_panics() {
+        let _: FieldElement<u32> = std::iter::empty::<FieldElement<u32>>().sum();
+    }
+
+    #[test]
+    fn scalar_ops_work() {
+        let a: FieldElement<u32> = FieldElement::new(5, 7);
+        assert_eq!(a * 3, FieldElement::new(1, 7));
+        assert_eq!(a + 2, a.zero());
+        assert_eq!(a - 12, a.zero());
+        assert_eq!(3 * a, a * 3);
+        assert_eq!(1 - a, FieldElement::new(3, 7));
+        let b: FieldElement<u8> = FieldElement::new(250, 251);
+        assert_eq!(b + u64::MAX, FieldElement::new(((u64::MAX - 1) % 251) as u8, 251));
+    }
+
+    #[test]
+    fn square_and_double_work() {
+        let a: FieldElement<u8> = FieldElement::new(250, 251);
+        assert_eq!(a.square(), a.one());
+        assert_eq!(a.double(), FieldElement::new(249, 251));
+        let b: FieldElement<u64> = FieldElement::new(3, 18446744073709551557);
+        assert_eq!(b.square(), b * b);
+        assert_eq!(b.double(), b + b);
+    }
+
+    #[test]
+    fn predicates_work() {
+        let a: FieldElement<i32> = FieldElement::new(6, 7);
+        assert!(a.is_even() && !a.is_odd());
+        assert!((a + a).is_odd());
+        assert!(a.zero().is_zero() && !a.is_zero());
+        assert!(a.one().is_one() && !a.is_one());
+    }
+
+    #[test]
+    fn new_reduced_works() {
+        assert_eq!(FieldElement::new_reduced(-3i32, 7), FieldElement::new(4, 7));
+        assert_eq!(FieldElement::new_reduced(-14i32, 7), FieldElement::new(0, 7));
+        assert_eq!(FieldElement::new_reduced(-128i8, 127), FieldElement::new(126, 127));
+        assert_eq!(FieldElement::new_reduced(25u8, 17), FieldElement::new(8, 17));
+    }
+
    #[test]
     fn add_i32_works() {
         let a: FieldElement<i32> = FieldElement::new(7, 19);   
@@ -232,4 +1065,276 @@ mod tests {
         assert_eq!(a + zero, a);            
     }    
 
+    #[test]
+    fn find_nonresidue_works() {
+        assert_eq!(FieldElement::find_nonresidue(7u32), Some(FieldElement::new(3, 7)));
+        assert_eq!(FieldElement::find_nonresidue(17u32), Some(FieldElement::new(3, 17)));
+        assert_eq!(FieldElement::find_nonresidue(2u32), None);
+        assert_eq!(FieldElement::find_cubic_nonresidue(7u32), Some(FieldElement::new(2, 7)));
+        assert_eq!(FieldElement::find_cubic_nonresidue(5u32), None);
+    }    
+
+    #[test]
+    fn rebase_works() {
+        let a: FieldElement<u32> = FieldElement::new(20, 23);
+        assert_eq!(a.rebase_into(29), FieldElement::new(20, 29));
+        assert_eq!(a.rebase_into(17), FieldElement::new(3, 17));
+        assert_eq!(a.try_rebase_into(29), Ok(FieldElement::new(20, 29)));
+        assert_eq!(a.try_rebase_into(17), Err(FieldError::OutOfRange));
+    }    
+
+    #[test]
+    fn mod_switch_works() {
+        let a: FieldElement<u64> = FieldElement::new(100, 257);
+        // 100 * 17 / 257 = 6.61
+        assert_eq!(a.mod_switch(17, Rounding::Nearest), FieldElement::new(7, 17));
+        assert_eq!(a.mod_switch(17, Rounding::Down), FieldElement::new(6, 17));
+        assert_eq!(a.mod_switch(17, Rounding::Up), FieldElement::new(7, 17));
+
+        let b: FieldElement<u64> = FieldElement::new(256, 257);
+        assert_eq!(b.mod_switch(17, Rounding::Nearest), FieldElement::new(0, 17));
+        assert_eq!(b.mod_switch(17, Rounding::Down), FieldElement::new(16, 17));
+
+        // n * to exceeds u128: (q − 1)·t/q = t − t/q
+        let (q, t) = ((1u128 << 127) - 1, (1u128 << 100) + 7);
+        let c = FieldElement::new(q - 1, q);
+        assert_eq!(c.mod_switch(t, Rounding::Nearest), FieldElement::new(0, t));
+        assert_eq!(c.mod_switch(t, Rounding::Down), FieldElement::new(t - 1, t));
+        assert_eq!(FieldElement::new(q / 2 + 1, q).mod_switch(t, Rounding::Up), FieldElement::new(t / 2 + 1, t));
+    }    
+
+    #[test]
+    fn rational_round_trip_works() {
+        let a: FieldElement<u64> = FieldElement::encode_rational(-3, 7, 10007).unwrap();
+        assert_eq!(a * FieldElement::new(7, 10007), FieldElement::new(10004, 10007));
+        assert_eq!(a.decode_rational(), Some((-3, 7)));
+
+        let b: FieldElement<u64> = FieldElement::encode_rational(70, 69, 10007).unwrap();
+        assert_eq!(b.decode_rational(), Some((70, 69)));
+        assert_eq!(FieldElement::new(0u64, 10007).decode_rational(), Some((0, 1)));
+        assert_eq!(FieldElement::new(100u64, 10007).decode_rational(), None);
+        assert_eq!(FieldElement::<u64>::encode_rational(1, 10007, 10007), None);
+    }    
+
+    #[test]
+    fn lift_centered_works() {
+        let a: FieldElement<u8> = FieldElement::new(9, 17);
+        let b: FieldElement<u8> = FieldElement::new(8, 17);
+        assert_eq!(a.lift_centered(), -8);
+        assert_eq!(b.lift_centered(), 8);
+        assert_eq!(FieldElement::new(1u8, 2).lift_centered(), 1);
+        assert_eq!(FieldElement::from_signed(-8, 17u8), a);
+        assert_eq!(FieldElement::from_signed(-25, 17u8), a);
+        assert_eq!(FieldElement::from_signed(a.lift_centered(), 17u8), a);
+    }    
+
+    #[test]
+    fn bytes_work() {
+        let a: FieldElement<u32> = FieldElement::new(0x1234, 0x1_0001);
+        assert_eq!(a.to_be_bytes(), vec![0x00, 0x12, 0x34]);
+        assert_eq!(a.to_le_bytes(), vec![0x34, 0x12, 0x00]);
+        assert_eq!(FieldElement::from_be_bytes(&a.to_be_bytes(), 0x1_0001), Ok(a));
+        assert_eq!(FieldElement::from_le_bytes(&a.to_le_bytes(), 0x1_0001), Ok(a));
+        assert_eq!(FieldElement::from_be_bytes(&[0x12, 0x34], 0x1_0001u32), Err(FieldError::InvalidLength));
+        assert_eq!(FieldElement::from_be_bytes(&[0x01, 0x00, 0x01], 0x1_0001u32), Err(FieldError::OutOfRange));
+        assert_eq!(FieldElement::from_be_bytes(&[0xff], 251u8), Err(FieldError::OutOfRange));
+    }
+
+    #[test]
+    fn hex_helpers_work() {
+        let a: FieldElement<u32> = FieldElement::new(0xab, 0x1_0001);
+        assert_eq!(a.to_hex(), "0000ab");
+        assert_eq!(FieldElement::from_hex(&a.to_hex(), 0x1_0001), Ok(a));
+        assert_eq!(FieldElement::from_hex("0xAB", 0x1_0001), Ok(a));
+        assert_eq!(FieldElement::from_hex("0X1f", 251u8), Ok(FieldElement::new(0x1f, 251)));
+        assert_eq!(FieldElement::from_hex("0X1f", 251u8), "0X1f mod 251".parse());
+        assert_eq!(FieldElement::from_hex("fb", 251u8), Err(FieldError::OutOfRange));
+        assert_eq!(FieldElement::from_hex("xyz", 251u8), Err(FieldError::Parse));
+    }
+
+    #[test]
+    fn from_uniform_bytes_works() {
+        let a: FieldElement<u32> = FieldElement::from_uniform_bytes(&[1, 0], 251);
+        assert_eq!(a, FieldElement::new(5, 251));
+        let b: FieldElement<u32> = FieldElement::from_uniform_bytes(&[], 251);
+        assert_eq!(b, FieldElement::new(0, 251));
+
+        // orders above 2^120, checked against BigUint
+        use num::BigUint;
+        let p = (1u128 << 127) - 1;
+        for bytes in [[0xab; 32], [0xff; 32]] {
+            let expected = BigUint::from_bytes_be(&bytes) % BigUint::from(p);
+            let c = FieldElement::from_uniform_bytes(&bytes, p);
+            assert_eq!(BigUint::from(c.get_num()), expected);
+        }
+        let c = FieldElement::from_uniform_bytes(&[0xab; 32], p);
+        assert_eq!(c.get_num(), 4003321963775746628980877734491390725);
+        let tiny: FieldElement<u8> = FieldElement::from_uniform_bytes(&[0xff, 0xff], 251);
+        assert_eq!(tiny.get_num(), (0xffff % 251) as u8);
+    }    
+
+    #[test]
+    fn checked_ops_work() {
+        let a: FieldElement<u32> = FieldElement::new(3, 7);
+        let b: FieldElement<u32> = FieldElement::new(5, 7);
+        let c: FieldElement<u32> = FieldElement::new(5, 11);
+        assert_eq!(a.checked_add(b), Ok(a + b));
+        assert_eq!(a.checked_sub(b), Ok(a - b));
+        assert_eq!(a.checked_mul(b), Ok(a * b));
+        assert_eq!(a.checked_div(b), Ok(a / b));
+        assert_eq!(a.checked_add(c), Err(FieldError::MismatchedOrders));
+        assert_eq!(a.checked_div(a.zero()), Err(FieldError::NotInvertible));
+
+        // a prime order above i128::MAX
+        let p = u128::MAX - 158;
+        let x = FieldElement::new(u128::MAX / 3, p);
+        let y = FieldElement::new(12345, p);
+        assert_eq!(x * x.inverse().unwrap(), x.one());
+        assert_eq!(x.checked_div(y), Ok(x / y));
+        assert_eq!(FiniteField::inverse(&y), Some(y.one() / y));
+    }
+
+    #[test]
+    fn unchecked_ops_work() {
+        let a: FieldElement<u32> = FieldElement::new(3, 7);
+        let b: FieldElement<u32> = FieldElement::new(5, 7);
+        assert_eq!(a.unchecked_add(b), a + b);
+        assert_eq!(a.unchecked_sub(b), a - b);
+        assert_eq!(b.unchecked_sub(a), b - a);
+        assert_eq!(a.unchecked_mul(b), a * b);
+    }
+
+    #[test]
+    fn legendre_works() {
+        let symbols: Vec<i8> = (0..7u32).map(|n| FieldElement::new(n, 7).legendre()).collect();
+        assert_eq!(symbols, vec![0, 1, 1, -1, 1, -1, -1]);
+        assert!(FieldElement::new(0u32, 7).is_quadratic_residue());
+        assert!(!FieldElement::new(3u32, 7).is_quadratic_residue());
+    }
+
+    #[test]
+    fn sqrt_works() {
+        for p in [7u64, 13, 17, 41, 65537] {
+            let mut residues = 0;
+            for n in 0..p {
+                let a = FieldElement::new(n, p);
+                if let Some(r) = a.sqrt() {
+                    assert_eq!(r * r, a);
+                    residues += 1;
+                }
+            }
+            assert_eq!(residues, 1 + (p - 1) / 2);
+        }
+        assert_eq!(FieldElement::new(1u8, 2).sqrt(), Some(FieldElement::new(1, 2)));
+    }
+
+    #[test]
+    fn sqrt_near_max_order_works() {
+        // (p + 1)/4 and (p + 1)/2 would overflow T for these orders
+        let a = FieldElement::new(4i8, 127);
+        assert_eq!(a.sqrt().map(|r| r * r), Some(a));
+        assert_eq!(a.sqrt_cipolla().map(|r| r * r), Some(a));
+        let b = FieldElement::new(1_000_000i32, i32::MAX);
+        assert_eq!(b.sqrt().map(|r| r * r), Some(b));
+        assert_eq!(b.sqrt_cipolla().map(|r| r * r), Some(b));
+        let c = FieldElement::new(9u8, 251);
+        assert_eq!(c.sqrt_cipolla().map(|r| r * r), Some(c));
+        let q = u128::MAX - 158;
+        let d = FieldElement::new(25u128, q);
+        assert_eq!(d.sqrt().map(|r| r * r), Some(d));
+        assert_eq!(d.sqrt_cipolla().map(|r| r * r), Some(d));
+    }
+
+    #[test]
+    fn sqrt_cipolla_works() {
+        for p in [13u64, 17, 65537] {
+            for n in 0..p.min(500) {
+                let a = FieldElement::new(n, p);
+                assert_eq!(a.sqrt_cipolla().map(|r| r * r == a), a.sqrt().map(|_| true));
+            }
+        }
+    }
+
+    #[test]
+    fn nth_root_works() {
+        // including n whose 2- or 3-part exceeds that of p − 1
+        for (p, n) in [(13u64, 3), (13, 5), (13, 6), (13, 8), (13, 16), (17, 4), (37, 9), (37, 12), (37, 27), (41, 16), (1009, 7)] {
+            let mut powers: Vec<_> = (0..p).map(|x| FieldElement::new(x, p).pow(n)).collect();
+            powers.sort_by_key(|a| a.get_num());
+            powers.dedup();
+            let mut roots = 0;
+            for a in (0..p).map(|x| FieldElement::new(x, p)) {
+                if let Some(x) = a.nth_root(n) {
+                    assert_eq!(x.pow(n), a, "p = {}, n = {}", p, n);
+                    roots += 1;
+                }
+            }
+            assert_eq!(roots, powers.len());
+        }
+        let one = FieldElement::new(1u64, 13);
+        assert_eq!(one.nth_root(0), Some(one));
+        assert_eq!(one.nth_root(8).map(|x| x.pow(8)), Some(one));
+        // 3⁸ = 9, though 8 is not invertible modulo 12
+        assert_eq!(FieldElement::new(9u64, 13).nth_root(8).map(|x| x.pow(8)), Some(FieldElement::new(9, 13)));
+        assert_eq!(FieldElement::new(2u64, 13).nth_root(0), None);
+    }
+
+    #[test]
+    fn nth_root_wide_orders_work() {
+        let q = u128::MAX - 158;
+        let a = FieldElement::new(123456789u128, q);
+        for n in [2, 3, 5, 1 << 40] {
+            let x = a.pow_u64(n);
+            assert_eq!(x.nth_root(n).map(|r| r.pow_u64(n)), Some(x), "n = {}", n);
+        }
+        // n wider than T
+        let b = FieldElement::new(7u8, 251).pow_u64(1000);
+        assert_eq!(b.nth_root(1000).map(|r| r.pow_u64(1000)), Some(b));
+        assert_eq!(FieldElement::new(3i8, 127).nth_root(300).map(|r| r.pow_u64(300)), None);
+    }
+
+    #[cfg(feature = "primality")]
+    #[test]
+    fn new_checked_prime_works() {
+        assert!(FieldElement::new_checked_prime(3u32, 7).unwrap().is_field());
+        assert_eq!(FieldElement::new_checked_prime(3u32, 12), Err(FieldError::NonPrimeModulus));
+        assert_eq!(FieldElement::new_checked_prime(13u32, 7), Err(FieldError::OutOfRange));
+        assert!(!FieldElement::new(3i64, 561).is_field());
+    }
+
+    #[test]
+    fn add_near_type_max_works() {
+        let a: FieldElement<u8> = FieldElement::new(200, 251);
+        let b: FieldElement<u8> = FieldElement::new(100, 251);
+        assert_eq!(a + b, FieldElement::new(49, 251));
+        assert_eq!(b - a, FieldElement::new(151, 251));
+        assert_eq!(a + FieldElement::new(51, 251), a.zero());
+        assert_eq!(a.unchecked_add(b), a + b);
+    }
+
+    #[test]
+    fn large_moduli_work() {
+        let p = 18446744073709551557u64;
+        let a: FieldElement<u64> = FieldElement::new(p - 1, p);
+        assert_eq!(a * a, a.one());
+        assert_eq!(a.pow(3), a);
+        assert_eq!(FieldElement::new(3u64, p).pow(p - 1), a.one());
+        let three = FieldElement::new(3u64, p);
+        assert_eq!(a / three * three, a);
+        let b: FieldElement<i32> = FieldElement::new(2147483629, 2147483647);
+        assert_eq!(b * b, FieldElement::new(324, 2147483647));
+    }
+
+    #[test]
+    fn inverse_works() {
+        let a: FieldElement<u32> = FieldElement::new(3, 7);
+        assert_eq!(a.inverse(), Some(FieldElement::new(5, 7)));
+        assert_eq!(FieldElement::new(0u32, 7).inverse(), None);
+        // composite moduli: only units are invertible
+        assert_eq!(FieldElement::new(7u64, 12).inverse(), Some(FieldElement::new(7, 12)));
+        assert_eq!(FieldElement::new(8u64, 12).inverse(), None);
+        let b: FieldElement<i64> = FieldElement::new(123456, 1000003);
+        assert_eq!(b * b.inverse().unwrap(), b.one());
+    }
+
 }
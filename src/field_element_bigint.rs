@@ -1,95 +1,657 @@
-use std::ops::{Add, Sub, Mul, Div};
+use std::{fmt, hash::{Hash, Hasher}, iter::{Product, Sum}, ops::{Add, Sub, Mul, Div}, str::FromStr};
 use crypto_bigint::modular::runtime_mod::{DynResidueParams, DynResidue};
-use crypto_bigint::{Checked, NonZero, Uint};
+use crypto_bigint::{Checked, Limb, NonZero, Uint, Word};
+use crate::FieldError;
+use crate::field_element::{split_literal, strip_hex_prefix};
 
 
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+/// An element of the field of odd order `order`, kept in Montgomery form
+/// together with the Montgomery parameters of its order. Montgomery form
+/// needs an odd modulus, so GF(2) and other even orders are not supported.
+///
+/// Arithmetic stays in Montgomery form; the canonical representative is only
+/// recovered by `get_num` and the encodings.
+#[derive(Copy, Clone, PartialEq, Eq)]
 pub struct FieldElement<const LIMBS: usize> {
-    n: Uint<LIMBS>,
-    order: Uint<LIMBS>,
+    residue: DynResidue<LIMBS>,
+}
+
+/// Derives the Montgomery parameters, which need an odd order, so even
+/// orders, 2 included, are rejected.
+pub(crate) fn residue_params<const LIMBS: usize>(order: &Uint<LIMBS>) -> Result<DynResidueParams<LIMBS>, FieldError> {
+    if *order == Uint::ZERO {
+        return Err(FieldError::ZeroModulus);
+    }
+    if order.as_words()[0] & 1 == 0 {
+        return Err(FieldError::EvenModulus);
+    }
+    Ok(DynResidueParams::new(order))
+}
+
+/// Like `residue_params`, for the constructors that panic on a bad order.
+fn expect_residue_params<const LIMBS: usize>(order: &Uint<LIMBS>) -> DynResidueParams<LIMBS> {
+    residue_params(order).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Returns `a⁻¹ mod m` for `a < m` by the extended Euclidean algorithm, or
+/// `None` when `gcd(a, m) ≠ 1`. Works for any modulus, even ones.
+fn inv_mod<const LIMBS: usize>(a: &Uint<LIMBS>, m: &Uint<LIMBS>) -> Option<Uint<LIMBS>> {
+    let (mut r0, mut r1) = (*m, *a);
+    let (mut s0, mut s1) = (Uint::ZERO, Uint::ONE);
+    while r1 != Uint::ZERO {
+        let (q, r) = r0.div_rem(&NonZero::new(r1).unwrap());
+        let qs = Uint::const_rem_wide(q.mul_wide(&s1), m).0;
+        (r0, r1) = (r1, r);
+        (s0, s1) = (s1, s0.sub_mod(&qs, m));
+    }
+    if r0 == Uint::ONE {
+        Some(s0)
+    } else {
+        None
+    }
 }
 
 impl<const LIMBS: usize> FieldElement<LIMBS>
 {
+    /// Builds the element congruent to `n`. Panics on a zero or even order.
     pub fn new(n: Uint<LIMBS>, order: Uint<LIMBS>) -> Self {
-        let modulus = NonZero::new(order).unwrap();
-        let n = n % modulus;
+        let params = expect_residue_params(&order);
+        let n = n % NonZero::new(order).unwrap();
         FieldElement {
-            n,
-            order
+            residue: DynResidue::new(&n, params)
         }
-    }    
+    }
+
+    /// Like `new`, but returns an error instead of panicking on a zero or
+    /// even order. As with `new`, `n` is reduced modulo the order.
+    pub fn try_new(n: Uint<LIMBS>, order: Uint<LIMBS>) -> Result<Self, FieldError> {
+        let params = residue_params(&order)?;
+        let n = n % NonZero::new(order).unwrap();
+        Ok(FieldElement {
+            residue: DynResidue::new(&n, params)
+        })
+    }
+
+    /// Like `try_new`, but also rejects orders that fail a Miller–Rabin
+    /// primality test, so that division is guaranteed to be correct.
+    #[cfg(feature = "primality")]
+    pub fn new_checked_prime(n: Uint<LIMBS>, order: Uint<LIMBS>) -> Result<Self, FieldError> {
+        let element = FieldElement::try_new(n, order)?;
+        if element.is_field() {
+            Ok(element)
+        } else {
+            Err(FieldError::NonPrimeModulus)
+        }
+    }
+
+    /// Tests whether the order is prime, i.e. whether this is really an
+    /// element of a field rather than of a ring.
+    #[cfg(feature = "primality")]
+    pub fn is_field(&self) -> bool {
+        crate::primality::is_prime(&self.get_order())
+    }
+
+    /// Returns the element congruent to `n` in the same field, reusing the
+    /// Montgomery parameters instead of deriving them again.
+    pub(crate) fn with_num(&self, n: Uint<LIMBS>) -> Self {
+        let n = n % NonZero::new(self.get_order()).unwrap();
+        FieldElement {
+            residue: DynResidue::new(&n, *self.residue.params())
+        }
+    }
 
+    /// Returns the canonical representative in `[0, order)`, leaving
+    /// Montgomery form.
     pub fn get_num(&self) -> Uint<LIMBS> {
-        self.n    
-    }    
+        self.residue.retrieve()
+    }
+
+    pub fn get_order(&self) -> Uint<LIMBS> {
+        *self.residue.params().modulus()
+    }
+
+    /// Moves the element into a type with at least as many limbs.
+    pub fn widen<const WIDE: usize>(&self) -> FieldElement<WIDE> {
+        assert!(WIDE >= LIMBS, "cannot widen into fewer limbs");
+        FieldElement::new(self.get_num().resize(), self.get_order().resize())
+    }
+
+    /// Moves the element into a type with `NARROW` limbs, or fails with
+    /// [`FieldError::OutOfRange`] if the modulus does not fit.
+    pub fn try_narrow<const NARROW: usize>(&self) -> Result<FieldElement<NARROW>, FieldError> {
+        if self.get_order().bits() > NARROW * Limb::BITS {
+            return Err(FieldError::OutOfRange);
+        }
+        Ok(FieldElement::new(self.get_num().resize(), self.get_order().resize()))
+    }
+
+    /// Zero is zero in Montgomery form too, so this needs no conversion.
+    pub fn is_zero(&self) -> bool {
+        *self.residue.as_montgomery() == Uint::ZERO
+    }
+
+    pub fn is_one(&self) -> bool {
+        self.residue == DynResidue::one(*self.residue.params())
+    }
+
+    /// Whether the canonical representative in `[0, order)` is even.
+    pub fn is_even(&self) -> bool {
+        self.get_num().as_words()[0] & 1 == 0
+    }
+
+    pub fn is_odd(&self) -> bool {
+        !self.is_even()
+    }
+
+    /// Returns the number of bytes needed to hold the modulus, which is the
+    /// width of the byte and hex encodings.
+    pub fn byte_width(&self) -> usize {
+        self.get_order().bits().div_ceil(8)
+    }
+
+    /// Encodes the representative as `byte_width()` big-endian bytes.
+    pub fn to_be_bytes(&self) -> Vec<u8> {
+        let bytes: Vec<u8> = self.get_num().as_words().iter().rev().flat_map(|w| w.to_be_bytes()).collect();
+        bytes[bytes.len() - self.byte_width()..].to_vec()
+    }
+
+    pub fn to_le_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.to_be_bytes();
+        bytes.reverse();
+        bytes
+    }
+
+    /// Decodes `byte_width()` big-endian bytes, rejecting values that are
+    /// not below `order`.
+    pub fn from_be_bytes(bytes: &[u8], order: Uint<LIMBS>) -> Result<Self, FieldError> {
+        let mut bytes = bytes.to_vec();
+        bytes.reverse();
+        Self::from_le_bytes(&bytes, order)
+    }
+
+    pub fn from_le_bytes(bytes: &[u8], order: Uint<LIMBS>) -> Result<Self, FieldError> {
+        let params = residue_params(&order)?;
+        if bytes.len() != order.bits().div_ceil(8) {
+            return Err(FieldError::InvalidLength);
+        }
+        let mut words = [0; LIMBS];
+        for (i, chunk) in bytes.chunks(Limb::BYTES).enumerate() {
+            let mut word = [0u8; Limb::BYTES];
+            word[..chunk.len()].copy_from_slice(chunk);
+            words[i] = Word::from_le_bytes(word);
+        }
+        let n = Uint::from_words(words);
+        if n >= order {
+            return Err(FieldError::OutOfRange);
+        }
+        Ok(FieldElement { residue: DynResidue::new(&n, params) })
+    }
+
+    /// Returns the lowercase hex encoding, `2 * byte_width()` digits long.
+    pub fn to_hex(&self) -> String {
+        format!("{:x}", self)
+    }
+
+    /// Parses hex digits, with or without a `0x` or `0X` prefix as in
+    /// `FromStr`, rejecting values that are not below `order`.
+    pub fn from_hex(hex: &str, order: Uint<LIMBS>) -> Result<Self, FieldError> {
+        let n = parse_uint(strip_hex_prefix(hex).unwrap_or(hex), 16)?;
+        let params = residue_params(&order)?;
+        if n >= order {
+            return Err(FieldError::OutOfRange);
+        }
+        Ok(FieldElement { residue: DynResidue::new(&n, params) })
+    }
+
+    /// Moves the element into the field of order `order` by reducing its
+    /// canonical representative modulo the new order.
+    pub fn rebase_into(&self, order: Uint<LIMBS>) -> Self {
+        FieldElement::new(self.get_num(), order)
+    }
+
+    /// Moves the element into the field of order `order`, failing if its
+    /// canonical representative is not below the new order.
+    pub fn try_rebase_into(&self, order: Uint<LIMBS>) -> Result<Self, FieldError> {
+        let n = self.get_num();
+        if n < order {
+            FieldElement::try_new(n, order)
+        } else {
+            Err(FieldError::OutOfRange)
+        }
+    }
+
+    fn check_order(&self, rhs: &Self) -> Result<(), FieldError> {
+        if self.get_order() == rhs.get_order() {
+            Ok(())
+        } else {
+            Err(FieldError::MismatchedOrders)
+        }
+    }
+
+    /// Adds, returning an error instead of panicking on mismatched orders.
+    pub fn checked_add(self, rhs: Self) -> Result<Self, FieldError> {
+        self.check_order(&rhs)?;
+        Ok(self + rhs)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Result<Self, FieldError> {
+        self.check_order(&rhs)?;
+        Ok(self - rhs)
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Result<Self, FieldError> {
+        self.check_order(&rhs)?;
+        Ok(self * rhs)
+    }
+
+    /// Adds without comparing the orders outside debug builds, for inner
+    /// loops where the operands are known to share a field.
+    pub fn unchecked_add(self, rhs: Self) -> Self {
+        debug_assert_eq!(self.get_order(), rhs.get_order());
+        FieldElement {
+            residue: self.residue + rhs.residue
+        }
+    }
+
+    pub fn unchecked_sub(self, rhs: Self) -> Self {
+        debug_assert_eq!(self.get_order(), rhs.get_order());
+        FieldElement {
+            residue: self.residue - rhs.residue
+        }
+    }
+
+    pub fn unchecked_mul(self, rhs: Self) -> Self {
+        debug_assert_eq!(self.get_order(), rhs.get_order());
+        FieldElement {
+            residue: self.residue * rhs.residue
+        }
+    }
+
+    /// Divides through [`FieldElement::inverse`], so a non-invertible
+    /// denominator is an error rather than a panic.
+    pub fn checked_div(self, rhs: Self) -> Result<Self, FieldError> {
+        self.check_order(&rhs)?;
+        Ok(self * rhs.inverse().ok_or(FieldError::NotInvertible)?)
+    }
+
+    /// Returns the multiplicative inverse by the extended Euclidean
+    /// algorithm, or `None` when `gcd(n, order) ≠ 1`. Unlike division, this
+    /// is correct for non-prime moduli as well.
+    pub fn inverse(&self) -> Option<Self> {
+        inv_mod(&self.get_num(), &self.get_order()).map(|n| self.with_num(n))
+    }
+
+    /// Returns the Legendre symbol `(n/p)`: `0` for zero, `1` for non-zero
+    /// squares and `−1` otherwise, by Euler's criterion. The order must be an
+    /// odd prime.
+    pub fn legendre(&self) -> i8 {
+        if self.is_zero() {
+            0
+        } else if self.pow(self.get_order().shr_vartime(1)).is_one() {
+            1
+        } else {
+            -1
+        }
+    }
+
+    pub fn is_quadratic_residue(&self) -> bool {
+        self.legendre() >= 0
+    }
+
+    /// Returns a square root, or `None` for non-residues. The order must be
+    /// prime. Uses `a^((p+1)/4)` when `p ≡ 3 mod 4`, and otherwise
+    /// Tonelli–Shanks, or Cipolla when the 2-adicity `s` of `p − 1` is so
+    /// large that the `O(s²)` steps of Tonelli–Shanks dominate.
+    pub fn sqrt(&self) -> Option<Self> {
+        let one = Uint::ONE;
+        let p = self.get_order();
+        if self.is_zero() {
+            return Some(*self);
+        }
+        if !self.is_quadratic_residue() {
+            return None;
+        }
+        if p.as_words()[0] & 3 == 3 {
+            return Some(self.pow(p.shr_vartime(2).wrapping_add(&one)));
+        }
+        let s = p.wrapping_sub(&one).trailing_zeros();
+        if s * s > 8 * p.bits_vartime() {
+            self.sqrt_cipolla()
+        } else {
+            Some(self.tonelli_shanks(s))
+        }
+    }
+
+    /// Tonelli–Shanks for a non-zero residue, where `p − 1 = q·2^s`.
+    fn tonelli_shanks(&self, s: usize) -> Self {
+        let one = self.with_num(Uint::ONE);
+        let q = self.get_order().wrapping_sub(&Uint::ONE).shr_vartime(s);
+        let mut z = one + one;
+        while z.is_quadratic_residue() {
+            z = z + one;
+        }
+        let mut m = s;
+        let mut c = z.pow(q);
+        let mut t = self.pow(q);
+        let mut r = self.pow(q.shr_vartime(1).wrapping_add(&Uint::ONE));
+        while !t.is_one() {
+            let mut i = 0;
+            let mut t2 = t;
+            while !t2.is_one() {
+                t2 = t2.square();
+                i += 1;
+            }
+            let mut b = c;
+            for _ in 0..m - i - 1 {
+                b = b.square();
+            }
+            m = i;
+            c = b.square();
+            t = t * c;
+            r = r * b;
+        }
+        r
+    }
+
+    /// Returns a square root by Cipolla's algorithm, or `None` for
+    /// non-residues: `(a + ω)^((p+1)/2)` lies in Fp, where `ω² = a² − n` for
+    /// the first `a` making that a non-residue. The cost does not depend on
+    /// the 2-adicity of `p − 1`.
+    pub fn sqrt_cipolla(&self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(*self);
+        }
+        if !self.is_quadratic_residue() {
+            return None;
+        }
+        let one = self.with_num(Uint::ONE);
+        let zero = self.with_num(Uint::ZERO);
+        let mut a = one;
+        while (a * a - *self).is_quadratic_residue() {
+            a = a + one;
+        }
+        let w = a * a - *self;
+        let mul = |(x1, y1): (Self, Self), (x2, y2): (Self, Self)| (x1 * x2 + y1 * y2 * w, x1 * y2 + x2 * y1);
+
+        let exp = self.get_order().shr_vartime(1).wrapping_add(&Uint::ONE);
+        let mut result = (one, zero);
+        let mut base = (a, one);
+        for i in 0..exp.bits_vartime() {
+            if exp.bit_vartime(i) {
+                result = mul(result, base);
+            }
+            base = mul(base, base);
+        }
+        Some(result.0)
+    }
+
+    /// Returns some `x` with `xⁿ = a`, or `None` if there is none. The order
+    /// must be prime. Write `n = n₁n₂` where the primes of `n₁` all divide
+    /// `p − 1` and `n₂` is coprime to it. Roots are extracted one prime
+    /// factor `r` of `n₁` at a time, with multiplicity, in the
+    /// Adleman–Manders–Miller style at a cost linear in `r`, and `n₂` is
+    /// inverted modulo `p − 1`.
+    pub fn nth_root(&self, n: u64) -> Option<Self> {
+        if n == 0 {
+            return if self.is_one() { Some(*self) } else { None };
+        }
+        if self.is_zero() {
+            return Some(*self);
+        }
+        let m = self.get_order().wrapping_sub(&Uint::ONE);
+        let m_nonzero = NonZero::new(m).unwrap();
+        let d = num::integer::gcd(n, rem_u64(&m, n));
+        if !self.pow(m.div_rem(&NonZero::new(Uint::from(d)).unwrap()).0).is_one() {
+            return None;
+        }
+
+        let (mut smooth, mut coprime) = (1, n);
+        let mut g = d;
+        while g > 1 {
+            smooth *= g;
+            coprime /= g;
+            g = num::integer::gcd(coprime, rem_u64(&m, coprime));
+        }
+        let mut root = *self;
+        let mut rest = smooth;
+        for r in primes::factors(smooth) {
+            rest /= r;
+            root = root.prime_root(r, m, num::integer::gcd(rest, rem_u64(&m, rest)))?;
+        }
+        // p − 1 is even, so the exponent is inverted on plain integers
+        let k = inv_mod(&(Uint::from(coprime) % m_nonzero), &m)?;
+        Some(root.pow(k))
+    }
+
+    /// Returns an `r`-th root for a prime `r` dividing `m = p − 1`, chosen
+    /// among the `r` candidates to be a `rest`-th power itself, for `rest`
+    /// dividing `m`.
+    fn prime_root(&self, r: u64, m: Uint<LIMBS>, rest: u64) -> Option<Self> {
+        let one = self.with_num(Uint::ONE);
+        let r_big = Uint::from(r);
+        let r_nonzero = NonZero::new(r_big).unwrap();
+        let r_pow = |k: u32| (0..k).fold(Uint::ONE, |acc, _| acc.wrapping_mul(&r_big));
+        // m = r^t·s with r ∤ s
+        let (mut s, mut t) = (m, 0);
+        loop {
+            let (q, rem) = s.div_rem(&r_nonzero);
+            if rem != Uint::ZERO {
+                break;
+            }
+            s = q;
+            t += 1;
+        }
+        let mut z = one + one;
+        let m_over_r = m.div_rem(&r_nonzero).0;
+        while z.pow(m_over_r) == one {
+            z = z + one;
+        }
+        // g generates the Sylow r-subgroup and ζ has order r
+        let g = z.pow(s);
+        let zeta = g.pow(r_pow(t - 1));
+
+        // with αr ≡ 1 mod s, y^r = a·e for some e in the Sylow subgroup
+        let alpha = inv_mod(&(r_big % NonZero::new(s).unwrap()), &s)?;
+        let y = self.pow(alpha);
+        let e = y.pow(r_big) / *self;
+        let mut j = Uint::ZERO;
+        for i in 0..t {
+            let h = (e / g.pow(j)).pow(r_pow(t - 1 - i));
+            let digit = (0..r).find(|&c| zeta.pow(Uint::from(c)) == h)?;
+            j = j.wrapping_add(&Uint::from(digit).wrapping_mul(&r_pow(i)));
+        }
+        let (j_over_r, j_rem) = j.div_rem(&r_nonzero);
+        if j_rem != Uint::ZERO {
+            return None;
+        }
+
+        let mut root = y / g.pow(j_over_r);
+        let m_over_rest = m.div_rem(&NonZero::new(Uint::from(rest)).unwrap()).0;
+        for _ in 0..r {
+            if root.pow(m_over_rest) == one {
+                return Some(root);
+            }
+            root = root * zeta;
+        }
+        None
+    }
+
+    /// Returns the Montgomery form `n * R mod order`, which is the stored
+    /// state.
+    pub fn to_montgomery(&self) -> Uint<LIMBS> {
+        *self.residue.as_montgomery()
+    }
+
+    /// Builds an element from its Montgomery form `n * R mod order`.
+    pub fn from_montgomery(montgomery: Uint<LIMBS>, order: Uint<LIMBS>) -> Self {
+        FieldElement {
+            residue: DynResidue::from_montgomery(montgomery, expect_residue_params(&order))
+        }
+    }
+
+    /// Returns the Montgomery forms of a slice of elements of one field.
+    pub fn batch_to_montgomery(elements: &[Self]) -> Vec<Uint<LIMBS>> {
+        let order = match elements.first() {
+            Some(e) => e.get_order(),
+            None => return Vec::new(),
+        };
+        elements.iter()
+            .map(|e| {
+                assert_eq!(e.get_order(), order);
+                e.to_montgomery()
+            })
+            .collect()
+    }
+
+    /// Converts a slice of Montgomery forms back into elements, computing
+    /// the Montgomery parameters only once.
+    pub fn batch_from_montgomery(montgomery: &[Uint<LIMBS>], order: Uint<LIMBS>) -> Vec<Self> {
+        let residue_params = expect_residue_params(&order);
+        montgomery.iter()
+            .map(|m| FieldElement {
+                residue: DynResidue::from_montgomery(*m, residue_params)
+            })
+            .collect()
+    }
+}
+
+/// Returns `k` if the order has the form `2^k − 1`.
+fn mersenne_bits<const LIMBS: usize>(order: &Uint<LIMBS>) -> Option<usize> {
+    let ones: u32 = order.as_words().iter().map(|w| w.count_ones()).sum();
+    (ones as usize == order.bits()).then_some(ones as usize)
+}
+
+/// Reduces `x` modulo `m = 2^k − 1` by folding the bits above `k` onto the
+/// low ones, since `2^k ≡ 1`.
+fn fold_mersenne<const LIMBS: usize>(mut x: Uint<LIMBS>, m: &Uint<LIMBS>, k: usize) -> Uint<LIMBS> {
+    while x > *m {
+        x = (x & *m).wrapping_add(&x.shr_vartime(k));
+    }
+    if x == *m {
+        Uint::ZERO
+    } else {
+        x
+    }
+}
+
+/// Returns `x·2^s mod m` for `x < m = 2^k − 1` and `s < k`, which is a
+/// rotation of the low `k` bits.
+fn rotate_mersenne<const LIMBS: usize>(x: Uint<LIMBS>, m: &Uint<LIMBS>, k: usize, s: usize) -> Uint<LIMBS> {
+    if s == 0 {
+        return x;
+    }
+    (x.shl_vartime(s) & *m) | x.shr_vartime(k - s)
+}
+
+/// Multiplies Montgomery forms modulo `m = 2^k − 1` without a Montgomery
+/// reduction. With `R = 2^(64·LIMBS) ≡ 2^s`, `s = 64·LIMBS mod k`, the
+/// wide product `hi·R + lo` divided by `R` is `hi + lo·2^(k − s)`, and
+/// both terms reduce by folding and rotating bits.
+fn mul_mersenne<const LIMBS: usize>(a: &Uint<LIMBS>, b: &Uint<LIMBS>, m: &Uint<LIMBS>, k: usize) -> Uint<LIMBS> {
+    let (lo, hi) = a.mul_wide(b);
+    let s = Uint::<LIMBS>::BITS % k;
+    let lo = rotate_mersenne(fold_mersenne(lo, m, k), m, k, (k - s) % k);
+    fold_mersenne(hi, m, k).add_mod(&lo, m)
+}
+
+fn rem_u64<const LIMBS: usize>(x: &Uint<LIMBS>, n: u64) -> u64 {
+    let rem = x.as_words().iter().rev()
+        .fold(0u128, |acc, &w| ((acc << Limb::BITS) | w as u128) % n as u128);
+    rem as u64
+}
+
+/// Shows the canonical representative rather than the Montgomery form.
+impl<const LIMBS: usize> fmt::Debug for FieldElement<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FieldElement")
+            .field("n", &self.get_num())
+            .field("order", &self.get_order())
+            .finish()
+    }
+}
+
+/// `DynResidue` has no `Hash`; the Montgomery form and the order determine
+/// the element, consistently with `Eq`.
+impl<const LIMBS: usize> Hash for FieldElement<LIMBS> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.residue.as_montgomery().hash(state);
+        self.get_order().hash(state);
+    }
 }
 
 impl<const LIMBS: usize> Add for FieldElement<LIMBS> {
     type Output = Self;
     fn add(self, rhs: Self) -> Self {
-        assert_eq!(self.order, rhs.order);
-        let residue_params = DynResidueParams::new(&self.order);
-
-        let residue1 = DynResidue::new(&self.n, residue_params);
-        let residue2 = DynResidue::new(&rhs.n, residue_params);
-        let n = (residue1 + residue2).retrieve();
+        assert_eq!(self.get_order(), rhs.get_order());
         FieldElement {
-            n,
-            order: self.order    
-        } 
-    }    
+            residue: self.residue + rhs.residue
+        }
+    }
 }
 
 impl<const LIMBS: usize> Sub for FieldElement<LIMBS> {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self {
-        assert_eq!(self.order, rhs.order);
-        let residue_params = DynResidueParams::new(&self.order);
-
-        let residue1 = DynResidue::new(&self.n, residue_params);
-        let residue2 = DynResidue::new(&rhs.n, residue_params);
-        let n = (residue1 - residue2).retrieve();
+        assert_eq!(self.get_order(), rhs.get_order());
         FieldElement {
-            n,
-            order: self.order    
-        } 
-    }    
+            residue: self.residue - rhs.residue
+        }
+    }
 }
 
 
 impl<const LIMBS: usize> Mul for FieldElement<LIMBS> {
     type Output = Self;
     fn mul(self, rhs: Self) -> Self {
-        assert_eq!(self.order, rhs.order);
-        let residue_params = DynResidueParams::new(&self.order);
-
-        let residue1 = DynResidue::new(&self.n, residue_params);
-        let residue2 = DynResidue::new(&rhs.n, residue_params);
-        let n = (residue1 * residue2).retrieve();
+        let order = self.get_order();
+        assert_eq!(order, rhs.get_order());
+        if let Some(k) = mersenne_bits(&order) {
+            let montgomery = mul_mersenne(self.residue.as_montgomery(), rhs.residue.as_montgomery(), &order, k);
+            return FieldElement {
+                residue: DynResidue::from_montgomery(montgomery, *self.residue.params())
+            };
+        }
         FieldElement {
-            n,
-            order: self.order    
-        } 
-    }    
+            residue: self.residue * rhs.residue
+        }
+    }
 }
 
 impl<const LIMBS: usize> FieldElement<LIMBS> {
+    /// Returns `self²`, using the dedicated Montgomery squaring.
+    pub fn square(self) -> Self {
+        FieldElement {
+            residue: self.residue.square()
+        }
+    }
 
-    pub fn pow(self, exp: Uint<LIMBS>) -> Self {
-        /* let one = Uint::from(1u8);   
-        let nonzero = NonZero::new(self.order.sub_mod(&one, &self.order)).unwrap();
-        let exp = exp % nonzero; */
-        let residue_params = DynResidueParams::new(&self.order);
+    /// Returns `2·self`.
+    pub fn double(self) -> Self {
+        FieldElement {
+            residue: self.residue + self.residue
+        }
+    }
 
-        let residue = DynResidue::new(&self.n, residue_params);
-        let n = (residue.pow(&exp)).retrieve();
+    pub fn pow(self, exp: Uint<LIMBS>) -> Self {
         FieldElement {
-            n,
-            order: self.order    
-        } 
-    }    
+            residue: self.residue.pow(&exp)
+        }
+    }
+
+    /// Raises the element to `exp`, or to `−exp` when `negative` is set by
+    /// inverting the base first, as `pow` does for signed primitive types.
+    pub fn pow_signed(self, exp: Uint<LIMBS>, negative: bool) -> Self {
+        if negative {
+            self.inverse().expect("Zero is not invertible.").pow(exp)
+        } else {
+            self.pow(exp)
+        }
+    }
+
+    pub fn pow_i64(self, exp: i64) -> Self {
+        self.pow_signed(Uint::from(exp.unsigned_abs()), exp < 0)
+    }
 }
 
 
@@ -97,20 +659,137 @@ impl<const LIMBS: usize> FieldElement<LIMBS> {
 impl<const LIMBS: usize> Div for FieldElement<LIMBS> {
     type Output = Self;
     fn div(self, rhs: Self) -> Self {
-        assert_eq!(self.order, rhs.order);
+        assert_eq!(self.get_order(), rhs.get_order());
+        if rhs.is_zero() {
+            panic!("Zero is not valid denominator.")
+        }
         let two = Uint::from(2u8);
-        let residue_params = DynResidueParams::new(&self.order);
-
-        let residue1 = DynResidue::new(&self.n, residue_params);
-        let residue2 = DynResidue::new(&rhs.n, residue_params);
         // a/b = a * b^(-1) = a * b^(p-2)
-        let p_sub_2 = (Checked::new(self.order) - Checked::new(two)).0.unwrap();
-        let n = (residue1 * residue2.pow(&p_sub_2)).retrieve();
+        let p_sub_2 = (Checked::new(self.get_order()) - Checked::new(two)).0.unwrap();
         FieldElement {
-            n,
-            order: self.order    
-        } 
-    }    
+            residue: self.residue * rhs.residue.pow(&p_sub_2)
+        }
+    }
+}
+
+
+/// Arithmetic with plain integers, which are first reduced modulo the
+/// element's order.
+macro_rules! impl_scalar_op {
+    ($op:ident, $method:ident) => {
+        impl<const LIMBS: usize> $op<u64> for FieldElement<LIMBS> {
+            type Output = Self;
+
+            fn $method(self, rhs: u64) -> Self::Output {
+                self.$method(self.with_num(Uint::from_u64(rhs)))
+            }
+        }
+
+        impl<const LIMBS: usize> $op<FieldElement<LIMBS>> for u64 {
+            type Output = FieldElement<LIMBS>;
+
+            fn $method(self, rhs: FieldElement<LIMBS>) -> Self::Output {
+                rhs.with_num(Uint::from_u64(self)).$method(rhs)
+            }
+        }
+    };
+}
+
+impl_scalar_op!(Add, add);
+impl_scalar_op!(Sub, sub);
+impl_scalar_op!(Mul, mul);
+
+/// Sums the elements. The modulus is taken from the elements themselves,
+/// so summing an empty iterator panics.
+impl<const LIMBS: usize> Sum for FieldElement<LIMBS> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.reduce(|a, b| a + b).expect("cannot sum an empty iterator")
+    }
+}
+
+impl<'a, const LIMBS: usize> Sum<&'a FieldElement<LIMBS>> for FieldElement<LIMBS> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+/// Multiplies the elements; like `sum`, panics on an empty iterator.
+impl<const LIMBS: usize> Product for FieldElement<LIMBS> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.reduce(|a, b| a * b).expect("cannot multiply an empty iterator")
+    }
+}
+
+impl<'a, const LIMBS: usize> Product<&'a FieldElement<LIMBS>> for FieldElement<LIMBS> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+/// Formats a `Uint` in decimal; its own `Display` prints every limb in hex.
+pub(crate) fn to_decimal<const LIMBS: usize>(x: &Uint<LIMBS>) -> String {
+    let ten = NonZero::new(Limb::from(10u8)).unwrap();
+    let mut digits = Vec::new();
+    let mut x = *x;
+    loop {
+        let (q, r) = x.div_rem_limb(ten);
+        digits.push(b'0' + r.0 as u8);
+        x = q;
+        if x == Uint::ZERO {
+            break;
+        }
+    }
+    digits.reverse();
+    String::from_utf8(digits).unwrap()
+}
+
+impl<const LIMBS: usize> fmt::Display for FieldElement<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (mod {})", to_decimal(&self.get_num()), to_decimal(&self.get_order()))
+    }
+}
+
+/// Hex digits are zero-padded to the byte width of the modulus; `{:#x}`
+/// adds a `0x` prefix.
+macro_rules! impl_hex {
+    ($trait:ident, $spec:literal) => {
+        impl<const LIMBS: usize> fmt::$trait for FieldElement<LIMBS> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let width = 2 * self.byte_width();
+                let digits = format!(concat!("{:", $spec, "}"), self.get_num());
+                if f.alternate() {
+                    f.write_str("0x")?;
+                }
+                f.write_str(&digits[digits.len() - width..])
+            }
+        }
+    };
+}
+
+impl_hex!(LowerHex, "x");
+impl_hex!(UpperHex, "X");
+
+fn parse_uint<const LIMBS: usize>(digits: &str, radix: u32) -> Result<Uint<LIMBS>, FieldError> {
+    if digits.is_empty() {
+        return Err(FieldError::Parse);
+    }
+    let base = Checked::new(Uint::<LIMBS>::from(radix));
+    digits.chars().try_fold(Uint::ZERO, |acc, c| {
+        let d = c.to_digit(radix).ok_or(FieldError::Parse)?;
+        let next = Checked::new(acc) * base + Checked::new(Uint::from(d));
+        Option::<Uint<LIMBS>>::from(next.0).ok_or(FieldError::Parse)
+    })
+}
+
+/// Parses `"14 mod 17"` or `"0x1f mod 0xfb"`. As with `try_new`, the
+/// number is reduced modulo the order.
+impl<const LIMBS: usize> FromStr for FieldElement<LIMBS> {
+    type Err = FieldError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [(n, n_radix), (p, p_radix)] = split_literal(s)?;
+        FieldElement::try_new(parse_uint(n, n_radix)?, parse_uint(p, p_radix)?)
+    }
 }
 
 
@@ -119,12 +798,381 @@ mod tests {
     use super::*;
     use crypto_bigint::{U256, U512};
 
+    fn check_mersenne_mul<const LIMBS: usize>(k: usize) {
+        let m = Uint::<LIMBS>::MAX.shr_vartime(Uint::<LIMBS>::BITS - k);
+        assert_eq!(mersenne_bits(&m), Some(k));
+        let samples = [Uint::ZERO, Uint::ONE, m.wrapping_sub(&Uint::ONE), m.shr_vartime(1), Uint::from_u64(0xdeadbeefcafef00d)];
+        for &x in &samples {
+            for &y in &samples {
+                let (a, b) = (FieldElement::new(x, m), FieldElement::new(y, m));
+                let expected = Uint::const_rem_wide(a.get_num().mul_wide(&b.get_num()), &m).0;
+                assert_eq!((a * b).get_num(), expected, "k = {}", k);
+                assert_eq!(a * b, FieldElement { residue: a.residue * b.residue });
+            }
+        }
+    }
+
+    #[test]
+    fn mersenne_mul_works() {
+        use crypto_bigint::{U64, U128, U576};
+        check_mersenne_mul::<{ U64::LIMBS }>(61);
+        check_mersenne_mul::<{ U64::LIMBS }>(64);
+        check_mersenne_mul::<{ U128::LIMBS }>(89);
+        check_mersenne_mul::<{ U128::LIMBS }>(127);
+        check_mersenne_mul::<{ U256::LIMBS }>(89);
+        check_mersenne_mul::<{ U576::LIMBS }>(521);
+        assert_eq!(mersenne_bits(&U256::from(13u8)), None);
+    }
+
     #[test]
     fn new_works() {
         let num = U256::from(9u8);
         let modulus = U256::from(7u8);
         let element = FieldElement::new(num, modulus);
-        assert!(element.n < element.order);
+        assert!(element.get_num() < element.get_order());
+    }
+
+    #[test]
+    fn display_works() {
+        let elm = FieldElement::new(U256::from(3u8), U256::from(7u8));
+        assert_eq!(elm.to_string(), "3 (mod 7)");
+        let modulus = U256::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+        let elm = FieldElement::new(U256::ZERO, modulus);
+        assert_eq!(
+            elm.to_string(),
+            "0 (mod 115792089237316195423570985008687907853269984665640564039457584007908834671663)"
+        );
+    }
+
+    #[test]
+    fn hex_works() {
+        let modulus = U256::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+        let elm = FieldElement::new(U256::from(0xdeadbeefu32), modulus);
+        assert_eq!(format!("{:x}", elm), format!("{:0>64}", "deadbeef"));
+        let elm = FieldElement::new(U256::from(0xabu8), U256::from(0x1_0001u32));
+        assert_eq!(format!("{:#X}", elm), "0x0000AB");
+    }
+
+    #[test]
+    fn from_str_works() {
+        let elm: FieldElement<4> = "14 mod 17".parse().unwrap();
+        assert_eq!(elm, FieldElement::new(U256::from(14u8), U256::from(17u8)));
+        let p = "0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f";
+        let elm: FieldElement<4> = format!("0xDEADBEEF mod {}", p).parse().unwrap();
+        assert_eq!(elm, FieldElement::new(U256::from(0xdeadbeefu32), U256::from_be_hex(&p[2..])));
+        let decimal = "115792089237316195423570985008687907853269984665640564039457584007908834671663";
+        assert_eq!(format!("3735928559 mod {}", decimal).parse(), Ok(elm));
+        assert_eq!(format!("1 mod {}0", decimal).parse::<FieldElement<4>>(), Err(FieldError::Parse));
+        assert_eq!("1 mod 0".parse::<FieldElement<4>>(), Err(FieldError::ZeroModulus));
+        assert_eq!("0x mod 7".parse::<FieldElement<4>>(), Err(FieldError::Parse));
+    }
+
+    #[test]
+    fn hash_works() {
+        use std::collections::HashMap;
+        let modulus = U256::from(7u8);
+        let mut squares = HashMap::new();
+        for n in 0..20u8 {
+            let x = FieldElement::new(U256::from(n), modulus);
+            squares.insert(x, x * x);
+        }
+        assert_eq!(squares.len(), 7);
+        assert_eq!(squares[&FieldElement::new(U256::from(3u8), modulus)], FieldElement::new(U256::from(2u8), modulus));
+    }
+
+    #[test]
+    fn bytes_work() {
+        let modulus = U256::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+        let elm = FieldElement::new(U256::from(0xdeadbeefu32), modulus);
+        let be = elm.to_be_bytes();
+        assert_eq!(be.len(), 32);
+        assert_eq!(be[28..], [0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(FieldElement::from_be_bytes(&be, modulus), Ok(elm));
+        assert_eq!(FieldElement::from_le_bytes(&elm.to_le_bytes(), modulus), Ok(elm));
+        assert_eq!(FieldElement::from_be_bytes(&[0xff; 32], modulus), Err(FieldError::OutOfRange));
+        assert_eq!(FieldElement::from_be_bytes(&be[1..], modulus), Err(FieldError::InvalidLength));
+
+        let small = FieldElement::new(U512::from(0x1234u16), U512::from(0x1_0001u32));
+        assert_eq!(small.to_be_bytes(), vec![0x00, 0x12, 0x34]);
+        assert_eq!(FieldElement::from_le_bytes(&[0x34, 0x12, 0x00], U512::from(0x1_0001u32)), Ok(small));
+    }
+
+    #[test]
+    fn hex_helpers_work() {
+        // secp256k1 generator x-coordinate
+        let p = U256::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+        let gx = "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+        let x = FieldElement::from_hex(gx, p).unwrap();
+        assert_eq!(x.get_num(), U256::from_be_hex(gx));
+        assert_eq!(x.to_hex(), gx);
+        assert_eq!(FieldElement::from_hex(&format!("0x{}", x.to_hex()), p), Ok(x));
+        assert_eq!(FieldElement::from_hex(&format!("0X{}", x.to_hex()), p), Ok(x));
+        let small = Ok(FieldElement::new(U256::from(0x1fu8), p));
+        assert_eq!(FieldElement::from_hex("0X1f", p), small);
+        assert_eq!(format!("0X1f mod 0x{}", p).parse(), small);
+        assert_eq!(FieldElement::from_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f", p), Err(FieldError::OutOfRange));
+
+        let q = U512::MAX.wrapping_sub(&U512::from(568u16));
+        let y = FieldElement::new(U512::from(0x1fu8), q);
+        assert_eq!(y.to_hex().len(), 128);
+        assert_eq!(FieldElement::from_hex(&y.to_hex(), q), Ok(y));
+    }
+
+    #[test]
+    fn limb_conversions_work() {
+        let modulus = U256::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+        let x = FieldElement::new(U256::from(0xdeadbeefu32), modulus);
+        let wide: FieldElement<8> = x.widen();
+        assert_eq!(wide.get_num(), U512::from(0xdeadbeefu32));
+        assert_eq!(wide.get_order().bits(), 256);
+        assert_eq!((wide * wide).try_narrow::<4>(), Ok(x * x));
+        let big = FieldElement::new(U512::ONE, U512::MAX.wrapping_sub(&U512::from(568u16)));
+        assert_eq!(big.try_narrow::<4>(), Err(FieldError::OutOfRange));
+    }
+
+    #[test]
+    fn sum_and_product_work() {
+        let modulus = U256::from(7u8);
+        let elms: Vec<_> = (1..=6u8).map(|n| FieldElement::new(U256::from(n), modulus)).collect();
+        assert_eq!(elms.iter().sum::<FieldElement<4>>(), FieldElement::new(U256::ZERO, modulus));
+        assert_eq!(elms.iter().copied().product::<FieldElement<4>>(), FieldElement::new(U256::from(6u8), modulus));
+    }
+
+    #[test]
+    fn scalar_ops_work() {
+        let modulus = U256::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+        let x = FieldElement::new(U256::from(0xdeadbeefu32), modulus);
+        assert_eq!(x * 3, x + x + x);
+        assert_eq!(3 * x, x * 3);
+        assert_eq!(x + 1 - 1, x);
+        assert_eq!(0 - x + x, FieldElement::new(U256::ZERO, modulus));
+    }
+
+    #[test]
+    fn square_and_double_work() {
+        let modulus = U256::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+        let x = FieldElement::new(modulus.wrapping_sub(&U256::from(5u8)), modulus);
+        assert_eq!(x.square(), x * x);
+        assert_eq!(x.square(), FieldElement::new(U256::from(25u8), modulus));
+        assert_eq!(x.double(), x + x);
+        assert_eq!(x.double(), FieldElement::new(modulus.wrapping_sub(&U256::from(10u8)), modulus));
+    }
+
+    #[test]
+    fn predicates_work() {
+        let modulus = U256::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+        let x = FieldElement::new(modulus.wrapping_sub(&U256::ONE), modulus);
+        assert!(x.is_even() && !x.is_odd());
+        assert!((x + x).is_odd());
+        assert!((x + FieldElement::new(U256::ONE, modulus)).is_zero());
+        assert!((x * x).is_one() && !x.is_one() && !x.is_zero());
+    }
+
+    #[test]
+    fn try_new_works() {
+        let elm = FieldElement::try_new(U256::from(9u8), U256::from(7u8)).unwrap();
+        assert_eq!(elm.get_num(), U256::from(2u8));
+        assert_eq!(FieldElement::try_new(U256::ONE, U256::ZERO), Err(FieldError::ZeroModulus));
+        assert_eq!(FieldElement::try_new(U256::ONE, U256::from(12u8)), Err(FieldError::EvenModulus));
+        assert_eq!(FieldElement::try_new(U256::ONE, U256::from(2u8)), Err(FieldError::EvenModulus));
+        assert_eq!(FieldElement::from_hex("01", U256::from(12u8)), Err(FieldError::EvenModulus));
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus is even, but Montgomery form needs an odd modulus")]
+    fn new_panics_on_even_order() {
+        let _ = FieldElement::new(U256::ONE, U256::from(2u8));
+    }
+
+    #[test]
+    fn checked_ops_work() {
+        let a = FieldElement::new(U256::from(3u8), U256::from(7u8));
+        let b = FieldElement::new(U256::from(5u8), U256::from(7u8));
+        let c = FieldElement::new(U256::from(5u8), U256::from(11u8));
+        assert_eq!(a.checked_add(b), Ok(a + b));
+        assert_eq!(a.checked_sub(b), Ok(a - b));
+        assert_eq!(a.checked_mul(b), Ok(a * b));
+        assert_eq!(a.checked_div(b), Ok(a / b));
+        assert_eq!(a.checked_mul(c), Err(FieldError::MismatchedOrders));
+        let zero = FieldElement::new(U256::ZERO, U256::from(7u8));
+        assert_eq!(a.checked_div(zero), Err(FieldError::NotInvertible));
+    }
+
+    #[test]
+    fn unchecked_ops_work() {
+        let modulus = U256::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+        let a = FieldElement::new(U256::from(0xdeadbeefu32), modulus);
+        let b = FieldElement::new(modulus.wrapping_sub(&U256::from(5u8)), modulus);
+        assert_eq!(a.unchecked_add(b), a + b);
+        assert_eq!(a.unchecked_sub(b), a - b);
+        assert_eq!(a.unchecked_mul(b), a * b);
+    }
+
+    #[cfg(feature = "primality")]
+    #[test]
+    fn new_checked_prime_works() {
+        let p = U256::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+        assert!(FieldElement::new_checked_prime(U256::from(3u8), p).unwrap().is_field());
+        assert_eq!(FieldElement::new_checked_prime(U256::from(3u8), U256::from(15u8)), Err(FieldError::NonPrimeModulus));
+        assert_eq!(FieldElement::new_checked_prime(U256::from(3u8), U256::from(12u8)), Err(FieldError::EvenModulus));
+        assert_eq!(FieldElement::new_checked_prime(U256::from(3u8), U256::ZERO), Err(FieldError::ZeroModulus));
+    }
+
+    #[test]
+    #[should_panic(expected = "Zero is not valid denominator.")]
+    fn div_by_zero_panics() {
+        let modulus = U256::from(7u8);
+        let _ = FieldElement::new(U256::from(3u8), modulus) / FieldElement::new(U256::ZERO, modulus);
+    }
+
+    #[test]
+    fn rebase_works() {
+        let elm = FieldElement::new(U256::from(20u8), U256::from(23u8));
+        assert_eq!(elm.rebase_into(U256::from(17u8)), FieldElement::new(U256::from(3u8), U256::from(17u8)));
+        assert_eq!(elm.try_rebase_into(U256::from(17u8)), Err(FieldError::OutOfRange));
+        assert_eq!(elm.try_rebase_into(U256::from(29u8)).unwrap().get_num(), U256::from(20u8));
+    }
+
+    #[test]
+    fn inverse_works() {
+        let modulus = U256::from(7u8);
+        let elm = FieldElement::new(U256::from(3u8), modulus);
+        assert_eq!(elm.inverse(), Some(FieldElement::new(U256::from(5u8), modulus)));
+        assert_eq!(FieldElement::new(U256::ZERO, modulus).inverse(), None);
+
+        let modulus = U256::from(15u8);
+        assert_eq!(FieldElement::new(U256::from(6u8), modulus).inverse(), None);
+        assert_eq!(FieldElement::new(U256::from(7u8), modulus).inverse().unwrap().get_num(), U256::from(13u8));
+
+        let modulus = U256::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+        let elm = FieldElement::new(U256::from(0xdeadbeefu32), modulus);
+        let one = FieldElement::new(U256::ONE, modulus);
+        assert_eq!(elm * elm.inverse().unwrap(), one);
+        assert_eq!(elm.inverse().unwrap(), one / elm);
+    }
+
+    #[test]
+    fn legendre_works() {
+        let modulus = U256::from(7u8);
+        let symbols: Vec<i8> = (0..7u8).map(|n| FieldElement::new(U256::from(n), modulus).legendre()).collect();
+        assert_eq!(symbols, vec![0, 1, 1, -1, 1, -1, -1]);
+
+        let modulus = U256::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+        let x = FieldElement::new(U256::from(0xdeadbeefu32), modulus);
+        assert!((x * x).is_quadratic_residue());
+        // −1 is a non-residue since p ≡ 3 mod 4
+        assert_eq!((FieldElement::new(U256::ZERO, modulus) - x * x).legendre(), -1);
+    }
+
+    #[test]
+    fn sqrt_works() {
+        let moduli = [
+            // secp256k1, p ≡ 3 mod 4
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+            // 2^255 − 19, p ≡ 5 mod 8
+            "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed",
+            // BN254 scalar field, 2-adicity 28
+            "30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001",
+        ];
+        for modulus in moduli {
+            let modulus = U256::from_be_hex(modulus);
+            for n in [2u64, 3, 5, 123456789, 0xdeadbeef] {
+                let x = FieldElement::new(U256::from(n), modulus);
+                let square = x * x;
+                let root = square.sqrt().unwrap();
+                assert_eq!(root * root, square);
+                assert!(root == x || root + x == FieldElement::new(U256::ZERO, modulus));
+            }
+        }
+        let modulus = U256::from(13u8);
+        assert_eq!(FieldElement::new(U256::from(2u8), modulus).sqrt(), None);
+        assert_eq!(FieldElement::new(U256::ZERO, modulus).sqrt().unwrap().get_num(), U256::ZERO);
+    }
+
+    #[test]
+    fn sqrt_cipolla_works() {
+        // Goldilocks, 2-adicity 32, where sqrt() picks Cipolla
+        let modulus = U256::from_be_hex("000000000000000000000000000000000000000000000000ffffffff00000001");
+        for n in [2u64, 3, 7, 0xdeadbeef] {
+            let x = FieldElement::new(U256::from(n), modulus);
+            let square = x * x;
+            assert_eq!(square.sqrt_cipolla().map(|r| r * r), Some(square));
+            assert_eq!(square.sqrt().map(|r| r * r), Some(square));
+        }
+        let modulus = U256::from(13u8);
+        assert_eq!(FieldElement::new(U256::from(2u8), modulus).sqrt_cipolla(), None);
+    }
+
+    #[test]
+    fn nth_root_works() {
+        // secp256k1, p ≡ 1 mod 3
+        let modulus = U256::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+        let mut non_cubes = 0;
+        for n in 2u64..30 {
+            let a = FieldElement::new(U256::from(n), modulus);
+            let cube = a * a * a;
+            assert_eq!(cube.nth_root(3).map(|x| x * x * x), Some(cube));
+            match a.nth_root(3) {
+                Some(x) => assert_eq!(x * x * x, a),
+                None => non_cubes += 1,
+            }
+        }
+        assert!(non_cubes > 0);
+
+        // BN254 scalar field, where 9 and 2^28 divide r − 1
+        let modulus = U256::from_be_hex("30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001");
+        let a = FieldElement::new(U256::from(0xdeadbeefu32), modulus);
+        for n in [9u64, 12, 5] {
+            let power = a.pow(U256::from(n));
+            assert_eq!(power.nth_root(n).unwrap().pow(U256::from(n)), power);
+        }
+
+        // exponents sharing more of a prime than p − 1 has
+        let modulus = U256::from(13u8);
+        let nine = FieldElement::new(U256::from(9u8), modulus);
+        assert_eq!(nine.nth_root(8).map(|x| x.pow(U256::from(8u8))), Some(nine));
+        let one = nine.with_num(Uint::ONE);
+        assert_eq!(one.nth_root(8).map(|x| x.pow(U256::from(8u8))), Some(one));
+        for x in 1u8..13 {
+            let a = FieldElement::new(U256::from(x), modulus);
+            for n in [8u64, 24, 27] {
+                let power = a.pow(U256::from(n));
+                assert_eq!(power.nth_root(n).map(|r| r.pow(U256::from(n))), Some(power));
+            }
+        }
+    }
+
+    #[test]
+    fn pow_signed_works() {
+        let modulus = U256::from(31u8);
+        let a = FieldElement::new(U256::from(15u8), modulus);
+        let one = FieldElement::new(U256::ONE, modulus);
+        assert_eq!(a.pow_signed(U256::from(3u8), true), one / (a * a * a));
+        assert_eq!(a.pow_signed(U256::from(3u8), false), a * a * a);
+        assert_eq!(a.pow_i64(-3) * a.pow_i64(3), one);
+        assert_eq!(a.pow_i64(0), one);
+    }
+
+    #[test]
+    fn montgomery_works() {
+        let modulus = U256::from(7u8);
+        let elm = FieldElement::new(U256::from(3u8), modulus);
+        // R = 2^256 = 2 mod 7
+        assert_eq!(elm.to_montgomery(), U256::from(6u8));
+        assert_eq!(FieldElement::from_montgomery(U256::from(6u8), modulus), elm);
+        assert_eq!((elm * elm).to_montgomery(), U256::from(4u8));
+        assert_eq!(format!("{:?}", elm), format!("FieldElement {{ n: {:?}, order: {:?} }}", U256::from(3u8), modulus));
+    }
+
+    #[test]
+    fn batch_montgomery_works() {
+        let modulus = U256::from(10007u16);
+        let elements: Vec<_> = (1u16..20)
+            .map(|n| FieldElement::new(U256::from(n * 321), modulus))
+            .collect();
+        let montgomery = FieldElement::batch_to_montgomery(&elements);
+        assert_eq!(montgomery[4], elements[4].to_montgomery());
+        assert_eq!(FieldElement::batch_from_montgomery(&montgomery, modulus), elements);
     }
 
     #[test]    
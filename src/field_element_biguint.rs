@@ -0,0 +1,217 @@
+use std::{fmt, ops::{Add, Div, Mul, Sub}};
+use num::{BigInt, BigUint, One, Zero};
+use num::bigint::Sign;
+use crate::FieldError;
+
+/// A field element backed by a heap-allocated `BigUint`, for moduli whose
+/// size is only known at runtime or exceeds every compiled-in `Uint`.
+///
+/// `BigUint` is not `Copy`, so this type does not implement
+/// [`FiniteField`](crate::FiniteField); the operators are implemented for
+/// both owned values and references instead.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FieldElement {
+    n: BigUint,
+    order: BigUint,
+}
+
+impl FieldElement {
+    pub fn new(n: BigUint, order: BigUint) -> Self {
+        assert!(!order.is_zero());
+        FieldElement {
+            n: n % &order,
+            order
+        }
+    }
+
+    /// Like `new`, but returns an error instead of panicking on a zero
+    /// order. As with `new`, `n` is reduced modulo the order.
+    pub fn try_new(n: BigUint, order: BigUint) -> Result<Self, FieldError> {
+        if order.is_zero() {
+            return Err(FieldError::ZeroModulus);
+        }
+        Ok(FieldElement::new(n, order))
+    }
+
+    pub fn get_num(&self) -> &BigUint {
+        &self.n
+    }
+
+    pub fn get_order(&self) -> &BigUint {
+        &self.order
+    }
+
+    pub fn zero(&self) -> Self {
+        FieldElement { n: BigUint::zero(), order: self.order.clone() }
+    }
+
+    pub fn one(&self) -> Self {
+        FieldElement::new(BigUint::one(), self.order.clone())
+    }
+
+    pub fn pow(&self, exp: &BigUint) -> Self {
+        FieldElement {
+            n: self.n.modpow(exp, &self.order),
+            order: self.order.clone()
+        }
+    }
+
+    /// Returns the inverse via the extended Euclidean algorithm, or `None`
+    /// unless `gcd(n, order) = 1`.
+    pub fn inverse(&self) -> Option<Self> {
+        let p = BigInt::from_biguint(Sign::Plus, self.order.clone());
+        let (mut r0, mut r1) = (p.clone(), BigInt::from_biguint(Sign::Plus, self.n.clone()));
+        let (mut s0, mut s1) = (BigInt::zero(), BigInt::one());
+        while !r1.is_zero() {
+            let q = &r0 / &r1;
+            let r2 = &r0 - &q * &r1;
+            let s2 = &s0 - &q * &s1;
+            (r0, r1) = (r1, r2);
+            (s0, s1) = (s1, s2);
+        }
+        if !r0.is_one() {
+            return None;
+        }
+        let n = ((s0 % &p) + &p) % &p;
+        Some(FieldElement { n: n.to_biguint().unwrap(), order: self.order.clone() })
+    }
+
+    /// Reduces `x < order²` modulo the order. For an order `2^k − 1` the
+    /// bits above `k` are folded onto the low ones, since `2^k ≡ 1`,
+    /// instead of dividing.
+    fn reduce(&self, x: BigUint) -> BigUint {
+        let k = self.order.bits();
+        if self.order.count_ones() != k {
+            return x % &self.order;
+        }
+        let mut x = x;
+        while x.bits() > k {
+            x = (&x & &self.order) + (x >> k);
+        }
+        if x >= self.order {
+            x - &self.order
+        } else {
+            x
+        }
+    }
+}
+
+impl Add for &FieldElement {
+    type Output = FieldElement;
+    fn add(self, rhs: Self) -> FieldElement {
+        assert_eq!(self.order, rhs.order);
+        FieldElement {
+            n: (&self.n + &rhs.n) % &self.order,
+            order: self.order.clone()
+        }
+    }
+}
+
+impl Sub for &FieldElement {
+    type Output = FieldElement;
+    fn sub(self, rhs: Self) -> FieldElement {
+        assert_eq!(self.order, rhs.order);
+        FieldElement {
+            n: (&self.n + &self.order - &rhs.n) % &self.order,
+            order: self.order.clone()
+        }
+    }
+}
+
+impl Mul for &FieldElement {
+    type Output = FieldElement;
+    fn mul(self, rhs: Self) -> FieldElement {
+        assert_eq!(self.order, rhs.order);
+        FieldElement {
+            n: self.reduce(&self.n * &rhs.n),
+            order: self.order.clone()
+        }
+    }
+}
+
+impl Div for &FieldElement {
+    type Output = FieldElement;
+    fn div(self, rhs: Self) -> FieldElement {
+        assert_eq!(self.order, rhs.order);
+        if rhs.n.is_zero() {
+            panic!("Zero is not valid denominator.")
+        }
+        // a/b = a * b^(p-2)
+        let p_sub_2 = &self.order - BigUint::from(2u8);
+        self * &rhs.pow(&p_sub_2)
+    }
+}
+
+macro_rules! impl_owned_op {
+    ($op:ident, $method:ident) => {
+        impl $op for FieldElement {
+            type Output = FieldElement;
+            fn $method(self, rhs: Self) -> FieldElement {
+                (&self).$method(&rhs)
+            }
+        }
+    };
+}
+
+impl_owned_op!(Add, add);
+impl_owned_op!(Sub, sub);
+impl_owned_op!(Mul, mul);
+impl_owned_op!(Div, div);
+
+impl fmt::Display for FieldElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (mod {})", self.n, self.order)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mersenne_4253() -> BigUint {
+        // 2^4253 − 1 is a Mersenne prime
+        (BigUint::one() << 4253u32) - BigUint::one()
+    }
+
+    #[test]
+    fn arithmetic_works() {
+        let p = BigUint::from(17u8);
+        let a = FieldElement::new(BigUint::from(14u8), p.clone());
+        let b = FieldElement::new(BigUint::from(5u8), p.clone());
+        assert_eq!(&a + &b, FieldElement::new(BigUint::from(2u8), p.clone()));
+        assert_eq!(&b - &a, FieldElement::new(BigUint::from(8u8), p.clone()));
+        assert_eq!(&a * &b, FieldElement::new(BigUint::from(2u8), p.clone()));
+        assert_eq!(&(&a / &b) * &b, a);
+        assert_eq!(a.to_string(), "14 (mod 17)");
+        assert_eq!(FieldElement::try_new(BigUint::one(), BigUint::zero()), Err(FieldError::ZeroModulus));
+    }
+
+    #[test]
+    fn large_modulus_works() {
+        let p = mersenne_4253();
+        let a = FieldElement::new(BigUint::from(3u8), p);
+        let inv = a.inverse().unwrap();
+        assert_eq!(&a * &inv, a.one());
+        assert_eq!(a.one() / a.clone(), inv);
+        assert_eq!(a.zero().inverse(), None);
+    }
+
+    #[test]
+    fn mersenne_reduction_works() {
+        let p = mersenne_4253();
+        let a = FieldElement::new(&p - BigUint::from(2u8), p.clone());
+        assert_eq!(&a * &a, a.one() + a.one() + a.one() + a.one());
+        let b = FieldElement::new(BigUint::from(3u8).pow(4000), p.clone());
+        assert_eq!((&a * &b).n, (&a.n * &b.n) % &p);
+        assert_eq!(a.reduce(p.clone()), BigUint::zero());
+    }
+
+    #[test]
+    fn inverse_fails_for_non_units() {
+        let a = FieldElement::new(BigUint::from(4u8), BigUint::from(12u8));
+        assert_eq!(a.inverse(), None);
+        let b = FieldElement::new(BigUint::from(5u8), BigUint::from(12u8));
+        assert_eq!(b.inverse(), Some(b.clone()));
+    }
+}
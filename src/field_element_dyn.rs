@@ -0,0 +1,135 @@
+use std::{fmt, ops::{Add, Div, Mul, Sub}};
+use num::BigUint;
+use crate::{FieldElementBigUint, FieldError};
+
+/// A field element whose modulus size is chosen at runtime, stored as boxed
+/// little-endian `u32` limbs padded to the width of the modulus.
+///
+/// Unlike [`FieldElementBig`](crate::FieldElementBig), no `LIMBS` parameter
+/// has to be picked at compile time; arithmetic goes through the `BigUint`
+/// backend.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DynFieldElement {
+    limbs: Box<[u32]>,
+    order: Box<[u32]>,
+}
+
+/// Pads the digits of `x` with zero limbs up to `width`.
+fn to_limbs(x: &BigUint, width: usize) -> Box<[u32]> {
+    let mut limbs = x.to_u32_digits();
+    limbs.resize(width, 0);
+    limbs.into_boxed_slice()
+}
+
+impl DynFieldElement {
+    /// Builds the element from little-endian limbs, reducing `n` modulo
+    /// `order`. Panics on a zero order.
+    pub fn new(n: &[u32], order: &[u32]) -> Self {
+        Self::from_biguint(&FieldElementBigUint::new(BigUint::from_slice(n), BigUint::from_slice(order)))
+    }
+
+    pub fn try_new(n: &[u32], order: &[u32]) -> Result<Self, FieldError> {
+        FieldElementBigUint::try_new(BigUint::from_slice(n), BigUint::from_slice(order))
+            .map(|e| Self::from_biguint(&e))
+    }
+
+    /// Returns the representative as little-endian limbs, as many as the
+    /// modulus has.
+    pub fn limbs(&self) -> &[u32] {
+        &self.limbs
+    }
+
+    pub fn order_limbs(&self) -> &[u32] {
+        &self.order
+    }
+
+    pub fn zero(&self) -> Self {
+        DynFieldElement { limbs: vec![0; self.order.len()].into_boxed_slice(), order: self.order.clone() }
+    }
+
+    pub fn one(&self) -> Self {
+        Self::from_biguint(&self.to_biguint().one())
+    }
+
+    pub fn pow(&self, exp: &[u32]) -> Self {
+        Self::from_biguint(&self.to_biguint().pow(&BigUint::from_slice(exp)))
+    }
+
+    /// Returns the inverse, or `None` unless `gcd(n, order) = 1`.
+    pub fn inverse(&self) -> Option<Self> {
+        self.to_biguint().inverse().map(|e| Self::from_biguint(&e))
+    }
+
+    fn to_biguint(&self) -> FieldElementBigUint {
+        FieldElementBigUint::new(BigUint::from_slice(&self.limbs), BigUint::from_slice(&self.order))
+    }
+
+    fn from_biguint(e: &FieldElementBigUint) -> Self {
+        let width = e.get_order().to_u32_digits().len();
+        DynFieldElement {
+            limbs: to_limbs(e.get_num(), width),
+            order: to_limbs(e.get_order(), width),
+        }
+    }
+}
+
+macro_rules! impl_dyn_op {
+    ($op:ident, $method:ident) => {
+        impl $op for &DynFieldElement {
+            type Output = DynFieldElement;
+            fn $method(self, rhs: Self) -> DynFieldElement {
+                DynFieldElement::from_biguint(&self.to_biguint().$method(rhs.to_biguint()))
+            }
+        }
+
+        impl $op for DynFieldElement {
+            type Output = DynFieldElement;
+            fn $method(self, rhs: Self) -> DynFieldElement {
+                (&self).$method(&rhs)
+            }
+        }
+    };
+}
+
+impl_dyn_op!(Add, add);
+impl_dyn_op!(Sub, sub);
+impl_dyn_op!(Mul, mul);
+impl_dyn_op!(Div, div);
+
+impl fmt::Display for DynFieldElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_biguint(), f)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic_works() {
+        let order = [17];
+        let a = DynFieldElement::new(&[14], &order);
+        let b = DynFieldElement::new(&[5], &order);
+        assert_eq!(&a + &b, DynFieldElement::new(&[2], &order));
+        assert_eq!(&b - &a, DynFieldElement::new(&[8], &order));
+        assert_eq!(&a * &b, DynFieldElement::new(&[2], &order));
+        assert_eq!((&a / &b) * b.clone(), a);
+        assert_eq!(a.pow(&[16]), a.one());
+        assert_eq!(a.to_string(), "14 (mod 17)");
+        assert_eq!(DynFieldElement::try_new(&[1], &[0]), Err(FieldError::ZeroModulus));
+    }
+
+    #[test]
+    fn limbs_are_padded() {
+        // secp256k1 base field
+        let p = [0xfffffc2f, 0xfffffffe, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff];
+        let a = DynFieldElement::new(&[0xdeadbeef], &p);
+        assert_eq!(a.limbs(), &[0xdeadbeef, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(a.zero().limbs().len(), 8);
+        let inv = a.inverse().unwrap();
+        assert_eq!(&a * &inv, a.one());
+        assert_eq!(inv.limbs().len(), 8);
+    }
+}
@@ -0,0 +1,206 @@
+use std::{fmt::{self, Debug}, hash::Hash, iter::{Product, Sum}, marker::PhantomData, ops::{Add, Div, Mul, Neg, Sub}};
+use crypto_bigint::modular::runtime_mod::{DynResidue, DynResidueParams};
+use crypto_bigint::{NonZero, Uint};
+use num::{One, Zero};
+use crate::{FieldElementBig, FiniteField};
+use crate::field_element_bigint::to_decimal;
+
+/// A modulus known at compile time, usually implemented by a marker type.
+///
+/// The Montgomery parameters are derived from `MODULUS` once per type in a
+/// constant, instead of once per element as [`FieldElementBig`] does.
+pub trait Modulus<const LIMBS: usize>: Copy + Debug + Default + Eq + Hash + 'static {
+    /// An odd prime.
+    const MODULUS: Uint<LIMBS>;
+
+    const PARAMS: DynResidueParams<LIMBS> = DynResidueParams::new(&Self::MODULUS);
+}
+
+/// A bigint field element whose modulus is fixed by the type `M`, so that
+/// mixing elements of different fields fails to compile.
+///
+/// Elements are kept in Montgomery form; equality and hashing agree with
+/// the canonical representative since the form is a bijection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct FieldElementTyped<M, const LIMBS: usize> {
+    montgomery: Uint<LIMBS>,
+    modulus: PhantomData<M>,
+}
+
+impl<M: Modulus<LIMBS>, const LIMBS: usize> FieldElementTyped<M, LIMBS> {
+    pub const ZERO: Self = FieldElementTyped { montgomery: Uint::ZERO, modulus: PhantomData };
+    pub const ONE: Self = FieldElementTyped {
+        montgomery: *DynResidue::one(M::PARAMS).as_montgomery(),
+        modulus: PhantomData,
+    };
+
+    /// Builds the element, reducing `n` modulo `M::MODULUS`.
+    pub fn new(n: Uint<LIMBS>) -> Self {
+        let n = n % NonZero::new(M::MODULUS).unwrap();
+        Self::from_residue(DynResidue::new(&n, M::PARAMS))
+    }
+
+    pub fn get_num(&self) -> Uint<LIMBS> {
+        self.residue().retrieve()
+    }
+
+    pub fn get_order(&self) -> Uint<LIMBS> {
+        M::MODULUS
+    }
+
+    pub fn pow(self, exp: Uint<LIMBS>) -> Self {
+        Self::from_residue(self.residue().pow(&exp))
+    }
+
+    /// Returns the inverse by Fermat's little theorem, or `None` for zero.
+    pub fn inverse(&self) -> Option<Self> {
+        if self.montgomery == Uint::ZERO {
+            None
+        } else {
+            Some(self.pow(M::MODULUS.wrapping_sub(&Uint::from(2u8))))
+        }
+    }
+
+    pub fn square(self) -> Self {
+        Self::from_residue(self.residue().square())
+    }
+
+    /// Forgets the type-level modulus.
+    pub fn to_dynamic(&self) -> FieldElementBig<LIMBS> {
+        FieldElementBig::new(self.get_num(), M::MODULUS)
+    }
+
+    fn residue(&self) -> DynResidue<LIMBS> {
+        DynResidue::from_montgomery(self.montgomery, M::PARAMS)
+    }
+
+    fn from_residue(residue: DynResidue<LIMBS>) -> Self {
+        FieldElementTyped { montgomery: *residue.as_montgomery(), modulus: PhantomData }
+    }
+}
+
+macro_rules! impl_typed_op {
+    ($op:ident, $method:ident) => {
+        impl<M: Modulus<LIMBS>, const LIMBS: usize> $op for FieldElementTyped<M, LIMBS> {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self {
+                Self::from_residue(self.residue().$method(rhs.residue()))
+            }
+        }
+    };
+}
+
+impl_typed_op!(Add, add);
+impl_typed_op!(Sub, sub);
+impl_typed_op!(Mul, mul);
+
+impl<M: Modulus<LIMBS>, const LIMBS: usize> Div for FieldElementTyped<M, LIMBS> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        self.mul(rhs.inverse().expect("Zero is not valid denominator."))
+    }
+}
+
+impl<M: Modulus<LIMBS>, const LIMBS: usize> Neg for FieldElementTyped<M, LIMBS> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::from_residue(self.residue().neg())
+    }
+}
+
+impl<M: Modulus<LIMBS>, const LIMBS: usize> Zero for FieldElementTyped<M, LIMBS> {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self.montgomery == Uint::ZERO
+    }
+}
+
+impl<M: Modulus<LIMBS>, const LIMBS: usize> One for FieldElementTyped<M, LIMBS> {
+    fn one() -> Self {
+        Self::ONE
+    }
+}
+
+impl<M: Modulus<LIMBS>, const LIMBS: usize> Sum for FieldElementTyped<M, LIMBS> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(<Self as Zero>::zero(), |a, b| a + b)
+    }
+}
+
+impl<M: Modulus<LIMBS>, const LIMBS: usize> Product for FieldElementTyped<M, LIMBS> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(<Self as One>::one(), |a, b| a * b)
+    }
+}
+
+impl<M: Modulus<LIMBS>, const LIMBS: usize> FiniteField for FieldElementTyped<M, LIMBS> {
+    fn zero(&self) -> Self {
+        <Self as Zero>::zero()
+    }
+
+    fn one(&self) -> Self {
+        <Self as One>::one()
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        FieldElementTyped::inverse(self)
+    }
+
+    fn from_u64(&self, n: u64) -> Self {
+        Self::new(Uint::from_u64(n))
+    }
+}
+
+impl<M: Modulus<LIMBS>, const LIMBS: usize> fmt::Display for FieldElementTyped<M, LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (mod {})", to_decimal(&self.get_num()), to_decimal(&M::MODULUS))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crypto_bigint::U256;
+
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    struct Secp256k1;
+
+    impl Modulus<4> for Secp256k1 {
+        const MODULUS: U256 = U256::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+    }
+
+    type Fq = FieldElementTyped<Secp256k1, 4>;
+
+    #[test]
+    fn arithmetic_matches_dynamic_backend() {
+        let x = Fq::new(U256::from(0xdeadbeefu32));
+        let y = Fq::new(Secp256k1::MODULUS.wrapping_sub(&U256::from(5u8)));
+        let (dx, dy) = (x.to_dynamic(), y.to_dynamic());
+        assert_eq!((x + y).to_dynamic(), dx + dy);
+        assert_eq!((x - y).to_dynamic(), dx - dy);
+        assert_eq!((x * y).to_dynamic(), dx * dy);
+        assert_eq!((x / y).to_dynamic(), dx / dy);
+        assert_eq!(x.square(), x * x);
+        assert_eq!(-y, Fq::new(U256::from(5u8)));
+        assert_eq!(y.get_num(), dy.get_num());
+    }
+
+    #[test]
+    fn identities_work() {
+        let x = Fq::new(U256::from(3u8));
+        assert_eq!(Fq::default(), <Fq as Zero>::zero());
+        assert_eq!(Fq::ONE, <Fq as One>::one());
+        assert_eq!(Fq::ONE.get_num(), U256::ONE);
+        assert_eq!(x * x.inverse().unwrap(), <Fq as One>::one());
+        assert_eq!(<Fq as Zero>::zero().inverse(), None);
+        assert_eq!([x; 3].into_iter().sum::<Fq>(), x.from_u64(9));
+        assert_eq!(Fq::new(U256::ONE).to_string(), format!("1 (mod {})", to_decimal(&Secp256k1::MODULUS)));
+    }
+}
@@ -0,0 +1,362 @@
+//! Ready-made fields with dedicated reductions and precomputed constants.
+
+use crypto_bigint::{Limb, U256};
+
+/// The operations every word-sized built-in field shares. The type must be
+/// a newtype over `$t` holding a value below `MODULUS`, in whatever form
+/// `new`, `value` and `Mul` agree on, and define `MODULUS`, `ZERO` and
+/// `ONE`; addition and subtraction work the same in any such form.
+macro_rules! impl_word_field {
+    ($name:ident, $t:ty) => {
+        impl $name {
+            pub fn pow(self, mut exp: u64) -> Self {
+                let mut result = Self::ONE;
+                let mut base = self;
+                while exp > 0 {
+                    if exp & 1 == 1 {
+                        result = result * base;
+                    }
+                    base = base * base;
+                    exp >>= 1;
+                }
+                result
+            }
+
+            pub fn square(self) -> Self {
+                self * self
+            }
+
+            /// Returns the inverse by Fermat's little theorem, or `None` for
+            /// zero.
+            pub fn inverse(&self) -> Option<Self> {
+                if self.0 == 0 {
+                    None
+                } else {
+                    Some(self.pow(Self::MODULUS as u64 - 2))
+                }
+            }
+        }
+
+        impl std::ops::Add for $name {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                let p: $t = Self::MODULUS;
+                if p - self.0 > rhs.0 {
+                    $name(self.0 + rhs.0)
+                } else {
+                    $name(rhs.0 - (p - self.0))
+                }
+            }
+        }
+
+        impl std::ops::Sub for $name {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                if self.0 >= rhs.0 {
+                    $name(self.0 - rhs.0)
+                } else {
+                    $name(Self::MODULUS - (rhs.0 - self.0))
+                }
+            }
+        }
+
+        impl std::ops::Neg for $name {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self::ZERO - self
+            }
+        }
+
+        impl std::ops::Div for $name {
+            type Output = Self;
+
+            fn div(self, rhs: Self) -> Self {
+                std::ops::Mul::mul(self, rhs.inverse().expect("Zero is not valid denominator."))
+            }
+        }
+
+        impl num::Zero for $name {
+            fn zero() -> Self {
+                Self::ZERO
+            }
+
+            fn is_zero(&self) -> bool {
+                self.0 == 0
+            }
+        }
+
+        impl num::One for $name {
+            fn one() -> Self {
+                Self::ONE
+            }
+        }
+
+        impl std::iter::Sum for $name {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ZERO, |a, b| a + b)
+            }
+        }
+
+        impl std::iter::Product for $name {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ONE, |a, b| a * b)
+            }
+        }
+
+        impl From<u64> for $name {
+            fn from(n: u64) -> Self {
+                $name::new(n)
+            }
+        }
+
+        impl $crate::FiniteField for $name {
+            fn zero(&self) -> Self {
+                Self::ZERO
+            }
+
+            fn one(&self) -> Self {
+                Self::ONE
+            }
+
+            fn inverse(&self) -> Option<Self> {
+                $name::inverse(self)
+            }
+
+            fn from_u64(&self, n: u64) -> Self {
+                $name::new(n)
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{} (mod {})", self.value(), Self::MODULUS)
+            }
+        }
+    };
+}
+
+/// Returns `hi·2²⁵⁶ + lo mod p` for a 256-bit prime `p` with
+/// `c = 2²⁵⁶ mod p` below `2⁶⁴`: the high half is folded in as `hi·c`, and
+/// the little that overflows once more is folded again, leaving a few
+/// subtractions of `p`.
+fn reduce_pseudo_mersenne(lo: U256, hi: U256, c: &U256, p: &U256) -> U256 {
+    let (t_lo, t_hi) = hi.mul_wide(c);
+    let (s, carry) = lo.adc(&t_lo, Limb::ZERO);
+    let extra = t_hi.wrapping_add(&U256::from_word(carry.0)).wrapping_mul(c);
+    let (mut s, carry) = s.adc(&extra, Limb::ZERO);
+    if carry != Limb::ZERO {
+        // s wrapped, so it is below `extra` and adding c cannot overflow
+        s = s.wrapping_add(c);
+    }
+    while s >= *p {
+        s = s.wrapping_sub(p);
+    }
+    s
+}
+
+/// Defines a 256-bit field whose modulus `p` sits just below a power of two,
+/// so that `c = 2²⁵⁶ mod p` is a single word. Elements hold the canonical
+/// representative and products reduce by [`reduce_pseudo_mersenne`], with
+/// no Montgomery parameters at all.
+macro_rules! pseudo_mersenne_field {
+    ($(#[$attr:meta])* $name:ident, $modulus:literal, $c:literal) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+        pub struct $name(crypto_bigint::U256);
+
+        impl $name {
+            pub const MODULUS: crypto_bigint::U256 = crypto_bigint::U256::from_be_hex($modulus);
+            pub const ZERO: Self = $name(crypto_bigint::U256::ZERO);
+            pub const ONE: Self = $name(crypto_bigint::U256::ONE);
+
+            /// `2²⁵⁶ mod p`.
+            const C: crypto_bigint::U256 = crypto_bigint::U256::from_u64($c);
+
+            /// Builds the element, reducing `n` modulo the field order.
+            pub fn new(n: crypto_bigint::U256) -> Self {
+                $name(crate::fields::reduce_pseudo_mersenne(n, crypto_bigint::U256::ZERO, &Self::C, &Self::MODULUS))
+            }
+
+            pub fn from_u64(n: u64) -> Self {
+                Self::new(crypto_bigint::U256::from_u64(n))
+            }
+
+            pub fn get_num(&self) -> crypto_bigint::U256 {
+                self.0
+            }
+
+            pub fn pow(self, exp: crypto_bigint::U256) -> Self {
+                let mut result = Self::ONE;
+                for i in (0..exp.bits_vartime()).rev() {
+                    result = result.square();
+                    if exp.bit_vartime(i) {
+                        result = result * self;
+                    }
+                }
+                result
+            }
+
+            pub fn square(self) -> Self {
+                self * self
+            }
+
+            /// Returns the inverse by Fermat's little theorem, or `None` for
+            /// zero.
+            pub fn inverse(&self) -> Option<Self> {
+                if self.0 == crypto_bigint::U256::ZERO {
+                    None
+                } else {
+                    Some(self.pow(Self::MODULUS.wrapping_sub(&crypto_bigint::U256::from_u8(2))))
+                }
+            }
+
+            pub fn to_be_bytes(self) -> Vec<u8> {
+                self.to_dynamic().to_be_bytes()
+            }
+
+            /// Decodes the big-endian encoding, rejecting non-canonical values.
+            pub fn from_be_bytes(bytes: &[u8]) -> Result<Self, $crate::FieldError> {
+                $crate::FieldElementBig::from_be_bytes(bytes, Self::MODULUS).map(|e| $name(e.get_num()))
+            }
+
+            pub fn to_dynamic(&self) -> $crate::FieldElementBig<4> {
+                $crate::FieldElementBig::new(self.0, Self::MODULUS)
+            }
+        }
+
+        impl std::ops::Add for $name {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                $name(self.0.add_mod(&rhs.0, &Self::MODULUS))
+            }
+        }
+
+        impl std::ops::Sub for $name {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                $name(self.0.sub_mod(&rhs.0, &Self::MODULUS))
+            }
+        }
+
+        impl std::ops::Mul for $name {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self {
+                let (lo, hi) = self.0.mul_wide(&rhs.0);
+                $name(crate::fields::reduce_pseudo_mersenne(lo, hi, &Self::C, &Self::MODULUS))
+            }
+        }
+
+        impl std::ops::Div for $name {
+            type Output = Self;
+
+            fn div(self, rhs: Self) -> Self {
+                std::ops::Mul::mul(self, rhs.inverse().expect("Zero is not valid denominator."))
+            }
+        }
+
+        impl std::ops::Neg for $name {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $name(self.0.neg_mod(&Self::MODULUS))
+            }
+        }
+
+        impl num::Zero for $name {
+            fn zero() -> Self {
+                Self::ZERO
+            }
+
+            fn is_zero(&self) -> bool {
+                self.0 == crypto_bigint::U256::ZERO
+            }
+        }
+
+        impl num::One for $name {
+            fn one() -> Self {
+                Self::ONE
+            }
+        }
+
+        impl $crate::FiniteField for $name {
+            fn zero(&self) -> Self {
+                Self::ZERO
+            }
+
+            fn one(&self) -> Self {
+                Self::ONE
+            }
+
+            fn inverse(&self) -> Option<Self> {
+                $name::inverse(self)
+            }
+
+            fn from_u64(&self, n: u64) -> Self {
+                $name::from_u64(n)
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                std::fmt::Display::fmt(&self.to_dynamic(), f)
+            }
+        }
+    };
+}
+
+/// Adds the two-adic roots of unity to a field from
+/// [`define_prime_field!`](crate::define_prime_field) on `U256`, given the
+/// 2-adicity `s` of `p − 1 = 2^s·t` and a quadratic non-residue `g`.
+macro_rules! two_adic_roots {
+    ($name:ident, $adicity:literal, $non_residue:literal) => {
+        impl $name {
+            pub const TWO_ADICITY: u32 = $adicity;
+
+            /// Returns `g^t`, a primitive `2^TWO_ADICITY`-th root of unity.
+            pub fn two_adic_root_of_unity() -> Self {
+                let t = <Self as $crate::Modulus<4>>::MODULUS.shr_vartime(Self::TWO_ADICITY as usize);
+                Self::from_u64($non_residue).pow(t)
+            }
+
+            /// Returns a primitive `n`-th root of unity, or `None` unless `n`
+            /// is a power of two up to `2^TWO_ADICITY`.
+            pub fn root_of_unity(n: u64) -> Option<Self> {
+                if !n.is_power_of_two() || n.trailing_zeros() > Self::TWO_ADICITY {
+                    return None;
+                }
+                let exp = crypto_bigint::U256::ONE.shl_vartime((Self::TWO_ADICITY - n.trailing_zeros()) as usize);
+                Some(Self::two_adic_root_of_unity().pow(exp))
+            }
+        }
+    };
+}
+
+mod aes;
+mod baby_bear;
+mod bls12_381;
+mod bn254;
+mod curve25519;
+mod ghash;
+mod goldilocks;
+mod mersenne31;
+mod pasta;
+mod polyval;
+mod secp256k1;
+
+pub use aes::Gf256;
+pub use baby_bear::BabyBear;
+pub use bls12_381::Bls12_381Fq;
+pub use bn254::Bn254Fr;
+pub use curve25519::Curve25519Base;
+pub use ghash::Ghash;
+pub use goldilocks::Goldilocks;
+pub use mersenne31::Mersenne31;
+pub use pasta::{PallasBase, PallasScalar, VestaBase, VestaScalar};
+pub use polyval::Polyval;
+pub use secp256k1::{Secp256k1Base, Secp256k1Scalar};
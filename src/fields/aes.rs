@@ -0,0 +1,233 @@
+use std::{fmt, ops::{Add, Div, Mul, Neg, Sub}};
+
+/// An element of the Rijndael field `GF(2⁸) = GF(2)[x]/(x⁸ + x⁴ + x³ + x + 1)`
+/// used by AES, as a byte whose bit `i` is the coefficient of `xⁱ`.
+///
+/// Addition is XOR, and multiplication is carry-less with each overflow
+/// past `x⁷` folded back by `0x1b`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Gf256(u8);
+
+impl Gf256 {
+    /// The reduction polynomial `x⁸ + x⁴ + x³ + x + 1`.
+    pub const MODULUS: u16 = 0x11b;
+    pub const ZERO: Self = Gf256(0);
+    pub const ONE: Self = Gf256(1);
+
+    pub const fn new(n: u8) -> Self {
+        Gf256(n)
+    }
+
+    pub const fn value(&self) -> u8 {
+        self.0
+    }
+
+    /// Multiplies by shift-and-add, in constant time.
+    pub const fn mul(self, rhs: Self) -> Self {
+        let (mut a, mut b, mut result) = (self.0, rhs.0, 0u8);
+        let mut i = 0;
+        while i < 8 {
+            result ^= a & (b & 1).wrapping_neg();
+            a = (a << 1) ^ (0x1b & (a >> 7).wrapping_neg());
+            b >>= 1;
+            i += 1;
+        }
+        Gf256(result)
+    }
+
+    pub fn pow(self, mut exp: u64) -> Self {
+        let mut result = Self::ONE;
+        let mut base = self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    pub fn square(self) -> Self {
+        self * self
+    }
+
+    /// Returns `a²⁵⁴`, the inverse of nonzero `a`, or `None` for zero.
+    pub fn inverse(&self) -> Option<Self> {
+        if self.0 == 0 {
+            None
+        } else {
+            Some(self.pow(254))
+        }
+    }
+
+    /// The affine map of the S-box over `GF(2)⁸`: each bit becomes
+    /// `bᵢ ⊕ bᵢ₊₄ ⊕ bᵢ₊₅ ⊕ bᵢ₊₆ ⊕ bᵢ₊₇ ⊕ cᵢ`, indices mod 8, with
+    /// `c = 0x63`.
+    pub const fn affine(self) -> Self {
+        let b = self.0;
+        Gf256(b ^ b.rotate_left(1) ^ b.rotate_left(2) ^ b.rotate_left(3) ^ b.rotate_left(4) ^ 0x63)
+    }
+
+    /// Inverts [`Gf256::affine`].
+    pub const fn inverse_affine(self) -> Self {
+        let b = self.0;
+        Gf256(b.rotate_left(1) ^ b.rotate_left(3) ^ b.rotate_left(6) ^ 0x05)
+    }
+
+    /// The AES S-box: the inverse, with zero mapped to itself, followed by
+    /// the affine map.
+    pub fn sbox(self) -> Self {
+        self.inverse().unwrap_or(Self::ZERO).affine()
+    }
+
+    /// The inverse S-box used in decryption.
+    pub fn inverse_sbox(self) -> Self {
+        self.inverse_affine().inverse().unwrap_or(Self::ZERO)
+    }
+}
+
+impl Add for Gf256 {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self {
+        Gf256(self.0 ^ rhs.0)
+    }
+}
+
+/// Subtraction is addition in characteristic 2.
+impl Sub for Gf256 {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self {
+        Gf256(self.0 ^ rhs.0)
+    }
+}
+
+impl Mul for Gf256 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Gf256::mul(self, rhs)
+    }
+}
+
+impl Div for Gf256 {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Mul::mul(self, rhs.inverse().expect("Zero is not valid denominator."))
+    }
+}
+
+impl Neg for Gf256 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self
+    }
+}
+
+impl num::Zero for Gf256 {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl num::One for Gf256 {
+    fn one() -> Self {
+        Self::ONE
+    }
+}
+
+impl std::iter::Sum for Gf256 {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |a, b| a + b)
+    }
+}
+
+impl std::iter::Product for Gf256 {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |a, b| a * b)
+    }
+}
+
+impl From<u8> for Gf256 {
+    fn from(n: u8) -> Self {
+        Gf256(n)
+    }
+}
+
+impl From<Gf256> for u8 {
+    fn from(e: Gf256) -> Self {
+        e.0
+    }
+}
+
+impl crate::FiniteField for Gf256 {
+    fn zero(&self) -> Self {
+        Self::ZERO
+    }
+
+    fn one(&self) -> Self {
+        Self::ONE
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        Gf256::inverse(self)
+    }
+
+    /// Integers map through the prime subfield `GF(2)`, so only the parity
+    /// of `n` matters; use [`Gf256::new`] for a byte.
+    fn from_u64(&self, n: u64) -> Self {
+        Gf256((n & 1) as u8)
+    }
+}
+
+impl fmt::Display for Gf256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#04x} (mod {:#x})", self.0, Self::MODULUS)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic_works() {
+        // FIPS-197, section 4.2
+        assert_eq!(Gf256(0x57) + Gf256(0x83), Gf256(0xd4));
+        assert_eq!(Gf256(0x57) * Gf256(0x83), Gf256(0xc1));
+        assert_eq!(Gf256(0x57) * Gf256(0x13), Gf256(0xfe));
+        assert_eq!(Gf256(0x53).inverse(), Some(Gf256(0xca)));
+        assert_eq!(Gf256::ZERO.inverse(), None);
+        for n in 1..=255 {
+            let x = Gf256(n);
+            assert_eq!(x * x.inverse().unwrap(), Gf256::ONE);
+            assert_eq!(x.pow(255), Gf256::ONE);
+            assert_eq!(x / x, Gf256::ONE);
+        }
+        assert_eq!(Gf256(0x53).to_string(), "0x53 (mod 0x11b)");
+    }
+
+    #[test]
+    fn sbox_works() {
+        assert_eq!(Gf256(0x00).sbox(), Gf256(0x63));
+        assert_eq!(Gf256(0x01).sbox(), Gf256(0x7c));
+        assert_eq!(Gf256(0x53).sbox(), Gf256(0xed));
+        assert_eq!(Gf256(0xff).sbox(), Gf256(0x16));
+        for n in 0..=255 {
+            let x = Gf256(n);
+            assert_eq!(x.sbox().inverse_sbox(), x);
+            assert_eq!(x.affine().inverse_affine(), x);
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use std::ops::Mul;
+use crate::{FieldElement, FieldError};
+
+const P: u32 = 0x7800_0001;
+
+/// `−p⁻¹ mod 2³²`, by Newton's iteration from `p·p ≡ 1 mod 8`.
+const NEG_INV: u32 = {
+    let mut inv = P;
+    let mut i = 0;
+    while i < 4 {
+        inv = inv.wrapping_mul(2u32.wrapping_sub(P.wrapping_mul(inv)));
+        i += 1;
+    }
+    inv.wrapping_neg()
+};
+
+/// `R² mod p` for `R = 2³²`.
+const R2: u64 = ((1u128 << 64) % P as u128) as u64;
+
+/// An element of the BabyBear field `p = 15·2²⁷ + 1`, kept in Montgomery
+/// form `n·2³² mod p` so that products reduce without division.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct BabyBear(u32);
+
+/// Returns `t·2⁻³² mod p` for `t < p·2³²`.
+const fn redc(t: u64) -> u32 {
+    let m = (t as u32).wrapping_mul(NEG_INV);
+    let u = ((t + m as u64 * P as u64) >> 32) as u32;
+    if u >= P { u - P } else { u }
+}
+
+const fn mul(a: u32, b: u32) -> u32 {
+    redc(a as u64 * b as u64)
+}
+
+/// Powers of `31^((p − 1) / 2²⁷)`, squared down to `−1` and `1`.
+const fn two_adic_roots() -> [BabyBear; 28] {
+    let mut base = BabyBear::MULTIPLICATIVE_GENERATOR.0;
+    let mut exp = (P - 1) >> BabyBear::TWO_ADICITY;
+    let mut root = BabyBear::ONE.0;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            root = mul(root, base);
+        }
+        base = mul(base, base);
+        exp >>= 1;
+    }
+    let mut roots = [BabyBear::ONE; 28];
+    let mut k = 27;
+    while k > 0 {
+        roots[k] = BabyBear(root);
+        root = mul(root, root);
+        k -= 1;
+    }
+    roots
+}
+
+impl BabyBear {
+    pub const MODULUS: u32 = P;
+    pub const ZERO: Self = BabyBear(0);
+    pub const ONE: Self = BabyBear::new(1);
+
+    /// A generator of the whole multiplicative group.
+    pub const MULTIPLICATIVE_GENERATOR: Self = BabyBear::new(31);
+
+    /// `p − 1 = 2²⁷·15`.
+    pub const TWO_ADICITY: u32 = 27;
+
+    /// `TWO_ADIC_ROOTS[k]` is a primitive `2^k`-th root of unity, and the
+    /// square of `TWO_ADIC_ROOTS[k + 1]`.
+    pub const TWO_ADIC_ROOTS: [Self; 28] = two_adic_roots();
+
+    pub const fn new(n: u64) -> Self {
+        BabyBear(redc((n % P as u64) * R2))
+    }
+
+    /// Returns the canonical representative, leaving Montgomery form.
+    pub const fn value(&self) -> u32 {
+        redc(self.0 as u64)
+    }
+
+    /// Returns a primitive `n`-th root of unity, or `None` unless `n` is a
+    /// power of two up to `2²⁷`.
+    pub fn root_of_unity(n: u64) -> Option<Self> {
+        if n.is_power_of_two() && n.trailing_zeros() <= Self::TWO_ADICITY {
+            Some(Self::TWO_ADIC_ROOTS[n.trailing_zeros() as usize])
+        } else {
+            None
+        }
+    }
+}
+
+impl Mul for BabyBear {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        BabyBear(mul(self.0, rhs.0))
+    }
+}
+
+impl_word_field!(BabyBear, u32);
+
+/// Hands the element to the generic backend, e.g. for [`crate::ntt`].
+impl From<BabyBear> for FieldElement<u32> {
+    fn from(e: BabyBear) -> Self {
+        FieldElement::new(e.value(), P)
+    }
+}
+
+impl TryFrom<FieldElement<u32>> for BabyBear {
+    type Error = FieldError;
+
+    fn try_from(e: FieldElement<u32>) -> Result<Self, Self::Error> {
+        if e.get_order() != P {
+            return Err(FieldError::MismatchedOrders);
+        }
+        Ok(BabyBear::new(e.get_num() as u64))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ntt::{intt, ntt};
+
+    #[test]
+    fn arithmetic_matches_field_element() {
+        let p = P as u64;
+        let xs = [0, 1, 2, 1 << 27, p - 2, p - 1, 0xdeadbeef];
+        for &a in &xs {
+            for &b in &xs {
+                let (ba, bb) = (BabyBear::new(a), BabyBear::new(b));
+                assert_eq!((ba * bb).value() as u64, a % p * (b % p) % p);
+                assert_eq!((ba + bb).value() as u64, (a + b) % p);
+                assert_eq!((ba - bb).value() as u64, (a % p + p - b % p) % p);
+            }
+        }
+        let x = BabyBear::new(0xdeadbeef);
+        assert_eq!(x * x.inverse().unwrap(), BabyBear::ONE);
+        assert_eq!(x.to_string(), format!("{} (mod {})", 0xdeadbeefu64 % p, p));
+        assert_eq!(BabyBear::try_from(FieldElement::from(x)), Ok(x));
+        assert_eq!(BabyBear::try_from(FieldElement::new(1u32, 7)), Err(FieldError::MismatchedOrders));
+    }
+
+    #[test]
+    fn two_adic_roots_work() {
+        let roots = BabyBear::TWO_ADIC_ROOTS;
+        assert_eq!(roots[0], BabyBear::ONE);
+        assert_eq!(roots[1], -BabyBear::ONE);
+        assert_eq!(roots[27].pow(1 << 26), -BabyBear::ONE);
+        assert!((1..27).all(|k| roots[k + 1].square() == roots[k]));
+        assert_eq!(BabyBear::root_of_unity(1 << 27), Some(roots[27]));
+        assert_eq!(BabyBear::root_of_unity(1 << 28), None);
+        let g = BabyBear::MULTIPLICATIVE_GENERATOR;
+        for d in [2, 3, 5] {
+            assert_ne!(g.pow((P as u64 - 1) / d), BabyBear::ONE);
+        }
+    }
+
+    #[test]
+    fn ntt_works() {
+        let coeffs: Vec<FieldElement<u32>> = (1..=8).map(|n| BabyBear::new(n).into()).collect();
+        let evals = ntt(&coeffs);
+        assert_eq!(intt(&evals), coeffs);
+        assert_eq!(BabyBear::try_from(evals[0]), Ok(BabyBear::new(36)));
+    }
+}
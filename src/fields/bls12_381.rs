@@ -0,0 +1,25 @@
+crate::define_prime_field!(
+    /// The 381-bit base field of BLS12-381. Its Montgomery parameters are
+    /// computed at compile time.
+    pub Bls12_381Fq,
+    "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab"
+);
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crypto_bigint::U384;
+
+    #[test]
+    fn generator_is_on_the_curve() {
+        assert_eq!(Bls12_381Fq::LIMBS, U384::LIMBS);
+        let x = Bls12_381Fq::new(U384::from_be_hex("17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"));
+        let y = Bls12_381Fq::new(U384::from_be_hex("08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1"));
+        // G1 lies on y² = x³ + 4
+        assert_eq!(y.square(), x.square() * x + Bls12_381Fq::from_u64(4));
+        assert_eq!(x * x.inverse().unwrap(), Bls12_381Fq::ONE);
+        assert_eq!(Bls12_381Fq::from_be_bytes(&x.to_be_bytes()), Ok(x));
+        assert_eq!(x.to_be_bytes().len(), 48);
+    }
+}
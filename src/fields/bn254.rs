@@ -0,0 +1,31 @@
+crate::define_prime_field!(
+    /// The scalar field of BN254 (alt_bn128), the field of the Ethereum
+    /// pairing precompiles and of circuits verified on-chain.
+    ///
+    /// The two-adic roots are powers of `5^t`, the root arkworks and
+    /// snarkjs use, and so the one baked into on-chain verifiers.
+    pub Bn254Fr,
+    "30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001"
+);
+
+two_adic_roots!(Bn254Fr, 28, 5);
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crypto_bigint::U256;
+
+    #[test]
+    fn two_adic_root_works() {
+        let root = Bn254Fr::two_adic_root_of_unity();
+        let expected = "19103219067921713944291392827692070036145651957329286315305642004821462161904";
+        assert_eq!(root.to_string().split(' ').next(), Some(expected));
+        assert_eq!(root.pow(U256::ONE.shl_vartime(27)), -Bn254Fr::ONE);
+        assert_eq!(Bn254Fr::root_of_unity(1 << 28), Some(root));
+        assert_eq!(Bn254Fr::root_of_unity(2), Some(-Bn254Fr::ONE));
+        assert_eq!(Bn254Fr::root_of_unity(1), Some(Bn254Fr::ONE));
+        assert_eq!(Bn254Fr::root_of_unity(1 << 29), None);
+        assert_eq!(Bn254Fr::root_of_unity(6), None);
+    }
+}
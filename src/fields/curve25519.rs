@@ -0,0 +1,67 @@
+use crypto_bigint::U256;
+
+pseudo_mersenne_field!(
+    /// The field `p = 2²⁵⁵ − 19` underlying Curve25519, X25519 and Ed25519.
+    /// Here `2²⁵⁶ ≡ 38`.
+    Curve25519Base,
+    "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed",
+    38
+);
+
+impl Curve25519Base {
+    /// Returns `√−1 = 2^((p−1)/4)`.
+    pub fn sqrt_minus_one() -> Self {
+        Self::from_u64(2).pow(Self::MODULUS.shr_vartime(2))
+    }
+
+    /// Returns a square root, or `None` for non-residues. Since
+    /// `p ≡ 5 mod 8`, the candidate `a^((p+3)/8)` squares to `±a`, and is
+    /// fixed by `√−1` in the second case.
+    pub fn sqrt(&self) -> Option<Self> {
+        let root = self.pow(Self::MODULUS.wrapping_add(&U256::from_u8(3)).shr_vartime(3));
+        if root.square() == *self {
+            Some(root)
+        } else if root.square() == -*self {
+            Some(root * Self::sqrt_minus_one())
+        } else {
+            None
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FieldElementBig;
+
+    #[test]
+    fn arithmetic_matches_field_element_big() {
+        let p = Curve25519Base::MODULUS;
+        let xs = [U256::ZERO, U256::ONE, U256::from(19u8), p.wrapping_sub(&U256::ONE), p.shr_vartime(1), U256::MAX];
+        for &a in &xs {
+            for &b in &xs {
+                let (fa, fb) = (FieldElementBig::new(a, p), FieldElementBig::new(b, p));
+                let (ca, cb) = (Curve25519Base::new(a), Curve25519Base::new(b));
+                assert_eq!((ca * cb).to_dynamic(), fa * fb);
+                assert_eq!((ca + cb).to_dynamic(), fa + fb);
+                assert_eq!((ca - cb).to_dynamic(), fa - fb);
+            }
+        }
+        // 2²⁵⁶ − 1 = 2p + 37
+        assert_eq!(Curve25519Base::new(U256::MAX), Curve25519Base::from_u64(37));
+        assert_eq!(Curve25519Base::sqrt_minus_one().square(), -Curve25519Base::ONE);
+    }
+
+    #[test]
+    fn ed25519_base_point_decompresses() {
+        let one = Curve25519Base::ONE;
+        // y = 4/5 and d = −121665/121666
+        let y = Curve25519Base::from_u64(4) / Curve25519Base::from_u64(5);
+        let d = -Curve25519Base::from_u64(121665) / Curve25519Base::from_u64(121666);
+        let x = ((y.square() - one) / (d * y.square() + one)).sqrt().unwrap();
+        let expected = Curve25519Base::new(U256::from_be_hex("216936d3cd6e53fec0a4e231fdd6dc5c692cc7609525a7b2c9562d608f25d51a"));
+        assert!(x == expected || x == -expected);
+        assert_eq!(Curve25519Base::from_u64(2).sqrt(), None);
+    }
+}
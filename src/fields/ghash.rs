@@ -0,0 +1,277 @@
+use std::{fmt, ops::{Add, Div, Mul, Neg, Sub}};
+use crate::binary_field::clmul;
+
+/// An element of the GHASH field `GF(2¹²⁸) = GF(2)[x]/(x¹²⁸ + x⁷ + x² + x + 1)`
+/// of AES-GCM.
+///
+/// The value holds the coefficient of `xⁱ` in bit `i`. GCM numbers the bits
+/// of a block the other way round, with `x⁰` the most significant bit of the
+/// first byte; [`Ghash::from_block`] and [`Ghash::to_block`] translate.
+///
+/// Products use `pclmulqdq` on x86-64 and `pmull` on AArch64 when the CPU
+/// has them, detected at run time, and a portable constant-time carry-less
+/// multiplication otherwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Ghash(u128);
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "pclmulqdq")]
+unsafe fn clmul_pclmulqdq(a: u64, b: u64) -> u128 {
+    use std::arch::x86_64::{__m128i, _mm_clmulepi64_si128, _mm_set_epi64x};
+    let p = _mm_clmulepi64_si128(_mm_set_epi64x(0, a as i64), _mm_set_epi64x(0, b as i64), 0);
+    std::mem::transmute::<__m128i, u128>(p)
+}
+
+#[cfg(target_arch = "aarch64")]
+#[target_feature(enable = "neon,aes")]
+unsafe fn clmul_pmull(a: u64, b: u64) -> u128 {
+    std::arch::aarch64::vmull_p64(a, b)
+}
+
+/// Multiplies two 64-bit polynomials with the fastest instruction the CPU
+/// offers.
+pub(crate) fn clmul_detected(a: u64, b: u64) -> u128 {
+    #[cfg(target_arch = "x86_64")]
+    if std::arch::is_x86_feature_detected!("pclmulqdq") {
+        return unsafe { clmul_pclmulqdq(a, b) };
+    }
+    #[cfg(target_arch = "aarch64")]
+    if std::arch::is_aarch64_feature_detected!("aes") {
+        return unsafe { clmul_pmull(a, b) };
+    }
+    clmul(a, b)
+}
+
+/// Multiplies two 128-bit polynomials by Karatsuba on 64-bit halves,
+/// returning the high and low halves of the product.
+pub(crate) fn clmul128(a: u128, b: u128, clmul: fn(u64, u64) -> u128) -> (u128, u128) {
+    let (a0, a1, b0, b1) = (a as u64, (a >> 64) as u64, b as u64, (b >> 64) as u64);
+    let lo = clmul(a0, b0);
+    let hi = clmul(a1, b1);
+    let mid = clmul(a0 ^ a1, b0 ^ b1) ^ lo ^ hi;
+    (hi ^ (mid >> 64), lo ^ (mid << 64))
+}
+
+/// Reduces `hi·x¹²⁸ + lo` by `x¹²⁸ ≡ x⁷ + x² + x + 1`. The bits of `hi`
+/// pushed past `x¹²⁷` are at most degree 6 and are folded once more.
+fn reduce(hi: u128, lo: u128) -> u128 {
+    let overflow = (hi >> 127) ^ (hi >> 126) ^ (hi >> 121);
+    let fold = |x: u128| x ^ (x << 1) ^ (x << 2) ^ (x << 7);
+    lo ^ fold(hi) ^ fold(overflow)
+}
+
+impl Ghash {
+    pub const ZERO: Self = Ghash(0);
+    pub const ONE: Self = Ghash(1);
+
+    pub const fn new(n: u128) -> Self {
+        Ghash(n)
+    }
+
+    pub const fn value(&self) -> u128 {
+        self.0
+    }
+
+    /// Decodes a block in GCM's bit order.
+    pub const fn from_block(block: &[u8; 16]) -> Self {
+        Ghash(u128::from_be_bytes(*block).reverse_bits())
+    }
+
+    /// Encodes the element as a block in GCM's bit order.
+    pub const fn to_block(self) -> [u8; 16] {
+        self.0.reverse_bits().to_be_bytes()
+    }
+
+    /// Whether products run on a carry-less multiply instruction.
+    pub fn is_accelerated() -> bool {
+        #[cfg(target_arch = "x86_64")]
+        if std::arch::is_x86_feature_detected!("pclmulqdq") {
+            return true;
+        }
+        #[cfg(target_arch = "aarch64")]
+        if std::arch::is_aarch64_feature_detected!("aes") {
+            return true;
+        }
+        false
+    }
+
+    /// Multiplies without the hardware path, for platforms where it is not
+    /// trusted or for checking it.
+    pub fn mul_portable(self, rhs: Self) -> Self {
+        let (hi, lo) = clmul128(self.0, rhs.0, clmul);
+        Ghash(reduce(hi, lo))
+    }
+
+    pub fn pow(self, exp: u128) -> Self {
+        let mut result = Self::ONE;
+        for i in (0..128 - exp.leading_zeros()).rev() {
+            result = result.square();
+            if exp >> i & 1 == 1 {
+                result = result * self;
+            }
+        }
+        result
+    }
+
+    pub fn square(self) -> Self {
+        self * self
+    }
+
+    /// Returns `a^(2¹²⁸ − 2)`, the inverse of nonzero `a`, or `None` for
+    /// zero.
+    pub fn inverse(&self) -> Option<Self> {
+        if self.0 == 0 {
+            None
+        } else {
+            Some(self.pow(u128::MAX - 1))
+        }
+    }
+
+    /// Evaluates the GHASH polynomial with `self` as the hash key `H`:
+    /// `Y ← (Y + Xᵢ)·H` over the blocks, starting from zero. GCM passes the
+    /// zero-padded associated data, then the ciphertext, then the block of
+    /// their bit lengths.
+    pub fn hash_blocks<'a, I>(self, blocks: I) -> Self
+        where I: IntoIterator<Item = &'a [u8; 16]>,
+    {
+        blocks.into_iter().fold(Self::ZERO, |y, block| (y + Ghash::from_block(block)) * self)
+    }
+}
+
+impl Add for Ghash {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self {
+        Ghash(self.0 ^ rhs.0)
+    }
+}
+
+/// Subtraction is addition in characteristic 2.
+impl Sub for Ghash {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self {
+        Ghash(self.0 ^ rhs.0)
+    }
+}
+
+impl Mul for Ghash {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let (hi, lo) = clmul128(self.0, rhs.0, clmul_detected);
+        Ghash(reduce(hi, lo))
+    }
+}
+
+impl Div for Ghash {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Mul::mul(self, rhs.inverse().expect("Zero is not valid denominator."))
+    }
+}
+
+impl Neg for Ghash {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self
+    }
+}
+
+impl num::Zero for Ghash {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl num::One for Ghash {
+    fn one() -> Self {
+        Self::ONE
+    }
+}
+
+impl std::iter::Sum for Ghash {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |a, b| a + b)
+    }
+}
+
+impl std::iter::Product for Ghash {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |a, b| a * b)
+    }
+}
+
+impl crate::FiniteField for Ghash {
+    fn zero(&self) -> Self {
+        Self::ZERO
+    }
+
+    fn one(&self) -> Self {
+        Self::ONE
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        Ghash::inverse(self)
+    }
+
+    /// Integers map through the prime subfield `GF(2)`, so only the parity
+    /// of `n` matters.
+    fn from_u64(&self, n: u64) -> Self {
+        Ghash((n & 1) as u128)
+    }
+}
+
+impl fmt::Display for Ghash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x} (mod 0x100000000000000000000000000000087)", self.0)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(hex: &str) -> [u8; 16] {
+        u128::from_str_radix(hex, 16).unwrap().to_be_bytes()
+    }
+
+    #[test]
+    fn gcm_test_vector_works() {
+        // Test case 2 of the GCM specification: one ciphertext block, no
+        // associated data
+        let h = Ghash::from_block(&block("66e94bd4ef8a2c3b884cfa59ca342b2e"));
+        let c = block("0388dace60b6a392f328c2b971b2fe78");
+        let lengths = block("00000000000000000000000000000080");
+        let tag = h.hash_blocks([&c, &lengths]);
+        assert_eq!(tag.to_block(), block("f38cbb1ad69223dcc3457ae5b6b0f885"));
+    }
+
+    #[test]
+    fn arithmetic_works() {
+        let xs = [0, 1, 2, 1 << 127, u128::MAX, 0x0123456789abcdef_fedcba9876543210];
+        for &a in &xs {
+            for &b in &xs {
+                let (x, y) = (Ghash::new(a), Ghash::new(b));
+                assert_eq!(x * y, x.mul_portable(y));
+                assert_eq!(x * y, y * x);
+            }
+        }
+        // x¹²⁷ · x = x¹²⁸ = x⁷ + x² + x + 1
+        assert_eq!(Ghash::new(1 << 127) * Ghash::new(2), Ghash::new(0x87));
+        let x = Ghash::new(0x0123456789abcdef_fedcba9876543210);
+        assert_eq!(x * x.inverse().unwrap(), Ghash::ONE);
+        assert_eq!(Ghash::ZERO.inverse(), None);
+        assert_eq!(Ghash::from_block(&x.to_block()), x);
+        assert_eq!(Ghash::ONE.to_block()[0], 0x80);
+    }
+}
@@ -0,0 +1,146 @@
+use std::ops::Mul;
+
+const P: u64 = 0xffff_ffff_0000_0001;
+
+/// `2⁶⁴ mod p`.
+const EPSILON: u64 = (1 << 32) - 1;
+
+/// An element of the Goldilocks field `p = 2⁶⁴ − 2³² + 1`, the usual field
+/// of STARK provers.
+///
+/// Products are reduced without division: with `2⁶⁴ ≡ 2³² − 1` and
+/// `2⁹⁶ ≡ −1`, a 128-bit product folds to 64 bits with one subtraction, one
+/// 32×32 multiplication and one addition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Goldilocks(u64);
+
+/// Reduces any 128-bit value to the canonical representative.
+const fn reduce128(x: u128) -> u64 {
+    let lo = x as u64;
+    let hi_lo = (x >> 64) as u64 & EPSILON;
+    let hi_hi = (x >> 96) as u64;
+    // a wrapped difference is at least 2⁶⁴ − 2³², so this cannot underflow
+    let (mut t0, borrow) = lo.overflowing_sub(hi_hi);
+    if borrow {
+        t0 -= EPSILON;
+    }
+    let (mut t1, carry) = t0.overflowing_add(hi_lo * EPSILON);
+    if carry {
+        t1 += EPSILON;
+    }
+    if t1 >= P { t1 - P } else { t1 }
+}
+
+const fn mul(a: u64, b: u64) -> u64 {
+    reduce128(a as u128 * b as u128)
+}
+
+/// Powers of `7^((p − 1) / 2³²)`, squared down to `−1` and `1`.
+const fn two_adic_roots() -> [Goldilocks; 33] {
+    let mut base = Goldilocks::MULTIPLICATIVE_GENERATOR.0;
+    let mut exp = (P - 1) >> Goldilocks::TWO_ADICITY;
+    let mut root = 1;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            root = mul(root, base);
+        }
+        base = mul(base, base);
+        exp >>= 1;
+    }
+    let mut roots = [Goldilocks(1); 33];
+    let mut k = 32;
+    while k > 0 {
+        roots[k] = Goldilocks(root);
+        root = mul(root, root);
+        k -= 1;
+    }
+    roots
+}
+
+impl Goldilocks {
+    pub const MODULUS: u64 = P;
+    pub const ZERO: Self = Goldilocks(0);
+    pub const ONE: Self = Goldilocks(1);
+
+    /// A generator of the whole multiplicative group.
+    pub const MULTIPLICATIVE_GENERATOR: Self = Goldilocks(7);
+
+    /// `p − 1 = 2³²·(2³² − 1)`.
+    pub const TWO_ADICITY: u32 = 32;
+
+    /// `TWO_ADIC_ROOTS[k]` is a primitive `2^k`-th root of unity, and the
+    /// square of `TWO_ADIC_ROOTS[k + 1]`.
+    pub const TWO_ADIC_ROOTS: [Self; 33] = two_adic_roots();
+
+    pub const fn new(n: u64) -> Self {
+        Goldilocks(if n >= P { n - P } else { n })
+    }
+
+    pub const fn value(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns a primitive `n`-th root of unity, or `None` unless `n` is a
+    /// power of two up to `2³²`.
+    pub fn root_of_unity(n: u64) -> Option<Self> {
+        if n.is_power_of_two() && n.trailing_zeros() <= Self::TWO_ADICITY {
+            Some(Self::TWO_ADIC_ROOTS[n.trailing_zeros() as usize])
+        } else {
+            None
+        }
+    }
+}
+
+impl Mul for Goldilocks {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Goldilocks(mul(self.0, rhs.0))
+    }
+}
+
+impl_word_field!(Goldilocks, u64);
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FieldElement;
+
+    #[test]
+    fn arithmetic_matches_field_element() {
+        let xs = [0, 1, 2, EPSILON, 1 << 32, P - 2, P - 1, 0xdeadbeef_cafebabe % P];
+        for &a in &xs {
+            for &b in &xs {
+                let (fa, fb) = (FieldElement::new(a, P), FieldElement::new(b, P));
+                let (ga, gb) = (Goldilocks::new(a), Goldilocks::new(b));
+                assert_eq!((ga * gb).value(), (fa * fb).get_num());
+                assert_eq!((ga + gb).value(), (fa + fb).get_num());
+                assert_eq!((ga - gb).value(), (fa - fb).get_num());
+            }
+        }
+        assert_eq!(Goldilocks::new(u64::MAX), Goldilocks::new(EPSILON - 1));
+        let x = Goldilocks::new(0xdeadbeef);
+        assert_eq!(x / x, Goldilocks::ONE);
+        assert_eq!(-Goldilocks::ONE, Goldilocks::new(P - 1));
+        assert_eq!(x.to_string(), format!("{} (mod {})", 0xdeadbeefu64, P));
+    }
+
+    #[test]
+    fn two_adic_roots_work() {
+        let roots = Goldilocks::TWO_ADIC_ROOTS;
+        assert_eq!(roots[0], Goldilocks::ONE);
+        assert_eq!(roots[1], -Goldilocks::ONE);
+        assert_eq!(roots[32].pow(1 << 31), -Goldilocks::ONE);
+        assert_eq!(roots[32].pow(1 << 32), Goldilocks::ONE);
+        assert!((1..32).all(|k| roots[k + 1].square() == roots[k]));
+        assert_eq!(Goldilocks::root_of_unity(1 << 16), Some(roots[16]));
+        assert_eq!(Goldilocks::root_of_unity(12), None);
+        assert_eq!(Goldilocks::root_of_unity(1 << 33), None);
+        // 7 is a generator: it is not a d-th power for any prime d | p − 1
+        let g = Goldilocks::MULTIPLICATIVE_GENERATOR;
+        for d in [2, 3, 5, 17, 257, 65537] {
+            assert_ne!(g.pow((P - 1) / d), Goldilocks::ONE);
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use std::ops::Mul;
+
+const P: u32 = (1 << 31) - 1;
+
+/// An element of the Mersenne field `p = 2³¹ − 1`.
+///
+/// Products fit in 62 bits and reduce by folding the bits above 31 onto the
+/// low ones, since `2³¹ ≡ 1`, with no branch and no division.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Mersenne31(u32);
+
+/// Reduces any 64-bit value to the canonical representative. Two folds
+/// leave at most `p + 8`, which one masked subtraction fixes.
+const fn reduce(x: u64) -> u32 {
+    let p = P as u64;
+    let x = (x & p) + (x >> 31);
+    let x = (x & p) + (x >> 31);
+    (x - p * (x >= p) as u64) as u32
+}
+
+impl Mersenne31 {
+    pub const MODULUS: u32 = P;
+    pub const ZERO: Self = Mersenne31(0);
+    pub const ONE: Self = Mersenne31(1);
+
+    pub const fn new(n: u64) -> Self {
+        Mersenne31(reduce(n))
+    }
+
+    pub const fn value(&self) -> u32 {
+        self.0
+    }
+}
+
+impl Mul for Mersenne31 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Mersenne31(reduce(self.0 as u64 * rhs.0 as u64))
+    }
+}
+
+impl_word_field!(Mersenne31, u32);
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic_works() {
+        let p = P as u64;
+        for x in [0, 1, p - 1, p, p + 1, (p - 1) * (p - 1), u64::MAX, 0xdeadbeef_cafebabe] {
+            assert_eq!(Mersenne31::new(x).value() as u64, x % p, "{}", x);
+        }
+        let xs = [0, 1, 2, 1 << 30, p - 2, p - 1, 123456789];
+        for &a in &xs {
+            for &b in &xs {
+                let (ma, mb) = (Mersenne31::new(a), Mersenne31::new(b));
+                assert_eq!((ma * mb).value() as u64, a * b % p);
+                assert_eq!((ma + mb).value() as u64, (a + b) % p);
+                assert_eq!((ma - mb).value() as u64, (a + p - b) % p);
+            }
+        }
+        let x = Mersenne31::new(123456789);
+        assert_eq!(x * x.inverse().unwrap(), Mersenne31::ONE);
+        assert_eq!(Mersenne31::ZERO.inverse(), None);
+        assert_eq!(x.pow(p - 1), Mersenne31::ONE);
+        assert_eq!(x.to_string(), "123456789 (mod 2147483647)");
+    }
+}
@@ -0,0 +1,41 @@
+crate::define_prime_field!(
+    /// The base field of the Pallas curve, and the scalar field of Vesta.
+    pub PallasBase,
+    "40000000000000000000000000000000224698fc094cf91b992d30ed00000001"
+);
+
+crate::define_prime_field!(
+    /// The base field of the Vesta curve, and the scalar field of Pallas.
+    pub VestaBase,
+    "40000000000000000000000000000000224698fc0994a8dd8c46eb2100000001"
+);
+
+/// The curves form a cycle: each one's scalar field is the other's base
+/// field.
+pub type PallasScalar = VestaBase;
+pub type VestaScalar = PallasBase;
+
+two_adic_roots!(PallasBase, 32, 5);
+two_adic_roots!(VestaBase, 32, 5);
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crypto_bigint::U256;
+
+    #[test]
+    fn two_adic_roots_work() {
+        let root = PallasBase::two_adic_root_of_unity();
+        assert_eq!(root, PallasBase::new(U256::from_be_hex("2bce74deac30ebda362120830561f81aea322bf2b7bb7584bdad6fabd87ea32f")));
+        assert_eq!(root.pow(U256::ONE.shl_vartime(31)), -PallasBase::ONE);
+        assert_eq!(PallasBase::root_of_unity(1 << 32), Some(root));
+
+        let root = VestaScalar::two_adic_root_of_unity();
+        assert_eq!(root, PallasBase::two_adic_root_of_unity());
+        let root = PallasScalar::two_adic_root_of_unity();
+        assert_eq!(root, VestaBase::new(U256::from_be_hex("2de6a9b8746d3f589e5c4dfd492ae26e9bb97ea3c106f049a70e2c1102b6d05f")));
+        assert_eq!(VestaBase::root_of_unity(4).unwrap().square(), -VestaBase::ONE);
+        assert_eq!(VestaBase::root_of_unity(1 << 33), None);
+    }
+}
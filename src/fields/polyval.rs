@@ -0,0 +1,240 @@
+use std::{fmt, ops::{Add, Div, Mul, Neg, Sub}};
+use super::Ghash;
+use super::ghash::{clmul128, clmul_detected};
+
+/// An element of the POLYVAL field of AES-GCM-SIV (RFC 8452),
+/// `GF(2¹²⁸) = GF(2)[x]/(x¹²⁸ + x¹²⁷ + x¹²⁶ + x¹²¹ + 1)`, whose blocks are
+/// little-endian: bit `i` of the value is the coefficient of `xⁱ`.
+///
+/// POLYVAL multiplies by `dot(a, b) = a·b·x⁻¹²⁸`, so values behave like
+/// Montgomery representatives with `R = x¹²⁸`: `Mul` is `dot`, and the
+/// identity is `x¹²⁸ mod p` rather than `1`. The field is isomorphic to
+/// [`Ghash`], whose polynomial is the reciprocal of this one, and the
+/// `From` conversions are that isomorphism.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Polyval(u128);
+
+/// `x⁻¹` in the GHASH field, `x¹²⁷ + x⁶ + x + 1`.
+const GHASH_X_INV: Ghash = Ghash::new(1 << 127 | 0x43);
+
+/// Returns `(hi·x¹²⁸ + lo)·x⁻¹²⁸`. Since `p ≡ 1 + x¹²¹ + x¹²⁶ + x¹²⁷` is its
+/// own inverse modulo `x¹²⁸`, `m = lo·p mod x¹²⁸` clears the low half.
+fn montgomery_reduce(hi: u128, lo: u128) -> u128 {
+    let m = lo ^ (lo << 121) ^ (lo << 126) ^ (lo << 127);
+    hi ^ m ^ (m >> 1) ^ (m >> 2) ^ (m >> 7)
+}
+
+impl Polyval {
+    pub const ZERO: Self = Polyval(0);
+    /// `x¹²⁸ mod p`, the identity under `dot`.
+    pub const ONE: Self = Polyval(0xc2000000000000000000000000000001);
+
+    /// Wraps a value as stored, which `dot` treats as a Montgomery
+    /// representative.
+    pub const fn new(n: u128) -> Self {
+        Polyval(n)
+    }
+
+    pub const fn value(&self) -> u128 {
+        self.0
+    }
+
+    pub const fn from_block(block: &[u8; 16]) -> Self {
+        Polyval(u128::from_le_bytes(*block))
+    }
+
+    pub const fn to_block(self) -> [u8; 16] {
+        self.0.to_le_bytes()
+    }
+
+    /// POLYVAL's product `a·b·x⁻¹²⁸`, the same as `*`.
+    pub fn dot(self, rhs: Self) -> Self {
+        let (hi, lo) = clmul128(self.0, rhs.0, clmul_detected);
+        Polyval(montgomery_reduce(hi, lo))
+    }
+
+    pub fn pow(self, exp: u128) -> Self {
+        let mut result = Self::ONE;
+        for i in (0..128 - exp.leading_zeros()).rev() {
+            result = result.square();
+            if exp >> i & 1 == 1 {
+                result = result * self;
+            }
+        }
+        result
+    }
+
+    pub fn square(self) -> Self {
+        self * self
+    }
+
+    /// Returns `a^(2¹²⁸ − 2)`, the inverse of nonzero `a`, or `None` for
+    /// zero.
+    pub fn inverse(&self) -> Option<Self> {
+        if self.0 == 0 {
+            None
+        } else {
+            Some(self.pow(u128::MAX - 1))
+        }
+    }
+
+    /// Evaluates POLYVAL with `self` as the key `H`:
+    /// `S ← dot(S + Xᵢ, H)` over the blocks, starting from zero.
+    pub fn hash_blocks<'a, I>(self, blocks: I) -> Self
+        where I: IntoIterator<Item = &'a [u8; 16]>,
+    {
+        blocks.into_iter().fold(Self::ZERO, |s, block| (s + Polyval::from_block(block)).dot(self))
+    }
+}
+
+/// Maps `a(x)·x⁻¹²⁸` to `a(x⁻¹)·x¹²⁸`, which in GHASH's bit order is
+/// `mulX_GHASH(ByteReverse(a))`, as in RFC 8452, appendix A.
+impl From<Polyval> for Ghash {
+    fn from(e: Polyval) -> Self {
+        Ghash::new(e.0.reverse_bits()) * Ghash::new(2)
+    }
+}
+
+impl From<Ghash> for Polyval {
+    fn from(e: Ghash) -> Self {
+        Polyval((e * GHASH_X_INV).value().reverse_bits())
+    }
+}
+
+impl Add for Polyval {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self {
+        Polyval(self.0 ^ rhs.0)
+    }
+}
+
+/// Subtraction is addition in characteristic 2.
+impl Sub for Polyval {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self {
+        Polyval(self.0 ^ rhs.0)
+    }
+}
+
+impl Mul for Polyval {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.dot(rhs)
+    }
+}
+
+impl Div for Polyval {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Mul::mul(self, rhs.inverse().expect("Zero is not valid denominator."))
+    }
+}
+
+impl Neg for Polyval {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self
+    }
+}
+
+impl num::Zero for Polyval {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl num::One for Polyval {
+    fn one() -> Self {
+        Self::ONE
+    }
+}
+
+impl std::iter::Sum for Polyval {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |a, b| a + b)
+    }
+}
+
+impl std::iter::Product for Polyval {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |a, b| a * b)
+    }
+}
+
+impl crate::FiniteField for Polyval {
+    fn zero(&self) -> Self {
+        Self::ZERO
+    }
+
+    fn one(&self) -> Self {
+        Self::ONE
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        Polyval::inverse(self)
+    }
+
+    /// Integers map through the prime subfield `GF(2)`, so only the parity
+    /// of `n` matters.
+    fn from_u64(&self, n: u64) -> Self {
+        if n & 1 == 1 { Self::ONE } else { Self::ZERO }
+    }
+}
+
+impl fmt::Display for Polyval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x} (mod 0x1c2000000000000000000000000000001)", self.0)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(hex: &str) -> [u8; 16] {
+        u128::from_str_radix(hex, 16).unwrap().to_be_bytes()
+    }
+
+    #[test]
+    fn rfc_8452_test_vector_works() {
+        let h = Polyval::from_block(&block("25629347589242761d31f826ba4b757b"));
+        let xs = [block("4f4f95668c83dfb6401762bb2d01a262"), block("d1a24ddd2721d006bbe45f20d3c9f362")];
+        let s = h.hash_blocks(&xs);
+        assert_eq!(s.to_block(), block("f7a3b47b846119fae5b7866cf5e5b77e"));
+
+        // POLYVAL(H, X) = ByteReverse(GHASH(mulX_GHASH(ByteReverse(H)), ByteReverse(X)))
+        let reversed = xs.map(|mut x| { x.reverse(); x });
+        let mut expected = Ghash::from(h).hash_blocks(&reversed).to_block();
+        expected.reverse();
+        assert_eq!(s.to_block(), expected);
+    }
+
+    #[test]
+    fn conversions_are_isomorphic() {
+        let xs = [0, 1, 2, 1 << 127, u128::MAX, 0x0123456789abcdef_fedcba9876543210];
+        assert_eq!(Ghash::from(Polyval::ONE), Ghash::ONE);
+        for &a in &xs {
+            for &b in &xs {
+                let (x, y) = (Polyval::new(a), Polyval::new(b));
+                assert_eq!(Ghash::from(x * y), Ghash::from(x) * Ghash::from(y));
+                assert_eq!(Ghash::from(x + y), Ghash::from(x) + Ghash::from(y));
+            }
+            assert_eq!(Polyval::from(Ghash::from(Polyval::new(a))), Polyval::new(a));
+        }
+        let x = Polyval::new(0x0123456789abcdef_fedcba9876543210);
+        assert_eq!(x * x.inverse().unwrap(), Polyval::ONE);
+        assert_eq!(x * Polyval::ONE, x);
+    }
+}
@@ -0,0 +1,104 @@
+use crypto_bigint::{Encoding, U256};
+use crate::FieldError;
+
+pseudo_mersenne_field!(
+    /// The base field of secp256k1, `p = 2²⁵⁶ − 2³² − 977`, in which the
+    /// curve points of Bitcoin keys have their coordinates.
+    Secp256k1Base,
+    "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+    0x1_0000_03d1
+);
+
+impl Secp256k1Base {
+    /// Returns a square root, or `None` for non-residues. Since
+    /// `p ≡ 3 mod 4`, the candidate is simply `a^((p+1)/4)`.
+    pub fn sqrt(&self) -> Option<Self> {
+        let root = self.pow(Self::MODULUS.shr_vartime(2).wrapping_add(&U256::ONE));
+        if root.square() == *self {
+            Some(root)
+        } else {
+            None
+        }
+    }
+}
+
+crate::define_prime_field!(
+    /// The scalar field of secp256k1, modulo the group order `n`, for key,
+    /// nonce and signature arithmetic.
+    pub Secp256k1Scalar,
+    "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141"
+);
+
+impl Secp256k1Scalar {
+    /// Interprets 32 big-endian bytes as an integer reduced modulo `n`, the
+    /// rule ECDSA applies to a 256-bit message hash.
+    pub fn from_bytes_reduced(bytes: &[u8; 32]) -> Self {
+        Self::new(U256::from_be_bytes(*bytes))
+    }
+
+    /// Decodes 32 big-endian bytes, rejecting values that are not below
+    /// `n`, as when parsing a secret key or a signature component. Whether
+    /// zero is acceptable is left to the caller.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Result<Self, FieldError> {
+        Self::from_be_bytes(bytes)
+    }
+
+    pub fn to_bytes(self) -> [u8; 32] {
+        self.get_num().to_be_bytes()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FieldElementBig;
+
+    #[test]
+    fn arithmetic_matches_field_element_big() {
+        let p = Secp256k1Base::MODULUS;
+        let xs = [U256::ZERO, U256::ONE, U256::from(0xdeadbeefu32), p.wrapping_sub(&U256::ONE), p.shr_vartime(1), U256::MAX.shr_vartime(3)];
+        for &a in &xs {
+            for &b in &xs {
+                let (fa, fb) = (FieldElementBig::new(a, p), FieldElementBig::new(b, p));
+                let (sa, sb) = (Secp256k1Base::new(a), Secp256k1Base::new(b));
+                assert_eq!((sa * sb).to_dynamic(), fa * fb);
+                assert_eq!((sa + sb).to_dynamic(), fa + fb);
+                assert_eq!((sa - sb).to_dynamic(), fa - fb);
+            }
+        }
+        assert_eq!(Secp256k1Base::new(U256::MAX), Secp256k1Base::from_u64(0x1_0000_03d0));
+        let x = Secp256k1Base::from_u64(0xdeadbeef);
+        assert_eq!(x / x, Secp256k1Base::ONE);
+        assert_eq!(-x + x, Secp256k1Base::ZERO);
+        assert_eq!(Secp256k1Base::from_be_bytes(&x.to_be_bytes()), Ok(x));
+        assert_eq!(Secp256k1Base::from_be_bytes(&[0xff; 32]), Err(FieldError::OutOfRange));
+        assert_eq!(x.to_string(), x.to_dynamic().to_string());
+    }
+
+    #[test]
+    fn generator_is_on_the_curve() {
+        let gx = Secp256k1Base::new(U256::from_be_hex("79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"));
+        let gy = Secp256k1Base::new(U256::from_be_hex("483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8"));
+        let rhs = gx.square() * gx + Secp256k1Base::from_u64(7);
+        assert_eq!(gy.square(), rhs);
+        let root = rhs.sqrt().unwrap();
+        assert!(root == gy || root == -gy);
+        // −1 is a non-residue since p ≡ 3 mod 4
+        assert_eq!((-Secp256k1Base::ONE).sqrt(), None);
+    }
+
+    #[test]
+    fn scalar_bytes_work() {
+        let n = <Secp256k1Scalar as crate::Modulus<4>>::MODULUS;
+        assert_eq!(Secp256k1Scalar::from_bytes_reduced(&[0xff; 32]).get_num(), U256::MAX.wrapping_sub(&n));
+        assert_eq!(Secp256k1Scalar::from_bytes(&[0xff; 32]), Err(FieldError::OutOfRange));
+        assert_eq!(Secp256k1Scalar::from_bytes(&n.to_be_bytes()), Err(FieldError::OutOfRange));
+        assert_eq!(Secp256k1Scalar::from_bytes_reduced(&n.to_be_bytes()), Secp256k1Scalar::ZERO);
+
+        let k = Secp256k1Scalar::from_bytes(&[0x42; 32]).unwrap();
+        assert_eq!(Secp256k1Scalar::from_bytes(&k.to_bytes()), Ok(k));
+        assert_eq!(k.to_bytes(), [0x42; 32]);
+        assert_eq!(k * k.inverse().unwrap(), Secp256k1Scalar::ONE);
+    }
+}
@@ -0,0 +1,200 @@
+use std::{fmt, iter::{Product, Sum}, ops::{Add, Div, Mul, Neg, Sub}};
+use num::{One, Zero};
+use crate::FiniteField;
+
+/// An element of the prime field `F_P` for a machine-word prime `P`.
+///
+/// The modulus is part of the type, so elements store only their
+/// representative, mixing fields is a type error rather than a panic, and
+/// the type can implement [`Default`], [`Zero`] and [`One`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Fp<const P: u64>(u64);
+
+impl<const P: u64> Fp<P> {
+    pub const MODULUS: u64 = P;
+    pub const ZERO: Self = Fp(0);
+    pub const ONE: Self = Fp(1 % P);
+
+    pub const fn new(n: u64) -> Self {
+        Fp(n % P)
+    }
+
+    pub const fn value(&self) -> u64 {
+        self.0
+    }
+
+    pub fn pow(self, mut exp: u64) -> Self {
+        let mut result = Self::ONE;
+        let mut base = self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// Returns the inverse by Fermat's little theorem, or `None` for zero.
+    pub fn inverse(&self) -> Option<Self> {
+        if self.0 == 0 {
+            None
+        } else {
+            Some(self.pow(P - 2))
+        }
+    }
+}
+
+impl<const P: u64> Add for Fp<P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        if P - self.0 > rhs.0 {
+            Fp(self.0 + rhs.0)
+        } else {
+            Fp(rhs.0 - (P - self.0))
+        }
+    }
+}
+
+impl<const P: u64> Sub for Fp<P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        if self.0 >= rhs.0 {
+            Fp(self.0 - rhs.0)
+        } else {
+            Fp(P - (rhs.0 - self.0))
+        }
+    }
+}
+
+impl<const P: u64> Neg for Fp<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::ZERO - self
+    }
+}
+
+impl<const P: u64> Mul for Fp<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Fp((self.0 as u128 * rhs.0 as u128 % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> Div for Fp<P> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        self.mul(rhs.inverse().expect("Zero is not valid denominator."))
+    }
+}
+
+impl<const P: u64> Zero for Fp<P> {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl<const P: u64> One for Fp<P> {
+    fn one() -> Self {
+        Self::ONE
+    }
+}
+
+impl<const P: u64> Sum for Fp<P> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |a, b| a + b)
+    }
+}
+
+impl<const P: u64> Product for Fp<P> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |a, b| a * b)
+    }
+}
+
+impl<const P: u64> From<u64> for Fp<P> {
+    fn from(n: u64) -> Self {
+        Fp::new(n)
+    }
+}
+
+impl<const P: u64> FiniteField for Fp<P> {
+    fn zero(&self) -> Self {
+        Self::ZERO
+    }
+
+    fn one(&self) -> Self {
+        Self::ONE
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        Fp::inverse(self)
+    }
+
+    fn from_u64(&self, n: u64) -> Self {
+        Fp::new(n)
+    }
+}
+
+impl<const P: u64> fmt::Display for Fp<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (mod {})", self.0, P)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Polynomial;
+
+    type F7 = Fp<7>;
+    type Big = Fp<18446744073709551557>;
+
+    #[test]
+    fn arithmetic_works() {
+        let a = F7::new(5);
+        let b = F7::new(4);
+        assert_eq!(a + b, F7::new(2));
+        assert_eq!(b - a, F7::new(6));
+        assert_eq!(a * b, F7::new(6));
+        assert_eq!(a / b * b, a);
+        assert_eq!(-a, F7::new(2));
+        assert_eq!(F7::new(10), F7::new(3));
+        assert_eq!(a.to_string(), "5 (mod 7)");
+    }
+
+    #[test]
+    fn large_prime_works() {
+        let a = Big::new(Big::MODULUS - 1);
+        assert_eq!(a * a, Big::ONE);
+        assert_eq!(a + a, Big::new(Big::MODULUS - 2));
+        assert_eq!(Big::new(3).pow(Big::MODULUS - 1), Big::ONE);
+        assert_eq!(Big::ZERO.inverse(), None);
+    }
+
+    #[test]
+    fn identities_work() {
+        let zeros: [F7; 4] = Default::default();
+        assert!(zeros.iter().all(Zero::is_zero));
+        let xs: Vec<F7> = (1..7).map(F7::new).collect();
+        assert_eq!(<F7 as Zero>::zero(), F7::ZERO);
+        assert_eq!(<F7 as One>::one(), F7::ONE);
+        assert_eq!(xs.iter().copied().sum::<F7>(), F7::ZERO);
+        assert_eq!(xs.iter().copied().product::<F7>(), -F7::ONE);
+        assert_eq!(std::iter::empty::<F7>().product::<F7>(), F7::ONE);
+
+        let p = Polynomial::new(vec![F7::ONE, F7::ONE]);
+        assert_eq!((p.clone() * p).coefficients(), &[F7::ONE, F7::new(2), F7::ONE]);
+    }
+}
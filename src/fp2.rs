@@ -0,0 +1,231 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use crate::{ExtensionField, FiniteField, Polynomial};
+
+/// An element `c₀ + c₁u` of the quadratic extension `F(u)`, `u² = β`, for a
+/// non-residue `β` of the base field.
+///
+/// This is [`ExtensionField`] of degree two with the reduction written out:
+/// products take three base multiplications by Karatsuba, squares two, and
+/// the inverse is the conjugate over the norm. Like the modulus there, `β`
+/// is carried by each element and must agree between operands. It is not
+/// checked to be a non-residue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fp2<F> {
+    c0: F,
+    c1: F,
+    non_residue: F,
+}
+
+impl<F: FiniteField> Fp2<F> {
+    pub fn new(c0: F, c1: F, non_residue: F) -> Self {
+        Fp2 { c0, c1, non_residue }
+    }
+
+    /// Embeds a base field element as `c + 0·u`.
+    pub fn from_base(&self, c: F) -> Self {
+        Fp2 { c0: c, c1: c.zero(), non_residue: self.non_residue }
+    }
+
+    pub fn c0(&self) -> F {
+        self.c0
+    }
+
+    pub fn c1(&self) -> F {
+        self.c1
+    }
+
+    pub fn non_residue(&self) -> F {
+        self.non_residue
+    }
+
+    pub fn zero(self) -> Self {
+        self.from_base(self.c0.zero())
+    }
+
+    pub fn one(self) -> Self {
+        self.from_base(self.c0.one())
+    }
+
+    /// Returns `u`.
+    pub fn u(self) -> Self {
+        Fp2 { c0: self.c0.zero(), c1: self.c0.one(), non_residue: self.non_residue }
+    }
+
+    /// Returns `c₀ − c₁u`, the image under the non-trivial automorphism.
+    pub fn conjugate(self) -> Self {
+        Fp2 { c0: self.c0, c1: self.c1.zero() - self.c1, non_residue: self.non_residue }
+    }
+
+    /// Multiplies both coefficients by a base field element.
+    pub fn mul_by_base(self, c: F) -> Self {
+        Fp2 { c0: self.c0 * c, c1: self.c1 * c, non_residue: self.non_residue }
+    }
+
+    pub fn pow(self, mut exp: u64) -> Self {
+        let mut result = self.one();
+        let mut base = self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base;
+            }
+            base = base.square();
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// Squares with two base multiplications:
+    /// `(c₀ + c₁)(c₀ + βc₁) − (1 + β)c₀c₁` and `2c₀c₁`.
+    pub fn square(self) -> Self {
+        let (a, b, beta) = (self.c0, self.c1, self.non_residue);
+        let v = a * b;
+        Fp2 { c0: (a + b) * (a + beta * b) - v - beta * v, c1: v + v, non_residue: beta }
+    }
+
+    /// Returns the norm to the base field, `a·conj(a) = c₀² − βc₁²`.
+    pub fn norm(&self) -> F {
+        self.c0 * self.c0 - self.non_residue * self.c1 * self.c1
+    }
+
+    /// Returns the trace to the base field, `a + conj(a) = 2c₀`.
+    pub fn trace(&self) -> F {
+        self.c0 + self.c0
+    }
+
+    /// Returns `conj(a) / N(a)`, or `None` for zero.
+    pub fn inverse(&self) -> Option<Self> {
+        Some(self.conjugate().mul_by_base(self.norm().inverse()?))
+    }
+}
+
+impl<F: FiniteField> Add for Fp2<F> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        assert_eq!(self.non_residue, rhs.non_residue);
+        Fp2 { c0: self.c0 + rhs.c0, c1: self.c1 + rhs.c1, non_residue: self.non_residue }
+    }
+}
+
+impl<F: FiniteField> Sub for Fp2<F> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        assert_eq!(self.non_residue, rhs.non_residue);
+        Fp2 { c0: self.c0 - rhs.c0, c1: self.c1 - rhs.c1, non_residue: self.non_residue }
+    }
+}
+
+impl<F: FiniteField> Mul for Fp2<F> {
+    type Output = Self;
+
+    /// Karatsuba: `v₀ = a₀b₀`, `v₁ = a₁b₁`, then `c₀ = v₀ + βv₁` and
+    /// `c₁ = (a₀ + a₁)(b₀ + b₁) − v₀ − v₁`.
+    fn mul(self, rhs: Self) -> Self {
+        assert_eq!(self.non_residue, rhs.non_residue);
+        let v0 = self.c0 * rhs.c0;
+        let v1 = self.c1 * rhs.c1;
+        Fp2 {
+            c0: v0 + self.non_residue * v1,
+            c1: (self.c0 + self.c1) * (rhs.c0 + rhs.c1) - v0 - v1,
+            non_residue: self.non_residue,
+        }
+    }
+}
+
+impl<F: FiniteField> Div for Fp2<F> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Mul::mul(self, rhs.inverse().expect("Zero is not valid denominator."))
+    }
+}
+
+impl<F: FiniteField> Neg for Fp2<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.zero() - self
+    }
+}
+
+impl<F: FiniteField> FiniteField for Fp2<F> {
+    fn zero(&self) -> Self {
+        Fp2::zero(*self)
+    }
+
+    fn one(&self) -> Self {
+        Fp2::one(*self)
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        Fp2::inverse(self)
+    }
+
+    fn from_u64(&self, n: u64) -> Self {
+        self.from_base(self.c0.from_u64(n))
+    }
+}
+
+/// Views the element in the generic representation modulo `x² − β`.
+impl<F: FiniteField> From<Fp2<F>> for ExtensionField<F, 2> {
+    fn from(e: Fp2<F>) -> Self {
+        let one = e.c0.one();
+        let modulus = Polynomial::new(vec![one.zero() - e.non_residue, one.zero(), one]);
+        ExtensionField::new([e.c0, e.c1], &modulus)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FieldElement;
+
+    fn elements(p: u32, beta: u32) -> Vec<Fp2<FieldElement<u32>>> {
+        let beta = FieldElement::new(beta, p);
+        (0..p * p).map(|k| Fp2::new(FieldElement::new(k % p, p), FieldElement::new(k / p, p), beta)).collect()
+    }
+
+    #[test]
+    fn matches_extension_field() {
+        // −1 is a non-residue mod 7, and 3 mod 5
+        for (p, beta) in [(7, 6), (5, 3)] {
+            let xs = elements(p, beta);
+            for &a in &xs {
+                for &b in &xs {
+                    let (ea, eb) = (ExtensionField::from(a), ExtensionField::from(b));
+                    assert_eq!(ExtensionField::from(a * b), ea * eb);
+                    assert_eq!(ExtensionField::from(a + b), ea + eb);
+                    assert_eq!(ExtensionField::from(a - b), ea - eb);
+                }
+                assert_eq!(a.square(), a * a);
+            }
+        }
+    }
+
+    #[test]
+    fn inverse_and_conjugate_work() {
+        let xs = elements(7, 6);
+        let u = xs[0].u();
+        assert_eq!(u * u, -u.one());
+        for &a in &xs[1..] {
+            assert_eq!(a * a.inverse().unwrap(), a.one());
+            assert_eq!(a.pow(48), a.one());
+            // the conjugate is the Frobenius a^p
+            assert_eq!(a.conjugate(), a.pow(7));
+        }
+        assert_eq!(xs[0].inverse(), None);
+        assert_eq!(u.from_u64(8), u.one());
+    }
+
+    #[test]
+    fn norm_and_trace_work() {
+        let xs = elements(7, 6);
+        for &a in &xs {
+            assert_eq!(a.from_base(a.norm()), a * a.conjugate());
+            assert_eq!(a.norm(), ExtensionField::from(a).norm());
+            assert_eq!(a.trace(), ExtensionField::from(a).trace());
+        }
+    }
+}
@@ -0,0 +1,325 @@
+//! The Frobenius endomorphism `a ↦ a^p` and its powers.
+
+use std::fmt::Debug;
+use crypto_bigint::Uint;
+use crate::{ExtensionField, FieldElement, FieldElementBig, FieldElementTyped, FiniteField, Fp, Fp2, Modulus, Widening};
+use crate::binary_field::BinaryFieldElement;
+use crate::fields::Gf256;
+use crate::tower::Fp3;
+
+/// Fields that know their characteristic `p` and degree over the prime
+/// subfield, and so can apply the Frobenius map `a ↦ a^p`.
+///
+/// On prime fields the map is the identity. An extension applies it to its
+/// coefficients and multiplies them by the images of its generator, which
+/// it derives from the modulus or non-residue it carries.
+pub trait Frobenius: FiniteField {
+    /// The characteristic `p` as little-endian 64-bit words.
+    fn characteristic(&self) -> Vec<u64>;
+
+    /// The degree `n` of the field over `GF(p)`, after which the Frobenius
+    /// map repeats.
+    fn absolute_degree(&self) -> usize;
+
+    /// Returns `a^(p^power)`.
+    fn frobenius_map(&self, power: usize) -> Self;
+}
+
+fn pow_words<F: FiniteField>(x: F, exp: &[u64]) -> F {
+    let mut result = x.one();
+    for &word in exp.iter().rev() {
+        for i in (0..64).rev() {
+            result = result * result;
+            if word >> i & 1 == 1 {
+                result = result * x;
+            }
+        }
+    }
+    result
+}
+
+/// Raises `g` to `p^power` by repeated `p`-th powers, for generators whose
+/// image cannot be read off their coefficients.
+fn frobenius_by_pow<F: Frobenius>(g: F, p: &[u64], power: usize) -> F {
+    (0..power % g.absolute_degree()).fold(g, |x, _| pow_words(x, p))
+}
+
+impl<T> Frobenius for FieldElement<T>
+    where T: Widening + Debug,
+{
+    fn characteristic(&self) -> Vec<u64> {
+        let p = self.get_order().to_u128().expect("order does not fit in u128");
+        if p >> 64 == 0 {
+            vec![p as u64]
+        } else {
+            vec![p as u64, (p >> 64) as u64]
+        }
+    }
+
+    fn absolute_degree(&self) -> usize {
+        1
+    }
+
+    fn frobenius_map(&self, _power: usize) -> Self {
+        *self
+    }
+}
+
+impl<const P: u64> Frobenius for Fp<P> {
+    fn characteristic(&self) -> Vec<u64> {
+        vec![P]
+    }
+
+    fn absolute_degree(&self) -> usize {
+        1
+    }
+
+    fn frobenius_map(&self, _power: usize) -> Self {
+        *self
+    }
+}
+
+fn uint_words<const LIMBS: usize>(n: &Uint<LIMBS>) -> Vec<u64> {
+    let bytes: Vec<u8> = n.as_words().iter().flat_map(|w| w.to_le_bytes()).collect();
+    bytes.chunks(8).map(|c| c.iter().rev().fold(0, |acc, &b| acc << 8 | b as u64)).collect()
+}
+
+impl<const LIMBS: usize> Frobenius for FieldElementBig<LIMBS> {
+    fn characteristic(&self) -> Vec<u64> {
+        uint_words(&self.get_order())
+    }
+
+    fn absolute_degree(&self) -> usize {
+        1
+    }
+
+    fn frobenius_map(&self, _power: usize) -> Self {
+        *self
+    }
+}
+
+impl<M: Modulus<LIMBS>, const LIMBS: usize> Frobenius for FieldElementTyped<M, LIMBS> {
+    fn characteristic(&self) -> Vec<u64> {
+        uint_words(&M::MODULUS)
+    }
+
+    fn absolute_degree(&self) -> usize {
+        1
+    }
+
+    fn frobenius_map(&self, _power: usize) -> Self {
+        *self
+    }
+}
+
+impl<const M: usize> Frobenius for BinaryFieldElement<M> {
+    fn characteristic(&self) -> Vec<u64> {
+        vec![2]
+    }
+
+    fn absolute_degree(&self) -> usize {
+        M
+    }
+
+    fn frobenius_map(&self, power: usize) -> Self {
+        (0..power % M).fold(*self, |x, _| x.square())
+    }
+}
+
+impl Frobenius for Gf256 {
+    fn characteristic(&self) -> Vec<u64> {
+        vec![2]
+    }
+
+    fn absolute_degree(&self) -> usize {
+        8
+    }
+
+    fn frobenius_map(&self, power: usize) -> Self {
+        (0..power % 8).fold(*self, |x, _| x.square())
+    }
+}
+
+impl<F: Frobenius, const N: usize> ExtensionField<F, N> {
+    /// Returns `x^(i·p^power)` for `i < N`, the images of the basis under
+    /// the Frobenius map, which [`ExtensionField::frobenius_map_with`]
+    /// applies. Computing them costs `power·log p` multiplications, so
+    /// repeated maps should reuse them.
+    pub fn frobenius_coefficients(&self, power: usize) -> [Self; N] {
+        let gamma = frobenius_by_pow(self.x(), &self.characteristic(), power);
+        let mut coeffs = [self.one(); N];
+        for i in 1..N {
+            coeffs[i] = coeffs[i - 1] * gamma;
+        }
+        coeffs
+    }
+
+    /// Applies the Frobenius map whose basis images are `coeffs`.
+    pub fn frobenius_map_with(&self, power: usize, coeffs: &[Self; N]) -> Self {
+        self.coefficients().iter().zip(coeffs)
+            .fold(self.zero(), |acc, (c, &g)| acc + g * self.from_base(c.frobenius_map(power)))
+    }
+}
+
+impl<F: Frobenius, const N: usize> Frobenius for ExtensionField<F, N> {
+    fn characteristic(&self) -> Vec<u64> {
+        self.coefficients()[0].characteristic()
+    }
+
+    fn absolute_degree(&self) -> usize {
+        N * self.coefficients()[0].absolute_degree()
+    }
+
+    fn frobenius_map(&self, power: usize) -> Self {
+        self.frobenius_map_with(power, &self.frobenius_coefficients(power))
+    }
+}
+
+impl<F: Frobenius> Fp2<F> {
+    /// Returns `γ = β^((p^power − 1)/2)`, so that `u^(p^power) = γu`.
+    pub fn frobenius_coefficient(&self, power: usize) -> F {
+        frobenius_by_pow(self.u(), &self.characteristic(), power).c1()
+    }
+
+    /// Applies the Frobenius map whose coefficient is `gamma`.
+    pub fn frobenius_map_with(&self, power: usize, gamma: F) -> Self {
+        Fp2::new(self.c0().frobenius_map(power), self.c1().frobenius_map(power) * gamma, self.non_residue())
+    }
+}
+
+impl<F: Frobenius> Frobenius for Fp2<F> {
+    fn characteristic(&self) -> Vec<u64> {
+        self.c0().characteristic()
+    }
+
+    fn absolute_degree(&self) -> usize {
+        2 * self.c0().absolute_degree()
+    }
+
+    fn frobenius_map(&self, power: usize) -> Self {
+        self.frobenius_map_with(power, self.frobenius_coefficient(power))
+    }
+}
+
+impl<F: Frobenius> Fp3<F> {
+    /// Whether `p^power ≡ 2 mod 3`, in which case the Frobenius map sends
+    /// `v` to a multiple of `v²` and back. The words of `p` sum to `p` mod 3
+    /// since `2⁶⁴ ≡ 1`.
+    fn frobenius_swaps(&self, power: usize) -> bool {
+        let p_mod_3 = self.characteristic().iter().fold(0, |acc, &w| (acc + w % 3) % 3);
+        p_mod_3 == 2 && power % 2 == 1
+    }
+
+    /// Returns `[γ₁, γ₂]` with `v^(p^power) = γ₁v` and `v^(2p^power) = γ₂v²`
+    /// when `p^power ≡ 1 mod 3`, where `γ₁ = ξ^((p^power − 1)/3)` and
+    /// `γ₂ = γ₁²`. When `p^power ≡ 2 mod 3` they are instead given by
+    /// `v^(p^power) = γ₁v²` and `v^(2p^power) = γ₂v`, so
+    /// `γ₁ = ξ^((p^power − 2)/3)` and `γ₂ = γ₁²ξ`.
+    pub fn frobenius_coefficients(&self, power: usize) -> [F; 2] {
+        let image = frobenius_by_pow(self.v(), &self.characteristic(), power);
+        if self.frobenius_swaps(power) {
+            let gamma = image.c2();
+            [gamma, gamma * gamma * self.non_residue()]
+        } else {
+            let gamma = image.c1();
+            [gamma, gamma * gamma]
+        }
+    }
+
+    /// Applies the Frobenius map whose coefficients are `gammas`.
+    pub fn frobenius_map_with(&self, power: usize, gammas: [F; 2]) -> Self {
+        let c1 = self.c1().frobenius_map(power) * gammas[0];
+        let c2 = self.c2().frobenius_map(power) * gammas[1];
+        let (c1, c2) = if self.frobenius_swaps(power) { (c2, c1) } else { (c1, c2) };
+        Fp3::new(self.c0().frobenius_map(power), c1, c2, self.non_residue())
+    }
+}
+
+impl<F: Frobenius> Frobenius for Fp3<F> {
+    fn characteristic(&self) -> Vec<u64> {
+        self.c0().characteristic()
+    }
+
+    fn absolute_degree(&self) -> usize {
+        3 * self.c0().absolute_degree()
+    }
+
+    fn frobenius_map(&self, power: usize) -> Self {
+        self.frobenius_map_with(power, self.frobenius_coefficients(power))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Polynomial;
+    use crate::tower::Fp12;
+    use crypto_bigint::U256;
+
+    type F7 = FieldElement<u32>;
+
+    #[test]
+    fn prime_fields_are_fixed() {
+        let x = F7::new(3, 7);
+        assert_eq!(x.frobenius_map(1), x);
+        assert_eq!(x.characteristic(), vec![7]);
+        let y = FieldElementBig::new(U256::from(3u8), U256::from_u64(u64::MAX - 58));
+        assert_eq!(y.characteristic(), [u64::MAX - 58, 0, 0, 0]);
+    }
+
+    #[test]
+    fn extension_field_works() {
+        // x³ + x + 1 over GF(5)
+        let f = Polynomial::new([1, 1, 0, 1].iter().map(|&n| FieldElement::new(n, 5u32)).collect());
+        let a = ExtensionField::new([2, 3, 4].map(|n| FieldElement::new(n, 5)), &f);
+        for k in 0..4 {
+            assert_eq!(a.frobenius_map(k), a.pow(5u64.pow(k as u32)));
+        }
+        assert_eq!(a.frobenius_map(3), a);
+        assert_eq!(a.absolute_degree(), 3);
+        let coeffs = a.frobenius_coefficients(2);
+        assert_eq!(a.square().frobenius_map_with(2, &coeffs), a.square().pow(25));
+    }
+
+    #[test]
+    fn tower_works() {
+        let fp2 = |a, b| Fp2::new(F7::new(a, 7), F7::new(b, 7), F7::new(6, 7));
+        let fp6 = |c: [u32; 6]| Fp3::new(fp2(c[0], c[1]), fp2(c[2], c[3]), fp2(c[4], c[5]), fp2(2, 1));
+        let f = Fp12::from_fp6(fp6([1, 2, 3, 4, 5, 6]), fp6([0, 1, 2, 3, 4, 5]));
+        assert_eq!(f.absolute_degree(), 12);
+        for k in [1, 2, 3, 6] {
+            assert_eq!(f.frobenius_map(k), f.pow(7u64.pow(k as u32)));
+        }
+        assert_eq!(f.frobenius_map(6), f.conjugate());
+        assert_eq!(f.frobenius_map(12), f);
+        // over a prime field the quadratic Frobenius is conjugation
+        let a = fp2(3, 4);
+        assert_eq!(a.frobenius_map(1), a.conjugate());
+        assert_eq!(a.frobenius_coefficient(1), F7::new(6, 7));
+    }
+
+    #[test]
+    fn cubic_frobenius_swaps_when_p_is_2_mod_3() {
+        // Fp6 = Fp2[v]/(v³ − ξ) over Fp2 = F5[u]/(u² − 2), ξ = 1 + u
+        let fp2 = |a, b| Fp2::new(FieldElement::new(a, 5u32), FieldElement::new(b, 5), FieldElement::new(2, 5));
+        let xi = fp2(1, 1);
+        assert_ne!(xi.pow(8), xi.one());
+        let a = Fp3::new(fp2(1, 2), fp2(3, 4), fp2(2, 0), xi);
+        for k in 0..7 {
+            assert_eq!(a.frobenius_map(k), a.pow(5u64.pow(k as u32)), "k = {}", k);
+        }
+        let coeffs = a.frobenius_coefficients(1);
+        assert_eq!(a.v().frobenius_map_with(1, coeffs), a.v().pow(5));
+        assert_eq!(a.square().frobenius_map_with(1, coeffs), a.square().pow(5));
+    }
+
+    #[test]
+    fn binary_fields_square() {
+        let a = Gf256::new(0x53);
+        assert_eq!(a.frobenius_map(1), a.square());
+        assert_eq!(a.frobenius_map(8), a);
+        let b = BinaryFieldElement::<4>::new(0b1011, 0x13);
+        assert_eq!(b.frobenius_map(2), b.pow(4));
+    }
+}
@@ -0,0 +1,293 @@
+use crate::{BinaryFieldElement, FieldError, FiniteField, Matrix, Polynomial};
+use crate::hamming::{Bit, bit};
+use crate::polynomial::{div_rem_poly, gcd, mul_mod, mul_poly, sub_poly, trim};
+
+/// Rabin's test over `GF(2^M)`: `g` of degree `t` is irreducible if and
+/// only if `x^(q^t) ≡ x mod g` and `gcd(x^(q^(t/r)) − x, g) = 1` for every
+/// prime `r` dividing `t`, where `q = 2^M`.
+fn is_irreducible<const M: usize>(g: &[BinaryFieldElement<M>]) -> bool {
+    let t = g.len() - 1;
+    let x = div_rem_poly(&[g[0].zero(), g[0].one()], g).1;
+    // x^(q^k) by M·k squarings
+    let frobenius = |k: usize| (0..M * k).fold(x.clone(), |y, _| mul_mod(&y, &y, g));
+    frobenius(t) == x && primes::factors_uniq(t as u64).into_iter().all(|r| {
+        gcd(sub_poly(&frobenius(t / r as usize), &x), g.to_vec()).len() == 1
+    })
+}
+
+/// Returns `a⁻¹ mod g` by the extended Euclidean algorithm, or `None` when
+/// `gcd(a, g) ≠ 1`.
+fn inverse_mod<E: FiniteField>(a: &[E], g: &[E]) -> Option<Vec<E>> {
+    let (mut r0, mut r1) = (g.to_vec(), trim(a.to_vec()));
+    let (mut s0, mut s1) = (Vec::new(), vec![g[0].one()]);
+    while !r1.is_empty() {
+        let (q, r) = div_rem_poly(&r0, &r1);
+        let s = sub_poly(&s0, &mul_poly(&q, &s1));
+        (r0, r1) = (r1, r);
+        (s0, s1) = (s1, s);
+    }
+    if r0.len() != 1 {
+        return None;
+    }
+    Some(div_rem_poly(&mul_poly(&s0, &[r0[0].inverse()?]), g).1)
+}
+
+/// A binary Goppa code `Γ(L, g)`: the words `c ∈ GF(2)^n` with
+/// `Σ cⱼ / (x − αⱼ) ≡ 0 mod g`, for a Goppa polynomial `g` of degree `t`
+/// over `GF(2^M)` and a support `L = (α₀, …, α_{n−1})` of distinct
+/// elements that are not roots of `g`.
+///
+/// `g` must be irreducible, so that Patterson's algorithm corrects any `t`
+/// errors; this is the setting of McEliece. The dimension is at least
+/// `n − M·t`.
+///
+/// The generator matrix is kept in reduced row echelon form, so the
+/// message occupies the pivot columns of each codeword.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GoppaCode<const M: usize> {
+    goppa: Vec<BinaryFieldElement<M>>,
+    support: Vec<BinaryFieldElement<M>>,
+    parity_check: Matrix<Bit>,
+    generator: Matrix<Bit>,
+    info_set: Vec<usize>,
+}
+
+impl<const M: usize> GoppaCode<M> {
+    /// Builds the code, returning [`FieldError::ReducibleModulus`] if `g`
+    /// is reducible and [`FieldError::NotInvertible`] if a support element
+    /// is a root of `g`. Panics if `g` is constant or the support repeats
+    /// an element.
+    pub fn new(goppa: &Polynomial<BinaryFieldElement<M>>, support: Vec<BinaryFieldElement<M>>) -> Result<Self, FieldError> {
+        let g = goppa.coefficients().to_vec();
+        assert!(g.len() >= 2, "The Goppa polynomial must have positive degree.");
+        assert!(
+            support.iter().enumerate().all(|(i, a)| !support[..i].contains(a)),
+            "Support elements must be distinct.",
+        );
+        if !is_irreducible(&g) {
+            return Err(FieldError::ReducibleModulus);
+        }
+
+        // H = [αⱼ^i / g(αⱼ)], each GF(2^M) entry expanded into M bits
+        let t = g.len() - 1;
+        let mut rows = vec![Vec::with_capacity(support.len()); t * M];
+        for &a in &support {
+            let mut entry = goppa.evaluate(a).inverse().ok_or(FieldError::NotInvertible)?;
+            for i in 0..t {
+                for b in 0..M {
+                    rows[i * M + b].push(bit(entry.get_num() >> b & 1 == 1));
+                }
+                entry = entry * a;
+            }
+        }
+        let parity_check = Matrix::new(rows);
+        let kernel = parity_check.kernel();
+        let mut generator = if kernel.is_empty() {
+            Matrix::zero(0, support.len(), bit(false))
+        } else {
+            Matrix::new(kernel)
+        };
+        let info_set = generator.rref();
+        Ok(GoppaCode { goppa: g, support, parity_check, generator, info_set })
+    }
+
+    /// Builds the code with every element of `GF(2^M)` in the support, so
+    /// `n = 2^M`. `g` must have no roots in the field, which irreducible
+    /// polynomials of degree above one never have.
+    pub fn with_full_support(goppa: &Polynomial<BinaryFieldElement<M>>) -> Result<Self, FieldError> {
+        let poly = goppa.coefficients().first().expect("The Goppa polynomial must have positive degree.").get_poly();
+        let support = (0..1u64 << M).map(|n| BinaryFieldElement::new(n, poly)).collect();
+        Self::new(goppa, support)
+    }
+
+    /// Block length.
+    pub fn n(&self) -> usize {
+        self.support.len()
+    }
+
+    /// Message length.
+    pub fn k(&self) -> usize {
+        self.info_set.len()
+    }
+
+    /// The number of errors Patterson decoding corrects, `deg g`.
+    pub fn t(&self) -> usize {
+        self.goppa.len() - 1
+    }
+
+    pub fn goppa_polynomial(&self) -> Polynomial<BinaryFieldElement<M>> {
+        Polynomial::new(self.goppa.clone())
+    }
+
+    pub fn support(&self) -> &[BinaryFieldElement<M>] {
+        &self.support
+    }
+
+    /// The binary parity-check matrix, `M·t` rows by `n` columns; its rank
+    /// may be lower than `M·t`.
+    pub fn parity_check_matrix(&self) -> Matrix<Bit> {
+        self.parity_check.clone()
+    }
+
+    pub fn generator_matrix(&self) -> Matrix<Bit> {
+        self.generator.clone()
+    }
+
+    pub fn encode(&self, message: &[Bit]) -> Vec<Bit> {
+        assert_eq!(message.len(), self.k());
+        (0..self.n())
+            .map(|j| message.iter().enumerate().fold(bit(false), |acc, (i, &m)| acc + m * self.generator.get(i, j)))
+            .collect()
+    }
+
+    /// Returns `H·y`, which is zero exactly for codewords.
+    pub fn syndrome(&self, received: &[Bit]) -> Vec<Bit> {
+        self.parity_check.mul_vec(received)
+    }
+
+    /// Corrects up to `t` bit errors by Patterson's algorithm and returns
+    /// the codeword, or `None` when the errors cannot be located.
+    ///
+    /// With `S(x) = Σ 1/(x − αⱼ)` over the received ones and
+    /// `τ = √(S⁻¹ + x) mod g`, the error locator is `σ = a² + x·b²` for
+    /// `a ≡ b·τ mod g` with `deg a ≤ t/2` and `deg b ≤ (t − 1)/2`, found
+    /// by stopping the Euclidean algorithm on `(g, τ)` half way.
+    pub fn correct(&self, received: &[Bit]) -> Option<Vec<Bit>> {
+        assert_eq!(received.len(), self.n());
+        let g = &self.goppa;
+        let (zero, one) = (g[0].zero(), g[0].one());
+        let x = [zero, one];
+
+        // 1/(x − α) = −q(x)/g(α), where g = q·(x − α) + g(α)
+        let goppa = self.goppa_polynomial();
+        let mut s = Vec::new();
+        for (&a, y) in self.support.iter().zip(received) {
+            if y.get_num() == 1 {
+                let q = div_rem_poly(g, &[zero - a, one]).0;
+                s = sub_poly(&s, &mul_poly(&q, &[goppa.evaluate(a).inverse()?]));
+            }
+        }
+        if s.is_empty() {
+            return Some(received.to_vec());
+        }
+
+        let v = sub_poly(&inverse_mod(&s, g)?, &x);
+        let sigma = if v.is_empty() {
+            x.to_vec()
+        } else {
+            // F[x]/(g) has 2^(M·t) elements, so √v = v^(2^(M·t − 1))
+            let tau = (1..M * self.t()).fold(v, |w, _| mul_mod(&w, &w, g));
+            let (mut r0, mut r1) = (g.clone(), tau);
+            let (mut b0, mut b1) = (Vec::new(), vec![one]);
+            while r1.len() > self.t() / 2 + 1 {
+                let (q, r) = div_rem_poly(&r0, &r1);
+                let b = sub_poly(&b0, &mul_poly(&q, &b1));
+                (r0, r1) = (r1, r);
+                (b0, b1) = (b1, b);
+            }
+            let a2 = mul_poly(&r1, &r1);
+            let xb2 = mul_poly(&x, &mul_poly(&b1, &b1));
+            // subtraction is addition in characteristic 2
+            sub_poly(&a2, &xb2)
+        };
+
+        let locator = Polynomial::new(sigma);
+        let mut word = received.to_vec();
+        let mut flipped = 0;
+        for (j, &a) in self.support.iter().enumerate() {
+            if locator.evaluate(a).is_zero() {
+                word[j] = word[j] + bit(true);
+                flipped += 1;
+            }
+        }
+        if Some(flipped) != locator.degree() || self.syndrome(&word).iter().any(|b| b.get_num() == 1) {
+            return None;
+        }
+        Some(word)
+    }
+
+    /// Corrects the received word and extracts the message bits.
+    pub fn decode(&self, received: &[Bit]) -> Option<Vec<Bit>> {
+        let word = self.correct(received)?;
+        Some(self.info_set.iter().map(|&j| word[j]).collect())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Gf16 = BinaryFieldElement<4>;
+    type Gf32 = BinaryFieldElement<5>;
+
+    // x² + x + α³ over GF(16) = GF(2)[α]/(α⁴ + α + 1)
+    fn goppa_16() -> Polynomial<Gf16> {
+        Polynomial::new([8, 1, 1].map(|n| Gf16::new(n, 0x13)).to_vec())
+    }
+
+    // x³ + x + 1 over GF(32) = GF(2)[α]/(α⁵ + α² + 1)
+    fn goppa_32() -> Polynomial<Gf32> {
+        Polynomial::new([1, 1, 0, 1].map(|n| Gf32::new(n, 0x25)).to_vec())
+    }
+
+    fn message(k: usize, seed: usize) -> Vec<Bit> {
+        (0..k).map(|i| bit((i * 7 + seed) % 3 == 1)).collect()
+    }
+
+    #[test]
+    fn matrices_are_orthogonal() {
+        let code = GoppaCode::with_full_support(&goppa_16()).unwrap();
+        assert_eq!((code.n(), code.k(), code.t()), (16, 8, 2));
+        let h = code.parity_check_matrix();
+        let g = code.generator_matrix();
+        assert_eq!((h.rows(), h.cols()), (8, 16));
+        let product = &h * &g.transpose();
+        assert_eq!(product, Matrix::zero(h.rows(), code.k(), bit(false)));
+        let codeword = code.encode(&message(code.k(), 1));
+        assert!(code.syndrome(&codeword).iter().all(|b| b.get_num() == 0));
+        assert_eq!(code.decode(&codeword), Some(message(code.k(), 1)));
+    }
+
+    #[test]
+    fn corrects_up_to_t_errors() {
+        let code = GoppaCode::with_full_support(&goppa_16()).unwrap();
+        let m = message(code.k(), 2);
+        let codeword = code.encode(&m);
+        for i in 0..16 {
+            for j in i..16 {
+                let mut received = codeword.clone();
+                received[i] = received[i] + bit(true);
+                if j != i {
+                    received[j] = received[j] + bit(true);
+                }
+                assert_eq!(code.correct(&received), Some(codeword.clone()), "errors at {} and {}", i, j);
+            }
+        }
+
+        let code = GoppaCode::with_full_support(&goppa_32()).unwrap();
+        assert_eq!((code.n(), code.k()), (32, 17));
+        let m = message(code.k(), 0);
+        let codeword = code.encode(&m);
+        for (i, j, l) in [(0, 1, 2), (3, 17, 31), (5, 6, 30), (10, 20, 25), (0, 16, 31)] {
+            let mut received = codeword.clone();
+            for e in [i, j, l] {
+                received[e] = received[e] + bit(true);
+            }
+            assert_eq!(code.decode(&received), Some(m.clone()));
+        }
+    }
+
+    #[test]
+    fn bad_parameters_fail() {
+        // (x + 1)(x + α)
+        let reducible = Polynomial::new([2, 3, 1].map(|n| Gf16::new(n, 0x13)).to_vec());
+        assert_eq!(GoppaCode::with_full_support(&reducible), Err(FieldError::ReducibleModulus));
+        // x + α has the root α
+        let linear = Polynomial::new([2, 1].map(|n| Gf16::new(n, 0x13)).to_vec());
+        assert_eq!(GoppaCode::with_full_support(&linear), Err(FieldError::NotInvertible));
+        let support = (3..16).map(|n| Gf16::new(n, 0x13)).collect();
+        let code = GoppaCode::new(&linear, support).unwrap();
+        assert_eq!((code.n(), code.t()), (13, 1));
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{FieldElement, Matrix};
+
+pub(crate) type Bit = FieldElement<u8>;
+
+pub(crate) fn bit(b: bool) -> Bit {
+    FieldElement::new(b as u8, 2)
+}
+
+/// A binary Hamming code with `r` parity bits, correcting any single error
+/// in blocks of `2^r − 1` bits. The extended variant adds an overall parity
+/// bit and additionally detects double errors.
+///
+/// The parity-check matrix is in systematic form `H = [A | I_r]`, so the
+/// message occupies the first `k` bits of each codeword.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HammingCode {
+    r: usize,
+    extended: bool,
+    // syndrome bits of each codeword position, as integers
+    columns: Vec<usize>,
+}
+
+impl HammingCode {
+    pub fn new(r: usize) -> Self {
+        assert!((2..usize::BITS as usize).contains(&r), "Need at least two parity bits.");
+        let n = (1 << r) - 1;
+        let mut columns: Vec<usize> = (1..=n).filter(|c: &usize| !c.is_power_of_two()).collect();
+        columns.extend((0..r).map(|i| 1 << i));
+        HammingCode {
+            r,
+            extended: false,
+            columns,
+        }
+    }
+
+    pub fn extended(r: usize) -> Self {
+        HammingCode {
+            extended: true,
+            ..Self::new(r)
+        }
+    }
+
+    /// Block length.
+    pub fn n(&self) -> usize {
+        self.columns.len() + self.extended as usize
+    }
+
+    /// Message length.
+    pub fn k(&self) -> usize {
+        self.columns.len() - self.r
+    }
+
+    pub fn parity_check_matrix(&self) -> Matrix<Bit> {
+        let mut rows: Vec<Vec<Bit>> = (0..self.r)
+            .map(|i| {
+                let mut row: Vec<Bit> = self.columns.iter().map(|c| bit(c >> i & 1 == 1)).collect();
+                if self.extended {
+                    row.push(bit(false));
+                }
+                row
+            })
+            .collect();
+        if self.extended {
+            rows.push(vec![bit(true); self.n()]);
+        }
+        Matrix::new(rows)
+    }
+
+    pub fn generator_matrix(&self) -> Matrix<Bit> {
+        let rows = (0..self.k())
+            .map(|j| {
+                let mut unit = vec![bit(false); self.k()];
+                unit[j] = bit(true);
+                self.encode(&unit)
+            })
+            .collect();
+        Matrix::new(rows)
+    }
+
+    pub fn encode(&self, message: &[Bit]) -> Vec<Bit> {
+        assert_eq!(message.len(), self.k());
+        let mut codeword = message.to_vec();
+        for i in 0..self.r {
+            let parity = self.columns.iter().zip(message)
+                .filter(|(c, _)| *c >> i & 1 == 1)
+                .fold(bit(false), |acc, (_, &m)| acc + m);
+            codeword.push(parity);
+        }
+        if self.extended {
+            let parity = codeword.iter().fold(bit(false), |acc, &b| acc + b);
+            codeword.push(parity);
+        }
+        codeword
+    }
+
+    /// Returns `H·y`, which is zero exactly for codewords.
+    pub fn syndrome(&self, received: &[Bit]) -> Vec<Bit> {
+        self.parity_check_matrix().mul_vec(received)
+    }
+
+    /// Corrects up to one bit error and returns the codeword, or `None` when
+    /// the extended code detects a double error.
+    pub fn correct(&self, received: &[Bit]) -> Option<Vec<Bit>> {
+        assert_eq!(received.len(), self.n());
+        let mut word = received.to_vec();
+        let syndrome = self.columns.iter().zip(&word)
+            .filter(|(_, b)| b.get_num() == 1)
+            .fold(0, |acc, (c, _)| acc ^ c);
+        let overall_odd = word.iter().filter(|b| b.get_num() == 1).count() % 2 == 1;
+
+        match (syndrome, self.extended) {
+            (0, false) => {}
+            (0, true) if !overall_odd => {}
+            // only the overall parity bit is wrong
+            (0, true) => {
+                let last = word.len() - 1;
+                word[last] = word[last] + bit(true);
+            }
+            (_, true) if !overall_odd => return None,
+            (s, _) => {
+                let pos = self.columns.iter().position(|&c| c == s)?;
+                word[pos] = word[pos] + bit(true);
+            }
+        }
+        Some(word)
+    }
+
+    /// Corrects the received word and extracts the message bits.
+    pub fn decode(&self, received: &[Bit]) -> Option<Vec<Bit>> {
+        let mut word = self.correct(received)?;
+        word.truncate(self.k());
+        Some(word)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bits(s: &str) -> Vec<Bit> {
+        s.chars().map(|c| bit(c == '1')).collect()
+    }
+
+    #[test]
+    fn matrices_are_orthogonal() {
+        for code in [HammingCode::new(3), HammingCode::extended(3), HammingCode::new(4)] {
+            let h = code.parity_check_matrix();
+            let g = code.generator_matrix();
+            assert_eq!((h.rows(), h.cols()), (code.n() - code.k(), code.n()));
+            assert_eq!(g.rank(), code.k());
+            let product = &h * &g.transpose();
+            assert_eq!(product, Matrix::zero(h.rows(), code.k(), bit(false)));
+        }
+    }
+
+    #[test]
+    fn single_errors_are_corrected() {
+        let code = HammingCode::new(3);
+        assert_eq!((code.n(), code.k()), (7, 4));
+        let message = bits("1011");
+        let codeword = code.encode(&message);
+        assert!(code.syndrome(&codeword).iter().all(|b| b.get_num() == 0));
+        for i in 0..7 {
+            let mut received = codeword.clone();
+            received[i] = received[i] + bit(true);
+            assert_eq!(code.decode(&received), Some(message.clone()));
+        }
+    }
+
+    #[test]
+    fn extended_detects_double_errors() {
+        let code = HammingCode::extended(3);
+        let message = bits("0110");
+        let codeword = code.encode(&message);
+        assert_eq!(codeword.len(), 8);
+        for i in 0..8 {
+            let mut received = codeword.clone();
+            received[i] = received[i] + bit(true);
+            assert_eq!(code.correct(&received), Some(codeword.clone()));
+        }
+        let mut received = codeword;
+        received[0] = received[0] + bit(true);
+        received[5] = received[5] + bit(true);
+        assert_eq!(code.decode(&received), None);
+    }
+}
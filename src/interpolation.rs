@@ -0,0 +1,142 @@
+use crate::{FieldError, FiniteField, Polynomial};
+
+/// Interpolates points one at a time in the Newton form
+/// `c₀ + c₁(x − x₀) + c₂(x − x₀)(x − x₁) + …`.
+///
+/// Alongside the coefficients `cᵢ = f[x₀, …, xᵢ]` it keeps the last
+/// diagonal of the divided-difference table, `f[xᵢ, …, x_{n−1}]` for each
+/// `i`, so adding a point costs `n` divisions rather than rebuilding the
+/// table. Earlier coefficients never change, and after `n` points the
+/// interpolant is the unique polynomial of degree below `n` through them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewtonInterpolator<F> {
+    xs: Vec<F>,
+    coeffs: Vec<F>,
+    diagonal: Vec<F>,
+}
+
+impl<F: FiniteField> NewtonInterpolator<F> {
+    pub fn new() -> Self {
+        NewtonInterpolator { xs: Vec::new(), coeffs: Vec::new(), diagonal: Vec::new() }
+    }
+
+    /// Interpolates `points` in order.
+    pub fn from_points(points: &[(F, F)]) -> Result<Self, FieldError> {
+        let mut interpolator = Self::new();
+        for &(x, y) in points {
+            interpolator.add_point(x, y)?;
+        }
+        Ok(interpolator)
+    }
+
+    /// Adds the point `(x, y)`, raising the degree by at most one. Returns
+    /// [`FieldError::NotInvertible`], leaving the interpolant unchanged, if
+    /// `x` repeats an earlier point.
+    pub fn add_point(&mut self, x: F, y: F) -> Result<(), FieldError> {
+        let inverses = self.xs.iter()
+            .map(|&xi| (x - xi).inverse().ok_or(FieldError::NotInvertible))
+            .collect::<Result<Vec<_>, _>>()?;
+        // f[xᵢ, …, x] = (f[xᵢ₊₁, …, x] − f[xᵢ, …, x_{n−1}]) / (x − xᵢ)
+        self.diagonal.push(y);
+        for i in (0..self.xs.len()).rev() {
+            self.diagonal[i] = (self.diagonal[i + 1] - self.diagonal[i]) * inverses[i];
+        }
+        self.xs.push(x);
+        self.coeffs.push(self.diagonal[0]);
+        Ok(())
+    }
+
+    /// The number of points added so far.
+    pub fn len(&self) -> usize {
+        self.xs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.xs.is_empty()
+    }
+
+    /// The interpolation points `x₀, x₁, …` in order.
+    pub fn nodes(&self) -> &[F] {
+        &self.xs
+    }
+
+    /// The Newton coefficients `f[x₀], f[x₀, x₁], …`.
+    pub fn coefficients(&self) -> &[F] {
+        &self.coeffs
+    }
+
+    /// Evaluates the interpolant at `x` by nested multiplication.
+    pub fn evaluate(&self, x: F) -> F {
+        self.coeffs.iter().zip(&self.xs).rev()
+            .fold(x.zero(), |acc, (&c, &xi)| acc * (x - xi) + c)
+    }
+
+    /// Converts the interpolant to the monomial basis.
+    pub fn polynomial(&self) -> Polynomial<F> {
+        let mut p = Polynomial::new(Vec::new());
+        for (&c, &xi) in self.coeffs.iter().zip(&self.xs).rev() {
+            let one = c.one();
+            p = p * Polynomial::new(vec![one.zero() - xi, one]) + Polynomial::new(vec![c]);
+        }
+        p
+    }
+}
+
+impl<F: FiniteField> Default for NewtonInterpolator<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FieldElement;
+
+    fn c(n: u32) -> FieldElement<u32> {
+        FieldElement::new(n, 97)
+    }
+
+    #[test]
+    fn recovers_polynomial() {
+        // 5x³ + 2x + 7
+        let f = Polynomial::new([7, 2, 0, 5].map(c).to_vec());
+        let mut newton = NewtonInterpolator::new();
+        for (k, x) in [3, 10, 0, 42, 96].into_iter().enumerate() {
+            newton.add_point(c(x), f.evaluate(c(x))).unwrap();
+            assert_eq!(newton.len(), k + 1);
+            assert_eq!(newton.evaluate(c(x)), f.evaluate(c(x)));
+        }
+        assert_eq!(newton.polynomial(), f);
+        assert_eq!(newton.coefficients()[4], c(0));
+        assert!((0..97).all(|x| newton.evaluate(c(x)) == f.evaluate(c(x))));
+    }
+
+    #[test]
+    fn points_are_incremental() {
+        let points: Vec<_> = [(1, 4), (2, 9), (5, 1), (7, 50)].map(|(x, y)| (c(x), c(y))).to_vec();
+        let mut newton = NewtonInterpolator::from_points(&points[..2]).unwrap();
+        let before = newton.coefficients().to_vec();
+        newton.add_point(points[2].0, points[2].1).unwrap();
+        newton.add_point(points[3].0, points[3].1).unwrap();
+        assert_eq!(newton.coefficients()[..2], before[..]);
+        assert_eq!(newton, NewtonInterpolator::from_points(&points).unwrap());
+        let p = newton.polynomial();
+        assert!(p.degree() < Some(4));
+        assert!(points.iter().all(|&(x, y)| p.evaluate(x) == y));
+        // f[x₀] = 4 and f[x₀, x₁] = (9 − 4)/(2 − 1)
+        assert_eq!(newton.coefficients()[..2], [c(4), c(5)]);
+    }
+
+    #[test]
+    fn repeated_node_fails() {
+        let mut newton = NewtonInterpolator::new();
+        newton.add_point(c(3), c(1)).unwrap();
+        let snapshot = newton.clone();
+        assert_eq!(newton.add_point(c(3), c(2)), Err(FieldError::NotInvertible));
+        assert_eq!(newton, snapshot);
+        assert!(NewtonInterpolator::<FieldElement<u32>>::default().is_empty());
+        assert_eq!(newton.nodes(), [c(3)]);
+    }
+}
@@ -0,0 +1,181 @@
+//! Irreducible polynomials over small prime fields, for choosing the
+//! modulus of an [`ExtensionField`](crate::ExtensionField).
+
+use std::fmt::Debug;
+use num::NumCast;
+use primes::factors_uniq;
+use crate::{FieldElement, Polynomial, Widening};
+use crate::polynomial::{div_rem_poly, sub_poly, trim};
+use crate::sequence::mul_mod;
+
+fn pow_mod<T>(a: &[FieldElement<T>], mut exp: u64, f: &[FieldElement<T>]) -> Vec<FieldElement<T>>
+    where T: Widening + Debug,
+{
+    let zero = f[0].zero();
+    let mut result = vec![zero; f.len() - 1];
+    result[0] = zero.one();
+    let mut base = a.to_vec();
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(&result, &base, f);
+        }
+        base = mul_mod(&base, &base, f);
+        exp >>= 1;
+    }
+    result
+}
+
+fn gcd<T>(a: Vec<FieldElement<T>>, b: Vec<FieldElement<T>>) -> Vec<FieldElement<T>>
+    where T: Widening + Debug,
+{
+    let (mut a, mut b) = (trim(a), trim(b));
+    while !b.is_empty() {
+        let (_, r) = div_rem_poly(&a, &b);
+        (a, b) = (b, r);
+    }
+    a
+}
+
+/// Tests irreducibility by Rabin's criterion: `f` of degree `n` is
+/// irreducible if and only if `x^(p^n) ≡ x mod f` and
+/// `gcd(x^(p^(n/q)) − x, f) = 1` for every prime `q` dividing `n`.
+pub fn is_irreducible<T>(poly: &Polynomial<FieldElement<T>>) -> bool
+    where T: Widening + Debug,
+{
+    let n = match poly.degree() {
+        Some(n) if n > 0 => n,
+        _ => return false,
+    };
+    if n == 1 {
+        return true;
+    }
+    let coeffs = poly.coefficients();
+    let lead_inv = coeffs[n].inverse().unwrap();
+    let f: Vec<_> = coeffs.iter().map(|&c| c * lead_inv).collect();
+    let p = f[0].get_order().to_u64().expect("order does not fit in u64");
+
+    let zero = f[0].zero();
+    let mut x = vec![zero; n];
+    x[1] = zero.one();
+    // frobenius[k] = x^(p^k) mod f
+    let mut frobenius = vec![x.clone()];
+    for k in 0..n {
+        let next = pow_mod(&frobenius[k], p, &f);
+        frobenius.push(next);
+    }
+    frobenius[n] == x
+        && factors_uniq(n as u64).iter().all(|&q| {
+            gcd(sub_poly(&frobenius[n / q as usize], &x), f.clone()).len() == 1
+        })
+}
+
+fn monic<T>(degree: usize, order: T, mut digit: impl FnMut() -> u64) -> Polynomial<FieldElement<T>>
+    where T: Widening + Debug,
+{
+    let p = order.to_u64().unwrap();
+    let mut coeffs: Vec<_> = (0..degree)
+        .map(|_| FieldElement::new(NumCast::from(digit() % p).unwrap(), order))
+        .collect();
+    coeffs.push(FieldElement::new(T::one(), order));
+    Polynomial::new(coeffs)
+}
+
+/// Iterates over all monic irreducible polynomials of the given degree over
+/// the prime field of order `order`.
+pub fn irreducible_polynomials<T>(degree: usize, order: T) -> impl Iterator<Item = Polynomial<FieldElement<T>>>
+    where T: Widening + Debug,
+{
+    let p = order.to_u64().unwrap();
+    let count = p.checked_pow(degree as u32).expect("search space is too large");
+    (0..count).filter_map(move |k| {
+        let mut rest = k;
+        let poly = monic(degree, order, || {
+            let digit = rest % p;
+            rest /= p;
+            digit
+        });
+        if is_irreducible(&poly) {
+            Some(poly)
+        } else {
+            None
+        }
+    })
+}
+
+/// Returns the first monic irreducible polynomial of the given degree.
+pub fn find_irreducible_polynomial<T>(degree: usize, order: T) -> Option<Polynomial<FieldElement<T>>>
+    where T: Widening + Debug,
+{
+    irreducible_polynomials(degree, order).next()
+}
+
+/// Samples a monic irreducible polynomial of the given degree by drawing
+/// random monic candidates until one passes [`is_irreducible`]. About one
+/// in `degree` candidates is irreducible. `next_u64` supplies the
+/// randomness, taken modulo the order for each coefficient.
+pub fn sample_irreducible_polynomial<T, R>(degree: usize, order: T, mut next_u64: R) -> Polynomial<FieldElement<T>>
+    where T: Widening + Debug,
+          R: FnMut() -> u64,
+{
+    assert!(degree > 0, "degree must be positive");
+    loop {
+        let poly = monic(degree, order, &mut next_u64);
+        if is_irreducible(&poly) {
+            return poly;
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sequence::primitive_polynomials;
+
+    fn poly(nums: &[u8], order: u8) -> Polynomial<FieldElement<u8>> {
+        Polynomial::new(nums.iter().map(|&n| FieldElement::new(n, order)).collect())
+    }
+
+    #[test]
+    fn is_irreducible_works() {
+        assert!(is_irreducible(&poly(&[1, 1, 0, 0, 1], 2)));
+        assert!(is_irreducible(&poly(&[1, 1, 1, 1, 1], 2)));
+        // (x² + x + 1)² and (x + 1)⁴
+        assert!(!is_irreducible(&poly(&[1, 0, 1, 0, 1], 2)));
+        assert!(!is_irreducible(&poly(&[1, 0, 0, 0, 1], 2)));
+        // x² + 1 splits mod 5 but not mod 3, and scaling does not matter
+        assert!(is_irreducible(&poly(&[2, 0, 2], 3)));
+        assert!(!is_irreducible(&poly(&[1, 0, 1], 5)));
+        assert!(is_irreducible(&poly(&[3, 1], 5)));
+        assert!(!is_irreducible(&poly(&[3], 5)));
+    }
+
+    #[test]
+    fn counts_match_gauss_formula() {
+        // (1/n) Σ_{d | n} μ(d) p^(n/d)
+        assert_eq!(irreducible_polynomials(4, 2u8).count(), 3);
+        assert_eq!(irreducible_polynomials(6, 2u8).count(), 9);
+        assert_eq!(irreducible_polynomials(2, 3u8).count(), 3);
+        assert_eq!(irreducible_polynomials(3, 3u8).count(), 8);
+        assert_eq!(irreducible_polynomials(2, 7u8).count(), 21);
+        assert!(primitive_polynomials(5, 2u8).all(|f| is_irreducible(&f)));
+        assert_eq!(find_irreducible_polynomial(2, 3u8), Some(poly(&[1, 0, 1], 3)));
+    }
+
+    #[test]
+    fn sampling_works() {
+        let mut state = 0x2545f4914f6cdd1du64;
+        let mut xorshift = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        for degree in 1..6 {
+            let f = sample_irreducible_polynomial(degree, 13u8, &mut xorshift);
+            assert_eq!(f.degree(), Some(degree));
+            assert_eq!(f.coefficients()[degree].get_num(), 1);
+            assert!(is_irreducible(&f));
+        }
+    }
+}
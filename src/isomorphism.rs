@@ -0,0 +1,151 @@
+use crate::{ExtensionField, FiniteField, Frobenius, Matrix, Polynomial};
+use crate::polynomial::distinct_roots;
+
+/// The matrix whose column `i` holds the coefficients of `aⁱ`.
+fn power_matrix<F: FiniteField, const N: usize>(a: ExtensionField<F, N>) -> Matrix<F> {
+    let mut m = Matrix::zero(N, N, a.coefficients()[0]);
+    let mut power = a.one();
+    for i in 0..N {
+        for (r, &c) in power.coefficients().iter().enumerate() {
+            m.set(r, i, c);
+        }
+        power = power * a;
+    }
+    m
+}
+
+/// The isomorphism `F[x]/(f) → F[y]/(g)` between two representations of
+/// `GF(p^N)` over a prime field `F`, sending `x` to a root of `f` modulo
+/// `g`.
+///
+/// The map is `F`-linear, so it is kept as the matrix whose columns are the
+/// images of `1, x, …, x^{N−1}`, together with the matrix of its inverse;
+/// applying either costs `N²` base multiplications. The root is found by
+/// equal-degree splitting, and which of the `N` roots is chosen is fixed
+/// but otherwise unspecified: composing with a power of the Frobenius map
+/// gives the others.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldIsomorphism<F, const N: usize> {
+    source: ExtensionField<F, N>,
+    target: ExtensionField<F, N>,
+    forward: Matrix<F>,
+    backward: Matrix<F>,
+}
+
+impl<F: Frobenius, const N: usize> FieldIsomorphism<F, N> {
+    /// Finds an isomorphism from `F[x]/(from)` to `F[y]/(to)`. Both moduli
+    /// must have degree `N`, and one always exists when both are
+    /// irreducible. Returns `None` if `from` has no root modulo `to` that
+    /// generates the field, which can only happen if one of them is
+    /// reducible.
+    pub fn new(from: &Polynomial<F>, to: &Polynomial<F>) -> Option<Self> {
+        let zero = from.coefficients().first().expect("modulus must have degree N").zero();
+        assert_eq!(zero.absolute_degree(), 1, "base field must be a prime field");
+        let source = ExtensionField::new([zero; N], from);
+        let target = ExtensionField::new([zero; N], to);
+
+        let p = zero.characteristic();
+        let radix = if p.len() == 1 { p[0] } else { u64::MAX };
+        let delta = |mut j: u64| -> ExtensionField<F, N> {
+            let coeffs = std::array::from_fn(|_| {
+                let digit = zero.from_u64(j % radix);
+                j /= radix;
+                digit
+            });
+            ExtensionField::new(coeffs, to)
+        };
+        let f = source.modulus().coefficients().iter().map(|&c| target.from_base(c)).collect();
+        let forward = power_matrix(distinct_roots(f, delta).into_iter().next()?);
+        if forward.rank() < N {
+            return None;
+        }
+        let y = forward.solve(target.x().coefficients())?;
+        let backward = power_matrix(ExtensionField::<F, N>::new(std::array::from_fn(|i| y[i]), from));
+        Some(FieldIsomorphism { source, target, forward, backward })
+    }
+
+    /// Returns the inverse isomorphism `F[y]/(g) → F[x]/(f)`.
+    pub fn inverse(&self) -> Self {
+        FieldIsomorphism {
+            source: self.target,
+            target: self.source,
+            forward: self.backward.clone(),
+            backward: self.forward.clone(),
+        }
+    }
+
+    /// Returns the image of `x`, the root of `f` in the target field.
+    pub fn generator_image(&self) -> ExtensionField<F, N> {
+        self.apply(self.source.x())
+    }
+
+    /// Maps an element of the source field to the target field.
+    pub fn apply(&self, a: ExtensionField<F, N>) -> ExtensionField<F, N> {
+        assert_eq!(a.zero(), self.source);
+        Self::map(&self.forward, a, &self.target)
+    }
+
+    /// Maps an element of the target field back to the source field.
+    pub fn apply_inverse(&self, b: ExtensionField<F, N>) -> ExtensionField<F, N> {
+        assert_eq!(b.zero(), self.target);
+        Self::map(&self.backward, b, &self.source)
+    }
+
+    fn map(m: &Matrix<F>, a: ExtensionField<F, N>, to: &ExtensionField<F, N>) -> ExtensionField<F, N> {
+        let v = m.mul_vec(a.coefficients());
+        ExtensionField::new(std::array::from_fn(|i| v[i]), &to.modulus())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conway::conway_polynomial;
+    use crate::testing::{elements, modulus};
+
+    #[test]
+    fn cubic_isomorphism_works() {
+        // x³ + x + 1 over GF(5) to the Conway polynomial x³ + 3x + 3
+        let f = modulus(&[1, 1, 0, 1], 5);
+        let g = conway_polynomial(5, 3).unwrap();
+        let phi = FieldIsomorphism::<_, 3>::new(&f, &g).unwrap();
+        let r = phi.generator_image();
+        assert_eq!(r.pow(3) + r + r.one(), r.zero());
+        let xs = elements::<3>(&f);
+        for &a in &xs {
+            assert_eq!(phi.apply_inverse(phi.apply(a)), a);
+            for &b in xs.iter().step_by(7) {
+                assert_eq!(phi.apply(a * b), phi.apply(a) * phi.apply(b));
+                assert_eq!(phi.apply(a + b), phi.apply(a) + phi.apply(b));
+            }
+        }
+        let psi = phi.inverse();
+        assert_eq!(psi.apply(phi.generator_image()), xs[0].x());
+    }
+
+    #[test]
+    fn aes_to_conway_works() {
+        // x⁸ + x⁴ + x³ + x + 1 to x⁸ + x⁴ + x³ + x² + 1 over GF(2)
+        let f = modulus(&[1, 1, 0, 1, 1, 0, 0, 0, 1], 2);
+        let g = conway_polynomial(2, 8).unwrap();
+        let phi = FieldIsomorphism::<_, 8>::new(&f, &g).unwrap();
+        let xs = elements::<8>(&f);
+        for &a in xs.iter().step_by(5) {
+            assert_eq!(phi.inverse().apply(phi.apply(a)), a);
+            for &b in xs.iter().step_by(17) {
+                assert_eq!(phi.apply(a * b), phi.apply(a) * phi.apply(b));
+            }
+        }
+        assert_eq!(phi.apply(xs[1]), phi.apply(xs[1]).one());
+    }
+
+    #[test]
+    fn reducible_modulus_fails() {
+        // (x + 1)(x³ + x + 1) has only the root 1 in GF(16)
+        let f = modulus(&[1, 0, 1, 1, 1], 2);
+        let g = modulus(&[1, 1, 0, 0, 1], 2);
+        assert_eq!(FieldIsomorphism::<_, 4>::new(&f, &g), None);
+        assert!(FieldIsomorphism::<_, 4>::new(&g, &modulus(&[1, 0, 0, 1, 1], 2)).is_some());
+    }
+}
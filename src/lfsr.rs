@@ -0,0 +1,207 @@
+//! Linear feedback shift registers over prime fields.
+
+use std::{collections::VecDeque, fmt::Debug};
+use crate::{FieldElement, Polynomial, Widening};
+
+/// An LFSR producing `s_n = c_1·s_(n-1) + c_2·s_(n-2) + ... + c_L·s_(n-L)`.
+///
+/// The register is an iterator over its output sequence, starting with the
+/// seed values.
+#[derive(Debug, Clone)]
+pub struct Lfsr<T> {
+    taps: Vec<FieldElement<T>>,
+    state: VecDeque<FieldElement<T>>,
+}
+
+impl<T: Widening + Debug> Lfsr<T> {
+    /// Creates a register from the taps `c_1, ..., c_L` and the first `L`
+    /// terms of the sequence.
+    pub fn new(taps: Vec<FieldElement<T>>, seed: Vec<FieldElement<T>>) -> Self {
+        assert_eq!(taps.len(), seed.len(), "Seed length must match the register length.");
+        Lfsr {
+            taps,
+            state: seed.into(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.taps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.taps.is_empty()
+    }
+
+    pub fn taps(&self) -> &[FieldElement<T>] {
+        &self.taps
+    }
+
+    /// The largest possible period `p^L − 1`, reached exactly when the
+    /// feedback polynomial is primitive and the seed is non-zero.
+    pub fn max_period(&self) -> Option<u64> {
+        let p = self.taps.first()?.get_order().to_u64()?;
+        p.checked_pow(self.len() as u32).map(|q| q - 1)
+    }
+
+    /// Computes the period of the output sequence by running the register
+    /// until its state repeats.
+    ///
+    /// Returns `None` if `c_L` is zero, in which case the sequence need not be
+    /// purely periodic.
+    pub fn period(&self) -> Option<u64> {
+        let last = *self.taps.last()?;
+        if last == last.zero() {
+            return None;
+        }
+        let bound = self.max_period()?.max(1);
+        let mut register = self.clone();
+        for k in 1..=bound {
+            register.next();
+            if register.state == self.state {
+                return Some(k);
+            }
+        }
+        None
+    }
+}
+
+impl<T> Lfsr<T>
+    where T: Widening + Debug,
+{
+    /// Builds the shortest register generating `sequence`, seeded with its
+    /// first terms.
+    pub fn from_sequence(sequence: &[FieldElement<T>]) -> Self {
+        let (connection, len) = massey(sequence);
+        let zero = sequence[0].zero();
+        let taps = (1..=len)
+            .map(|i| zero - connection.get(i).copied().unwrap_or(zero))
+            .collect();
+        Lfsr::new(taps, sequence[..len].to_vec())
+    }
+}
+
+impl<T: Widening + Debug> Iterator for Lfsr<T> {
+    type Item = FieldElement<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let out = *self.state.front()?;
+        let len = self.taps.len();
+        let feedback = self.taps.iter()
+            .enumerate()
+            .fold(out.zero(), |acc, (i, &c)| acc + c * self.state[len - 1 - i]);
+        self.state.pop_front();
+        self.state.push_back(feedback);
+        Some(out)
+    }
+}
+
+fn massey<T>(sequence: &[FieldElement<T>]) -> (Vec<FieldElement<T>>, usize)
+    where T: Widening + Debug,
+{
+    assert!(!sequence.is_empty(), "Sequence must not be empty.");
+    let one = sequence[0].one();
+    let zero = sequence[0].zero();
+
+    let mut c = vec![one];
+    let mut b = vec![one];
+    let mut len = 0;
+    let mut shift = 1;
+    let mut last_discrepancy = one;
+
+    for n in 0..sequence.len() {
+        let d = (1..=len).fold(sequence[n], |acc, i| {
+            acc + c.get(i).copied().unwrap_or(zero) * sequence[n - i]
+        });
+        if d == zero {
+            shift += 1;
+            continue;
+        }
+
+        let coef = d / last_discrepancy;
+        let previous = c.clone();
+        if c.len() < b.len() + shift {
+            c.resize(b.len() + shift, zero);
+        }
+        for (i, &bi) in b.iter().enumerate() {
+            c[i + shift] = c[i + shift] - coef * bi;
+        }
+
+        if 2 * len <= n {
+            len = n + 1 - len;
+            b = previous;
+            last_discrepancy = d;
+            shift = 1;
+        } else {
+            shift += 1;
+        }
+    }
+    (c, len)
+}
+
+/// Returns the connection polynomial `C(x) = 1 + c_1·x + ... + c_L·x^L` of
+/// the shortest linear recurrence `Σ c_i·s_(n-i) = 0` generating `sequence`.
+pub fn berlekamp_massey<T>(sequence: &[FieldElement<T>]) -> Polynomial<FieldElement<T>>
+    where T: Widening + Debug,
+{
+    Polynomial::new(massey(sequence).0)
+}
+
+/// The length `L` of the shortest LFSR generating `sequence`.
+pub fn linear_complexity<T>(sequence: &[FieldElement<T>]) -> usize
+    where T: Widening + Debug,
+{
+    massey(sequence).1
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn elements(nums: &[u8], order: u8) -> Vec<FieldElement<u8>> {
+        nums.iter().map(|&n| FieldElement::new(n, order)).collect()
+    }
+
+    #[test]
+    fn fibonacci_works() {
+        let lfsr = Lfsr::new(elements(&[1, 1], 7), elements(&[0, 1], 7));
+        let seq: Vec<u8> = lfsr.take(10).map(|e| e.get_num()).collect();
+        assert_eq!(seq, vec![0, 1, 1, 2, 3, 5, 1, 6, 0, 6]);
+    }
+
+    #[test]
+    fn primitive_period_works() {
+        // x^4 + x + 1 is primitive over GF(2): s_n = s_(n-3) + s_(n-4)
+        let lfsr = Lfsr::new(elements(&[0, 0, 1, 1], 2), elements(&[1, 0, 0, 0], 2));
+        assert_eq!(lfsr.max_period(), Some(15));
+        assert_eq!(lfsr.period(), Some(15));
+
+        // x^4 + x^3 + x^2 + x + 1 is irreducible but not primitive
+        let lfsr = Lfsr::new(elements(&[1, 1, 1, 1], 2), elements(&[1, 0, 0, 0], 2));
+        assert_eq!(lfsr.period(), Some(5));
+    }
+
+    #[test]
+    fn berlekamp_massey_works() {
+        let fib: Vec<_> = Lfsr::new(elements(&[1, 1], 7), elements(&[0, 1], 7)).take(10).collect();
+        assert_eq!(berlekamp_massey(&fib), Polynomial::new(elements(&[1, 6, 6], 7)));
+        assert_eq!(linear_complexity(&fib), 2);
+
+        let bits: Vec<_> = Lfsr::new(elements(&[0, 0, 1, 1], 2), elements(&[1, 0, 0, 0], 2)).take(20).collect();
+        assert_eq!(berlekamp_massey(&bits), Polynomial::new(elements(&[1, 0, 0, 1, 1], 2)));
+    }
+
+    #[test]
+    fn from_sequence_works() {
+        let seq = elements(&[2, 0, 1, 4, 4, 3, 1, 1, 0, 2], 5);
+        let lfsr = Lfsr::from_sequence(&seq);
+        let regenerated: Vec<_> = lfsr.take(seq.len()).collect();
+        assert_eq!(regenerated, seq);
+    }
+
+    #[test]
+    fn singular_period_is_none() {
+        let lfsr = Lfsr::new(elements(&[1, 0], 3), elements(&[1, 2], 3));
+        assert_eq!(lfsr.period(), None);
+    }
+}
@@ -1,4 +1,78 @@
+mod barrett;
+mod binary_field;
+mod context;
+mod convert;
+mod error;
+mod extension;
+mod field;
 mod field_element;
 mod field_element_bigint;
-pub use field_element::FieldElement;
-pub use field_element_bigint::FieldElement as FieldElementBig;
\ No newline at end of file
+mod field_element_biguint;
+mod field_element_dyn;
+mod field_element_typed;
+mod fp;
+mod fp2;
+mod frobenius;
+mod goppa;
+mod hamming;
+mod interpolation;
+mod isomorphism;
+mod macros;
+mod matrix;
+mod montgomery;
+mod polynomial;
+#[cfg(feature = "primality")]
+mod primality;
+mod ring;
+mod rns;
+mod sage;
+mod small_field;
+#[cfg(test)]
+mod testing;
+mod widening;
+pub mod branded;
+pub mod conway;
+pub mod curve;
+pub mod eval;
+pub mod fields;
+pub mod irreducible;
+pub mod lfsr;
+pub mod lookup;
+pub mod ntt;
+pub mod permutation;
+pub mod schoof;
+pub mod sequence;
+pub mod tower;
+pub mod transcript;
+pub mod utils;
+pub use barrett::{BarrettContext, BarrettElement};
+pub use binary_field::BinaryFieldElement;
+pub use context::{ContextElement, FieldContext};
+pub use error::FieldError;
+pub use extension::ExtensionField;
+pub use field::FiniteField;
+pub use field_element::{FieldElement, Rounding};
+pub use field_element_bigint::FieldElement as FieldElementBig;
+pub use field_element_biguint::FieldElement as FieldElementBigUint;
+pub use field_element_dyn::DynFieldElement;
+pub use field_element_typed::{FieldElementTyped, Modulus};
+pub use fp::Fp;
+pub use fp2::Fp2;
+pub use frobenius::Frobenius;
+pub use goppa::GoppaCode;
+pub use hamming::HammingCode;
+pub use interpolation::NewtonInterpolator;
+pub use isomorphism::FieldIsomorphism;
+pub use matrix::Matrix;
+pub use montgomery::{MontgomeryContext, MontgomeryElement};
+pub use polynomial::Polynomial;
+pub use ring::RingElement;
+pub use rns::RnsElement;
+pub use small_field::{SmallElement, SmallField};
+pub use widening::Widening;
+#[doc(hidden)]
+pub mod __private {
+    pub use crypto_bigint::Uint;
+    pub use num::{One, Zero};
+    pub use crate::macros::{limbs_for_hex, uint_from_hex, uint_from_literal};
+}
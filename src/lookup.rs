@@ -0,0 +1,121 @@
+//! Multiset-equality and plookup-style lookup helpers.
+
+use std::{collections::HashMap, fmt::Debug, hash::Hash};
+use crate::{FieldElement, Widening};
+
+/// Folds a tuple of values into one element as `v0 + α·v1 + α²·v2 + ...`.
+pub fn compress<T>(values: &[FieldElement<T>], alpha: FieldElement<T>) -> FieldElement<T>
+    where T: Widening + Debug,
+{
+    values.iter().rev().fold(alpha.zero(), |acc, &v| acc * alpha + v)
+}
+
+/// Randomized multiset hash `Π (γ + v)`; two multisets are equal with high
+/// probability when their hashes agree at a random `γ`.
+pub fn multiset_hash<T>(values: &[FieldElement<T>], gamma: FieldElement<T>) -> FieldElement<T>
+    where T: Widening + Debug,
+{
+    values.iter().fold(gamma.one(), |acc, &v| acc * (gamma + v))
+}
+
+/// Probabilistic multiset equality check at the challenge `γ`.
+pub fn multiset_eq<T>(a: &[FieldElement<T>], b: &[FieldElement<T>], gamma: FieldElement<T>) -> bool
+    where T: Widening + Debug,
+{
+    a.len() == b.len() && multiset_hash(a, gamma) == multiset_hash(b, gamma)
+}
+
+/// Builds the plookup vector `s`: the concatenation of `f` and `t`, sorted
+/// by the order in which values appear in `t`.
+///
+/// Returns `None` if some value of `f` does not occur in `t`.
+pub fn sorted_concat<T>(f: &[FieldElement<T>], t: &[FieldElement<T>]) -> Option<Vec<FieldElement<T>>>
+    where T: Widening + Hash,
+{
+    let mut counts: HashMap<T, usize> = HashMap::new();
+    for v in f {
+        *counts.entry(v.get_num()).or_insert(0) += 1;
+    }
+    let mut s = Vec::with_capacity(f.len() + t.len());
+    for &v in t {
+        s.push(v);
+        if let Some(count) = counts.remove(&v.get_num()) {
+            s.extend(std::iter::repeat_n(v, count));
+        }
+    }
+    if counts.is_empty() {
+        Some(s)
+    } else {
+        None
+    }
+}
+
+/// Checks the plookup identity showing that every value of `f` lies in the
+/// table `t`, for challenges `β` and `γ`.
+pub fn verify_lookup<T>(
+    f: &[FieldElement<T>],
+    t: &[FieldElement<T>],
+    beta: FieldElement<T>,
+    gamma: FieldElement<T>,
+) -> bool
+    where T: Widening + Debug + Hash,
+{
+    let s = match sorted_concat(f, t) {
+        Some(s) => s,
+        None => return false,
+    };
+    let one = beta.one();
+    let gamma_beta = gamma * (one + beta);
+    let pairs = |v: &[FieldElement<T>]| {
+        v.windows(2)
+            .fold(one, |acc, w| acc * (gamma_beta + w[0] + beta * w[1]))
+    };
+
+    let lhs = f.iter().fold(one, |acc, &v| acc * (one + beta) * (gamma + v)) * pairs(t);
+    let rhs = pairs(&s);
+    lhs == rhs
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn elements(nums: &[u64]) -> Vec<FieldElement<u64>> {
+        nums.iter().map(|&n| FieldElement::new(n, 10007)).collect()
+    }
+
+    #[test]
+    fn compress_works() {
+        let alpha = FieldElement::new(10, 10007u64);
+        let tuple = elements(&[3, 2, 1]);
+        assert_eq!(compress(&tuple, alpha), FieldElement::new(123, 10007));
+    }
+
+    #[test]
+    fn multiset_eq_works() {
+        let gamma = FieldElement::new(4321, 10007u64);
+        let a = elements(&[5, 1, 5, 9]);
+        let b = elements(&[9, 5, 1, 5]);
+        let c = elements(&[9, 5, 1, 1]);
+        assert!(multiset_eq(&a, &b, gamma));
+        assert!(!multiset_eq(&a, &c, gamma));
+    }
+
+    #[test]
+    fn sorted_concat_works() {
+        let t = elements(&[1, 2, 3, 4]);
+        let f = elements(&[4, 2, 2]);
+        assert_eq!(sorted_concat(&f, &t), Some(elements(&[1, 2, 2, 2, 3, 4, 4])));
+        assert_eq!(sorted_concat(&elements(&[5]), &t), None);
+    }
+
+    #[test]
+    fn verify_lookup_works() {
+        let beta = FieldElement::new(71, 10007u64);
+        let gamma = FieldElement::new(2024, 10007u64);
+        let t = elements(&[1, 2, 3, 4]);
+        assert!(verify_lookup(&elements(&[4, 2, 2]), &t, beta, gamma));
+        assert!(!verify_lookup(&elements(&[4, 2, 7]), &t, beta, gamma));
+    }
+}
@@ -0,0 +1,312 @@
+//! Compile-time helpers for [`define_prime_field!`](crate::define_prime_field).
+
+use crypto_bigint::{Limb, Uint, Word};
+
+const fn digits_start(hex: &[u8]) -> usize {
+    if hex.len() >= 2 && hex[0] == b'0' && (hex[1] == b'x' || hex[1] == b'X') {
+        2
+    } else {
+        0
+    }
+}
+
+/// Returns the number of limbs needed to hold a hex literal, with or
+/// without a `0x` prefix.
+pub const fn limbs_for_hex(hex: &str) -> usize {
+    let hex = hex.as_bytes();
+    let limbs = ((hex.len() - digits_start(hex)) * 4).div_ceil(Limb::BITS);
+    if limbs == 0 { 1 } else { limbs }
+}
+
+/// Parses a hex literal of any length that fits in `LIMBS` limbs.
+pub const fn uint_from_hex<const LIMBS: usize>(hex: &str) -> Uint<LIMBS> {
+    let hex = hex.as_bytes();
+    let start = digits_start(hex);
+    let nibbles_per_word = Limb::BITS / 4;
+    let mut words = [0 as Word; LIMBS];
+    let mut i = hex.len();
+    let mut nibble = 0;
+    while i > start {
+        i -= 1;
+        let digit = match hex[i] {
+            b'0'..=b'9' => hex[i] - b'0',
+            b'a'..=b'f' => hex[i] - b'a' + 10,
+            b'A'..=b'F' => hex[i] - b'A' + 10,
+            _ => panic!("invalid hex digit"),
+        };
+        words[nibble / nibbles_per_word] |= (digit as Word) << (nibble % nibbles_per_word * 4);
+        nibble += 1;
+    }
+    Uint::from_words(words)
+}
+
+/// Parses an integer literal as written in source, in decimal or with a
+/// `0x` prefix, optionally quoted and with `_` separators. Panics, which
+/// fails compilation in a constant, if it does not fit in `LIMBS` limbs.
+pub const fn uint_from_literal<const LIMBS: usize>(literal: &str) -> Uint<LIMBS> {
+    let bytes = literal.as_bytes();
+    let (mut i, mut end) = (0, bytes.len());
+    if end >= 2 && bytes[0] == b'"' && bytes[end - 1] == b'"' {
+        i += 1;
+        end -= 1;
+    }
+    let mut radix = 10;
+    if end - i >= 2 && bytes[i] == b'0' && (bytes[i + 1] == b'x' || bytes[i + 1] == b'X') {
+        radix = 16;
+        i += 2;
+    }
+    let mut n = Uint::<LIMBS>::ZERO;
+    while i < end {
+        let digit = match bytes[i] {
+            b'_' => {
+                i += 1;
+                continue;
+            }
+            b'0'..=b'9' => bytes[i] - b'0',
+            b'a'..=b'f' if radix == 16 => bytes[i] - b'a' + 10,
+            b'A'..=b'F' if radix == 16 => bytes[i] - b'A' + 10,
+            _ => panic!("invalid digit in literal"),
+        };
+        let (lo, hi) = n.mul_wide(&Uint::<1>::from_u8(radix));
+        let (sum, carry) = lo.adc(&Uint::from_u8(digit), Limb::ZERO);
+        if hi.as_words()[0] != 0 || carry.0 != 0 {
+            panic!("literal does not fit in the field's limbs");
+        }
+        n = sum;
+        i += 1;
+    }
+    n
+}
+
+/// Builds a field element from literals, checking at compile time that the
+/// representative is below the modulus.
+///
+/// `fe!(n mod p)` builds a primitive [`FieldElement`](crate::FieldElement);
+/// `fe!(n, Field)` builds an element of a field from
+/// [`define_prime_field!`](crate::define_prime_field), where `n` may be a
+/// quoted literal for values wider than `u128`.
+///
+/// ```
+/// use finite_field::{define_prime_field, fe, FieldElement};
+///
+/// define_prime_field!(Fq, "0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+///
+/// assert_eq!(fe!(14 mod 17), FieldElement::new(14, 17));
+/// let gx = fe!("0x79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798", Fq);
+/// assert_eq!(fe!(3, Fq) * gx, gx + gx + gx);
+/// ```
+///
+/// ```compile_fail
+/// let _ = finite_field::fe!(17 mod 17);
+/// ```
+#[macro_export]
+macro_rules! fe {
+    ($n:literal mod $p:literal) => {{
+        const _: () = assert!(($n as u128) < ($p as u128), "representative is not below the modulus");
+        $crate::FieldElement::new($n, $p)
+    }};
+    ($n:tt mod $p:tt) => {
+        $crate::FieldElement::new($n, $p)
+    };
+    ($n:literal, $field:ty) => {{
+        const N: $crate::__private::Uint<{ <$field>::LIMBS }> = $crate::__private::uint_from_literal(stringify!($n));
+        const _: () = assert!(
+            N.cmp_vartime(&<$field as $crate::Modulus<{ <$field>::LIMBS }>>::MODULUS).is_lt(),
+            "representative is not below the modulus"
+        );
+        <$field>::new(N)
+    }};
+}
+
+/// Defines a newtype prime field from a name and a hex modulus literal.
+///
+/// The limb count is derived from the literal, the Montgomery parameters
+/// are computed at compile time (an even modulus fails to compile), and the
+/// type gets the arithmetic operators, `ZERO`/`ONE`, `num` identities,
+/// [`FiniteField`](crate::FiniteField), `Display` and a big-endian byte
+/// encoding.
+///
+/// ```
+/// use finite_field::define_prime_field;
+///
+/// define_prime_field!(pub Fq, "0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+///
+/// let x = Fq::from_u64(3);
+/// assert_eq!(x * x.inverse().unwrap(), Fq::ONE);
+/// assert_eq!(Fq::ZERO + Fq::ONE, Fq::ONE);
+/// ```
+#[macro_export]
+macro_rules! define_prime_field {
+    ($(#[$attr:meta])* $vis:vis $name:ident, $modulus:literal) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+        $vis struct $name($crate::FieldElementTyped<$name, { $crate::__private::limbs_for_hex($modulus) }>);
+
+        impl $crate::Modulus<{ $crate::__private::limbs_for_hex($modulus) }> for $name {
+            const MODULUS: $crate::__private::Uint<{ $crate::__private::limbs_for_hex($modulus) }> =
+                $crate::__private::uint_from_hex($modulus);
+        }
+
+        impl $name {
+            pub const LIMBS: usize = $crate::__private::limbs_for_hex($modulus);
+            pub const ZERO: Self = $name($crate::FieldElementTyped::ZERO);
+            pub const ONE: Self = $name($crate::FieldElementTyped::ONE);
+
+            /// Builds the element, reducing `n` modulo the field order.
+            pub fn new(n: $crate::__private::Uint<{ $crate::__private::limbs_for_hex($modulus) }>) -> Self {
+                $name($crate::FieldElementTyped::new(n))
+            }
+
+            pub fn from_u64(n: u64) -> Self {
+                Self::new($crate::__private::Uint::from_u64(n))
+            }
+
+            pub fn get_num(&self) -> $crate::__private::Uint<{ $crate::__private::limbs_for_hex($modulus) }> {
+                self.0.get_num()
+            }
+
+            pub fn pow(self, exp: $crate::__private::Uint<{ $crate::__private::limbs_for_hex($modulus) }>) -> Self {
+                $name(self.0.pow(exp))
+            }
+
+            pub fn inverse(&self) -> Option<Self> {
+                self.0.inverse().map($name)
+            }
+
+            pub fn square(self) -> Self {
+                $name(self.0.square())
+            }
+
+            pub fn to_be_bytes(self) -> Vec<u8> {
+                self.0.to_dynamic().to_be_bytes()
+            }
+
+            /// Decodes the big-endian encoding, rejecting non-canonical values.
+            pub fn from_be_bytes(bytes: &[u8]) -> Result<Self, $crate::FieldError> {
+                let order = <$name as $crate::Modulus<{ $crate::__private::limbs_for_hex($modulus) }>>::MODULUS;
+                $crate::FieldElementBig::from_be_bytes(bytes, order).map(|e| Self::new(e.get_num()))
+            }
+        }
+
+        $crate::define_prime_field!(@op $name, Add, add);
+        $crate::define_prime_field!(@op $name, Sub, sub);
+        $crate::define_prime_field!(@op $name, Mul, mul);
+        $crate::define_prime_field!(@op $name, Div, div);
+
+        impl ::std::ops::Neg for $name {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $name(-self.0)
+            }
+        }
+
+        impl $crate::__private::Zero for $name {
+            fn zero() -> Self {
+                Self::ZERO
+            }
+
+            fn is_zero(&self) -> bool {
+                *self == Self::ZERO
+            }
+        }
+
+        impl $crate::__private::One for $name {
+            fn one() -> Self {
+                Self::ONE
+            }
+        }
+
+        impl $crate::FiniteField for $name {
+            fn zero(&self) -> Self {
+                Self::ZERO
+            }
+
+            fn one(&self) -> Self {
+                Self::ONE
+            }
+
+            fn inverse(&self) -> Option<Self> {
+                $name::inverse(self)
+            }
+
+            fn from_u64(&self, n: u64) -> Self {
+                $name::from_u64(n)
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                ::std::fmt::Display::fmt(&self.0, f)
+            }
+        }
+    };
+    (@op $name:ident, $op:ident, $method:ident) => {
+        impl ::std::ops::$op for $name {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self {
+                $name(::std::ops::$op::$method(self.0, rhs.0))
+            }
+        }
+    };
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crypto_bigint::{U256, U64};
+    use crate::FieldError;
+
+    crate::define_prime_field!(Secp256k1Fq, "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+    crate::define_prime_field!(Small, "0x7fffffff");
+
+    #[test]
+    fn hex_helpers_work() {
+        assert_eq!(limbs_for_hex("ff"), 1);
+        assert_eq!(limbs_for_hex(&"f".repeat(64)), 256 / Limb::BITS);
+        assert_eq!(uint_from_hex::<1>("0x1F"), U64::from(31u8));
+        assert_eq!(
+            uint_from_hex::<4>("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f"),
+            U256::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f")
+        );
+    }
+
+    #[test]
+    fn fe_works() {
+        assert_eq!(fe!(14 mod 17), crate::FieldElement::new(14, 17));
+        assert_eq!(fe!(0x1f mod 0xfbu8), crate::FieldElement::new(31, 251));
+        let (n, p) = (3u64, 7u64);
+        assert_eq!(fe!(n mod p), crate::FieldElement::new(3, 7));
+        assert_eq!(fe!(1_000_000, Small), Small::from_u64(1_000_000));
+        assert_eq!(fe!(0xdeadbeef, Secp256k1Fq), Secp256k1Fq::from_u64(0xdeadbeef));
+        assert_eq!(uint_from_literal::<4>("\"0xFFFF_ffff\""), U256::from(u32::MAX));
+        let wide = fe!("115792089237316195423570985008687907853269984665640564039457584007908834671662", Secp256k1Fq);
+        assert_eq!(wide, -Secp256k1Fq::ONE);
+    }
+
+    #[test]
+    fn generated_field_works() {
+        let x = Secp256k1Fq::from_u64(0xdeadbeef);
+        let y = -Secp256k1Fq::from_u64(5);
+        assert_eq!((x + y - y) * x / x, x);
+        assert_eq!(y.square(), Secp256k1Fq::from_u64(25));
+        assert_eq!(Secp256k1Fq::from_be_bytes(&x.to_be_bytes()), Ok(x));
+        assert_eq!(Secp256k1Fq::from_be_bytes(&[0xff; 32]), Err(FieldError::OutOfRange));
+        assert_eq!(x.to_be_bytes().len(), 32);
+
+        assert_eq!(Secp256k1Fq::LIMBS * Limb::BITS, 256);
+        assert_eq!(x.get_num(), U256::from(0xdeadbeefu32));
+        assert_eq!(x.pow(U256::from(3u8)), x * x * x);
+
+        assert_eq!(Small::LIMBS, 1);
+        let a = Small::from_u64(0x8000_0000);
+        assert_eq!(a, Small::ONE);
+        assert_eq!(a.to_string(), "1 (mod 2147483647)");
+        assert_eq!(a.get_num(), Uint::ONE);
+        assert_eq!(Small::from_u64(3).pow(Uint::from_u64(0x7fff_fffe)), a);
+        assert_eq!(a.square(), a);
+        assert_eq!(Small::from_be_bytes(&a.to_be_bytes()), Ok(a));
+    }
+}
@@ -0,0 +1,338 @@
+use std::ops::Mul;
+use crate::FiniteField;
+
+/// A dense matrix over any [`FiniteField`], stored row-major.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix<F> {
+    rows: usize,
+    cols: usize,
+    data: Vec<F>,
+}
+
+impl<F: FiniteField> Matrix<F> {
+    /// Builds a matrix from its rows, which must all have the same length.
+    pub fn new(rows: Vec<Vec<F>>) -> Self {
+        let cols = rows.first().map_or(0, |r| r.len());
+        assert!(rows.iter().all(|r| r.len() == cols), "Rows must have the same length.");
+        Matrix {
+            rows: rows.len(),
+            cols,
+            data: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// The zero matrix over the field of `field`.
+    pub fn zero(rows: usize, cols: usize, field: F) -> Self {
+        Matrix {
+            rows,
+            cols,
+            data: vec![field.zero(); rows * cols],
+        }
+    }
+
+    /// The identity matrix over the field of `field`.
+    pub fn identity(n: usize, field: F) -> Self {
+        let mut m = Self::zero(n, n, field);
+        for i in 0..n {
+            m.data[i * n + i] = field.one();
+        }
+        m
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> F {
+        self.data[row * self.cols + col]
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: F) {
+        self.data[row * self.cols + col] = value;
+    }
+
+    pub fn row(&self, row: usize) -> &[F] {
+        &self.data[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut data = Vec::with_capacity(self.data.len());
+        for c in 0..self.cols {
+            for r in 0..self.rows {
+                data.push(self.get(r, c));
+            }
+        }
+        Matrix {
+            rows: self.cols,
+            cols: self.rows,
+            data,
+        }
+    }
+
+    /// Returns `A·v`. Panics if the matrix has rows but no columns, as
+    /// there is then no element to take the field's zero from.
+    pub fn mul_vec(&self, v: &[F]) -> Vec<F> {
+        assert_eq!(v.len(), self.cols);
+        if self.rows == 0 {
+            return Vec::new();
+        }
+        let zero = v.first().expect("A matrix without columns has no field to sum in.").zero();
+        (0..self.rows)
+            .map(|r| {
+                self.row(r).iter().zip(v)
+                    .fold(zero, |acc, (&a, &b)| acc + a * b)
+            })
+            .collect()
+    }
+
+    /// Reduces the matrix to reduced row echelon form by Gaussian
+    /// elimination, returning the pivot columns.
+    pub fn rref(&mut self) -> Vec<usize> {
+        let mut pivots = Vec::new();
+        let mut row = 0;
+        for col in 0..self.cols {
+            if row == self.rows {
+                break;
+            }
+            let pivot = match (row..self.rows).find(|&r| self.get(r, col) != self.get(r, col).zero()) {
+                Some(p) => p,
+                None => continue,
+            };
+            for c in 0..self.cols {
+                self.data.swap(pivot * self.cols + c, row * self.cols + c);
+            }
+            let inv = self.get(row, col).one() / self.get(row, col);
+            for c in 0..self.cols {
+                let v = self.get(row, c) * inv;
+                self.set(row, c, v);
+            }
+            for r in 0..self.rows {
+                let factor = self.get(r, col);
+                if r != row && factor != factor.zero() {
+                    for c in 0..self.cols {
+                        let v = self.get(r, c) - factor * self.get(row, c);
+                        self.set(r, c, v);
+                    }
+                }
+            }
+            pivots.push(col);
+            row += 1;
+        }
+        pivots
+    }
+
+    /// Returns the determinant of a square matrix by Gaussian elimination.
+    pub fn determinant(&self) -> F {
+        assert_eq!(self.rows, self.cols, "Matrix must be square.");
+        let mut m = self.clone();
+        let mut det = self.data[0].one();
+        for col in 0..self.cols {
+            let pivot = match (col..self.rows).find(|&r| m.get(r, col) != det.zero()) {
+                Some(p) => p,
+                None => return det.zero(),
+            };
+            if pivot != col {
+                for c in 0..self.cols {
+                    m.data.swap(pivot * self.cols + c, col * self.cols + c);
+                }
+                det = det.zero() - det;
+            }
+            let lead = m.get(col, col);
+            det = det * lead;
+            let inv = lead.one() / lead;
+            for r in col + 1..self.rows {
+                let factor = m.get(r, col) * inv;
+                for c in col..self.cols {
+                    let v = m.get(r, c) - factor * m.get(col, c);
+                    m.set(r, c, v);
+                }
+            }
+        }
+        det
+    }
+
+    pub fn rank(&self) -> usize {
+        self.clone().rref().len()
+    }
+
+    /// Returns a basis of the null space `{x : A·x = 0}`.
+    pub fn kernel(&self) -> Vec<Vec<F>> {
+        let mut reduced = self.clone();
+        let pivots = reduced.rref();
+        let zero = match self.data.first() {
+            Some(e) => e.zero(),
+            None => return Vec::new(),
+        };
+
+        (0..self.cols)
+            .filter(|c| !pivots.contains(c))
+            .map(|free| {
+                let mut v = vec![zero; self.cols];
+                v[free] = zero.one();
+                for (r, &p) in pivots.iter().enumerate() {
+                    v[p] = zero - reduced.get(r, free);
+                }
+                v
+            })
+            .collect()
+    }
+
+    /// Solves `A·x = b`, returning one solution or `None` if the system is
+    /// inconsistent.
+    pub fn solve(&self, b: &[F]) -> Option<Vec<F>> {
+        assert_eq!(b.len(), self.rows);
+        let rows = (0..self.rows)
+            .map(|r| {
+                let mut row = self.row(r).to_vec();
+                row.push(b[r]);
+                row
+            })
+            .collect();
+        let mut augmented = Matrix::new(rows);
+        let pivots = augmented.rref();
+        if pivots.last() == Some(&self.cols) {
+            return None;
+        }
+        let zero = b.first()?.zero();
+        let mut x = vec![zero; self.cols];
+        for (r, &p) in pivots.iter().enumerate() {
+            x[p] = augmented.get(r, self.cols);
+        }
+        Some(x)
+    }
+}
+
+/// Panics if the product is nonempty but both operands are, as when
+/// multiplying `r × 0` by `0 × c`, since there is then no element to take
+/// the field's zero from.
+impl<F: FiniteField> Mul for &Matrix<F> {
+    type Output = Matrix<F>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.cols, rhs.rows);
+        if self.rows == 0 || rhs.cols == 0 {
+            return Matrix { rows: self.rows, cols: rhs.cols, data: Vec::new() };
+        }
+        let field = self.data.first().expect("Multiplying empty matrices has no field to sum in.");
+        let mut out = Matrix::zero(self.rows, rhs.cols, *field);
+        for r in 0..self.rows {
+            for k in 0..self.cols {
+                let a = self.get(r, k);
+                for c in 0..rhs.cols {
+                    let v = out.get(r, c) + a * rhs.get(k, c);
+                    out.set(r, c, v);
+                }
+            }
+        }
+        out
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FieldElement;
+
+    fn matrix(rows: &[&[u32]]) -> Matrix<FieldElement<u32>> {
+        Matrix::new(rows.iter().map(|r| r.iter().map(|&n| FieldElement::new(n, 7)).collect()).collect())
+    }
+
+    fn vector(nums: &[u32]) -> Vec<FieldElement<u32>> {
+        nums.iter().map(|&n| FieldElement::new(n, 7)).collect()
+    }
+
+    #[test]
+    fn mul_works() {
+        let a = matrix(&[&[1, 2], &[3, 4]]);
+        let b = matrix(&[&[0, 1], &[1, 0]]);
+        assert_eq!(&a * &b, matrix(&[&[2, 1], &[4, 3]]));
+        assert_eq!(&a * &Matrix::identity(2, a.get(0, 0)), a);
+        assert_eq!(a.mul_vec(&vector(&[1, 1])), vector(&[3, 0]));
+        assert_eq!(a.transpose(), matrix(&[&[1, 3], &[2, 4]]));
+    }
+
+    #[test]
+    fn mul_vec_handles_empty_matrices() {
+        let empty = Matrix::<FieldElement<u32>>::new(Vec::new());
+        assert_eq!(empty.mul_vec(&[]), Vec::new());
+        let wide = Matrix::zero(0, 3, FieldElement::new(0u32, 7));
+        assert_eq!(wide.mul_vec(&vector(&[1, 2, 3])), Vec::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "no field")]
+    fn mul_vec_without_columns_panics() {
+        Matrix::zero(2, 0, FieldElement::new(0u32, 7)).mul_vec(&[]);
+    }
+
+    #[test]
+    fn mul_handles_empty_matrices() {
+        let f = FieldElement::new(0u32, 7);
+        let a = matrix(&[&[1, 2], &[3, 4]]);
+        assert_eq!(&Matrix::zero(0, 2, f) * &a, Matrix::zero(0, 2, f));
+        assert_eq!(&a * &Matrix::zero(2, 0, f), Matrix::zero(2, 0, f));
+        assert_eq!(&Matrix::zero(0, 3, f) * &Matrix::zero(3, 0, f), Matrix::zero(0, 0, f));
+        assert_eq!(&Matrix::zero(2, 0, f) * &Matrix::zero(0, 0, f), Matrix::zero(2, 0, f));
+        // H·Gᵀ for a code with no codewords
+        let h = matrix(&[&[1, 0], &[0, 1]]);
+        assert_eq!(&h * &Matrix::zero(0, 2, f).transpose(), Matrix::zero(2, 0, f));
+    }
+
+    #[test]
+    #[should_panic(expected = "no field")]
+    fn mul_without_inner_dimension_panics() {
+        let f = FieldElement::new(0u32, 7);
+        let _ = &Matrix::zero(2, 0, f) * &Matrix::zero(0, 2, f);
+    }
+
+    #[test]
+    fn rref_works() {
+        let mut a = matrix(&[&[2, 4, 1], &[1, 2, 0]]);
+        assert_eq!(a.rref(), vec![0, 2]);
+        assert_eq!(a, matrix(&[&[1, 2, 0], &[0, 0, 1]]));
+        assert_eq!(matrix(&[&[1, 2], &[2, 4]]).rank(), 1);
+    }
+
+    #[test]
+    fn determinant_works() {
+        assert_eq!(matrix(&[&[1, 2], &[3, 4]]).determinant(), FieldElement::new(5, 7));
+        assert_eq!(matrix(&[&[0, 1, 0], &[1, 0, 0], &[0, 0, 3]]).determinant(), FieldElement::new(4, 7));
+        assert_eq!(matrix(&[&[1, 2], &[2, 4]]).determinant(), FieldElement::new(0, 7));
+    }
+
+    #[test]
+    fn kernel_works() {
+        let a = matrix(&[&[1, 2, 3], &[2, 4, 6]]);
+        let kernel = a.kernel();
+        assert_eq!(kernel.len(), 2);
+        for v in &kernel {
+            assert_eq!(a.mul_vec(v), vector(&[0, 0]));
+        }
+    }
+
+    #[test]
+    fn solve_works() {
+        let a = matrix(&[&[1, 1], &[1, 6]]);
+        let x = a.solve(&vector(&[3, 1])).unwrap();
+        assert_eq!(a.mul_vec(&x), vector(&[3, 1]));
+        assert_eq!(matrix(&[&[1, 1], &[1, 1]]).solve(&vector(&[1, 2])), None);
+    }
+
+    #[test]
+    fn bigint_solve_works() {
+        use crate::FieldElementBig;
+        use crypto_bigint::U256;
+
+        let c = |n: u8| FieldElementBig::new(U256::from(n), U256::from(7u8));
+        let a = Matrix::new(vec![vec![c(1), c(1)], vec![c(1), c(6)]]);
+        let x = a.solve(&[c(3), c(1)]).unwrap();
+        assert_eq!(a.mul_vec(&x), vec![c(3), c(1)]);
+        assert_eq!(a.rank(), 2);
+        assert!(a.kernel().is_empty());
+    }
+}
@@ -0,0 +1,258 @@
+use std::{fmt, ops::{Add, Div, Mul, Neg, Sub}};
+use crate::{FieldElement, FieldError, FiniteField};
+
+/// Montgomery parameters for one odd machine-word modulus `p`: `p' = −p⁻¹`
+/// and `R² mod p` for `R = 2^BITS`, computed once.
+///
+/// Elements minted from the context stay in Montgomery form, so a chain of
+/// multiplications costs no divisions at all, only the one in `new`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MontgomeryContext<T> {
+    order: T,
+    neg_inv: T,
+    r2: T,
+    one: T,
+}
+
+/// An element minted by a [`MontgomeryContext`], stored as `n·R mod p`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MontgomeryElement<T> {
+    montgomery: T,
+    context: MontgomeryContext<T>,
+}
+
+macro_rules! impl_montgomery {
+    ($($t:ty => $wide:ty),*) => {
+        $(
+            impl MontgomeryContext<$t> {
+                /// Finds `p'` by Newton iteration modulo `R`, which only
+                /// converges when `p` is invertible modulo `R`, so even
+                /// moduli are rejected.
+                pub fn new(order: $t) -> Result<Self, FieldError> {
+                    if order == 0 {
+                        return Err(FieldError::ZeroModulus);
+                    }
+                    if order & 1 == 0 {
+                        return Err(FieldError::EvenModulus);
+                    }
+                    // p·p ≡ 1 mod 8, and each Newton step doubles the
+                    // correct bits
+                    let mut inv = order;
+                    for _ in 0..5 {
+                        inv = inv.wrapping_mul((2 as $t).wrapping_sub(order.wrapping_mul(inv)));
+                    }
+                    let r = ((1 as $wide) << <$t>::BITS) % order as $wide;
+                    Ok(MontgomeryContext {
+                        order,
+                        neg_inv: inv.wrapping_neg(),
+                        r2: (r * r % order as $wide) as $t,
+                        one: r as $t,
+                    })
+                }
+
+                pub fn order(&self) -> $t {
+                    self.order
+                }
+
+                /// Returns `t·R⁻¹ mod p` for `t < p·R`.
+                fn redc(&self, t: $wide) -> $t {
+                    let m = (t as $t).wrapping_mul(self.neg_inv);
+                    let (sum, carry) = t.overflowing_add(m as $wide * self.order as $wide);
+                    let u = (sum >> <$t>::BITS) | ((carry as $wide) << <$t>::BITS);
+                    if u >= self.order as $wide {
+                        (u - self.order as $wide) as $t
+                    } else {
+                        u as $t
+                    }
+                }
+
+                /// Enters Montgomery form as the REDC of `n·R²`, which also
+                /// reduces `n`.
+                pub fn element(&self, n: $t) -> MontgomeryElement<$t> {
+                    MontgomeryElement { montgomery: self.redc(n as $wide * self.r2 as $wide), context: *self }
+                }
+
+                pub fn zero(&self) -> MontgomeryElement<$t> {
+                    MontgomeryElement { montgomery: 0, context: *self }
+                }
+
+                pub fn one(&self) -> MontgomeryElement<$t> {
+                    MontgomeryElement { montgomery: self.one, context: *self }
+                }
+
+                /// Converts an existing element to Montgomery form, failing if
+                /// its order differs.
+                pub fn import(&self, e: &FieldElement<$t>) -> Result<MontgomeryElement<$t>, FieldError> {
+                    if e.get_order() != self.order {
+                        return Err(FieldError::MismatchedOrders);
+                    }
+                    Ok(self.element(e.get_num()))
+                }
+            }
+
+            impl MontgomeryElement<$t> {
+                /// Returns the canonical representative, leaving Montgomery
+                /// form.
+                pub fn get_num(&self) -> $t {
+                    self.context.redc(self.montgomery as $wide)
+                }
+
+                pub fn get_order(&self) -> $t {
+                    self.context.order
+                }
+
+                pub fn context(&self) -> MontgomeryContext<$t> {
+                    self.context
+                }
+
+                /// Returns the Montgomery form `n·R mod p`, which is the
+                /// stored state.
+                pub fn to_montgomery(&self) -> $t {
+                    self.montgomery
+                }
+
+                pub fn pow(self, mut exp: $t) -> Self {
+                    let mut result = self.context.one();
+                    let mut base = self;
+                    while exp > 0 {
+                        if exp & 1 == 1 {
+                            result = result * base;
+                        }
+                        base = base.square();
+                        exp >>= 1;
+                    }
+                    result
+                }
+
+                pub fn square(self) -> Self {
+                    self * self
+                }
+
+                /// Returns the inverse by Fermat's little theorem, or `None`
+                /// for zero.
+                pub fn inverse(&self) -> Option<Self> {
+                    if self.montgomery == 0 {
+                        None
+                    } else {
+                        Some(self.pow(self.get_order() - 2))
+                    }
+                }
+
+                pub fn to_field_element(&self) -> FieldElement<$t> {
+                    FieldElement::new(self.get_num(), self.get_order())
+                }
+            }
+
+            impl Add for MontgomeryElement<$t> {
+                type Output = Self;
+
+                fn add(self, rhs: Self) -> Self {
+                    assert_eq!(self.context, rhs.context);
+                    let (a, b, p) = (self.montgomery, rhs.montgomery, self.get_order());
+                    let montgomery = if p - a > b { a + b } else { b - (p - a) };
+                    MontgomeryElement { montgomery, context: self.context }
+                }
+            }
+
+            impl Sub for MontgomeryElement<$t> {
+                type Output = Self;
+
+                fn sub(self, rhs: Self) -> Self {
+                    assert_eq!(self.context, rhs.context);
+                    let (a, b) = (self.montgomery, rhs.montgomery);
+                    let montgomery = if a >= b { a - b } else { self.get_order() - (b - a) };
+                    MontgomeryElement { montgomery, context: self.context }
+                }
+            }
+
+            impl Mul for MontgomeryElement<$t> {
+                type Output = Self;
+
+                fn mul(self, rhs: Self) -> Self {
+                    assert_eq!(self.context, rhs.context);
+                    let montgomery = self.context.redc(self.montgomery as $wide * rhs.montgomery as $wide);
+                    MontgomeryElement { montgomery, context: self.context }
+                }
+            }
+
+            impl Div for MontgomeryElement<$t> {
+                type Output = Self;
+
+                fn div(self, rhs: Self) -> Self {
+                    self.mul(rhs.inverse().expect("Zero is not valid denominator."))
+                }
+            }
+
+            impl Neg for MontgomeryElement<$t> {
+                type Output = Self;
+
+                fn neg(self) -> Self {
+                    self.context.zero() - self
+                }
+            }
+
+            impl FiniteField for MontgomeryElement<$t> {
+                fn zero(&self) -> Self {
+                    self.context.zero()
+                }
+
+                fn one(&self) -> Self {
+                    self.context.one()
+                }
+
+                fn inverse(&self) -> Option<Self> {
+                    MontgomeryElement::<$t>::inverse(self)
+                }
+
+                fn from_u64(&self, n: u64) -> Self {
+                    self.context.element((n % self.get_order() as u64) as $t)
+                }
+            }
+
+            impl fmt::Display for MontgomeryElement<$t> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "{} (mod {})", self.get_num(), self.get_order())
+                }
+            }
+        )*
+    };
+}
+
+impl_montgomery!(u32 => u64, u64 => u128);
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::check_arithmetic;
+
+    #[test]
+    fn arithmetic_matches_field_element() {
+        // Goldilocks, where the REDC sum overflows u128
+        let p = 0xffffffff00000001u64;
+        let ctx = MontgomeryContext::<u64>::new(p).unwrap();
+        for (a, b) in [(p - 1, p - 1), (u64::MAX, 1 << 63), (2, 0xffffffff)] {
+            check_arithmetic(ctx.element(a), ctx.element(b), |e| e.to_field_element());
+        }
+        let x = ctx.element(p - 2);
+        assert_eq!(x.pow(p - 1), ctx.one());
+        assert_eq!(ctx.element(u64::MAX).get_num(), u64::MAX % p);
+        assert_eq!(ctx.import(&x.to_field_element()), Ok(x));
+        assert_eq!(x.to_string(), format!("{} (mod {})", p - 2, p));
+    }
+
+    #[test]
+    fn u32_fields_work() {
+        // BabyBear
+        let p = 0x78000001u32;
+        let ctx = MontgomeryContext::<u32>::new(p).unwrap();
+        let x = ctx.element(123456789);
+        assert_eq!(x.to_montgomery() as u64, (123456789u64 << 32) % p as u64);
+        let product = (1..100).map(|n| ctx.element(n)).fold(ctx.one(), |a, b| a * b);
+        let expected = (1..100).map(|n| FieldElement::new(n, p)).fold(FieldElement::new(1, p), |a, b| a * b);
+        assert_eq!(product.to_field_element(), expected);
+        check_arithmetic(x, ctx.element(p - 1), |e| e.to_field_element());
+        assert_eq!(MontgomeryContext::<u32>::new(0), Err(FieldError::ZeroModulus));
+        assert_eq!(MontgomeryContext::<u32>::new(10), Err(FieldError::EvenModulus));
+    }
+}
@@ -0,0 +1,177 @@
+//! Number-theoretic transforms over prime fields with a power-of-two
+//! subgroup, and the low-degree extension built on top of them. The
+//! [`additive`] module covers binary fields, which have no such subgroups.
+
+use std::{fmt::Debug};
+use num::NumCast;
+use primes::factors_uniq;
+use crate::{FieldElement, Widening};
+
+pub mod additive;
+pub mod streaming;
+
+fn cast<T: NumCast>(x: u64) -> T {
+    NumCast::from(x).expect("value does not fit in the field type")
+}
+
+/// Returns a generator of the multiplicative group of the prime field.
+pub fn generator<T>(order: T) -> FieldElement<T>
+    where T: Widening + Debug,
+{
+    let p = order.to_u64().expect("order does not fit in u64");
+    if p == 2 {
+        return FieldElement::new(T::one(), order);
+    }
+    let factors = factors_uniq(p - 1);
+    let one = FieldElement::new(T::one(), order);
+    for c in 2..p {
+        let g = FieldElement::new(cast(c), order);
+        if factors.iter().all(|q| g.pow(cast((p - 1) / q)) != one) {
+            return g;
+        }
+    }
+    panic!("{} is not a prime order.", p)
+}
+
+/// Returns a primitive `n`-th root of unity, or `None` if `n` does not
+/// divide `order - 1`.
+pub fn root_of_unity<T>(n: usize, order: T) -> Option<FieldElement<T>>
+    where T: Widening + Debug,
+{
+    let p = order.to_u64()?;
+    let n = n as u64;
+    if n == 0 || (p - 1) % n != 0 {
+        return None;
+    }
+    Some(generator(order).pow(cast((p - 1) / n)))
+}
+
+fn transform<T>(values: &mut [FieldElement<T>], omega: FieldElement<T>)
+    where T: Widening + Debug,
+{
+    let n = values.len();
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let w_len = omega.pow(cast((n / len) as u64));
+        for start in (0..n).step_by(len) {
+            let mut w = omega.one();
+            for k in 0..len / 2 {
+                let u = values[start + k];
+                let v = values[start + k + len / 2] * w;
+                values[start + k] = u + v;
+                values[start + k + len / 2] = u - v;
+                w = w * w_len;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+/// Evaluates the polynomial with the given coefficients over the subgroup
+/// of size `coeffs.len()`, which must be a power of two.
+pub fn ntt<T>(coeffs: &[FieldElement<T>]) -> Vec<FieldElement<T>>
+    where T: Widening + Debug,
+{
+    let mut values = coeffs.to_vec();
+    if values.len() <= 1 {
+        return values;
+    }
+    assert!(values.len().is_power_of_two(), "Transform size must be a power of two.");
+    let order = values[0].get_order();
+    let omega = root_of_unity(values.len(), order)
+        .expect("The field has no subgroup of this size.");
+    transform(&mut values, omega);
+    values
+}
+
+/// Interpolates evaluations over the subgroup of size `evals.len()` back
+/// into coefficients.
+pub fn intt<T>(evals: &[FieldElement<T>]) -> Vec<FieldElement<T>>
+    where T: Widening + Debug,
+{
+    let mut values = evals.to_vec();
+    if values.len() <= 1 {
+        return values;
+    }
+    assert!(values.len().is_power_of_two(), "Transform size must be a power of two.");
+    let order = values[0].get_order();
+    let omega = root_of_unity(values.len(), order)
+        .expect("The field has no subgroup of this size.");
+    let one = omega.one();
+    transform(&mut values, one / omega);
+    let n = FieldElement::new(cast(values.len() as u64), order);
+    let n_inv = one / n;
+    values.iter().map(|&v| v * n_inv).collect()
+}
+
+/// Low-degree extension: interpolates `evals` over the subgroup H of size
+/// `evals.len()` and re-evaluates the polynomial over the coset
+/// `coset_shift * H'`, where H' is the subgroup of size `evals.len() * blowup`.
+pub fn lde<T>(evals: &[FieldElement<T>], blowup: usize, coset_shift: FieldElement<T>) -> Vec<FieldElement<T>>
+    where T: Widening + Debug,
+{
+    assert!(blowup.is_power_of_two(), "Blowup factor must be a power of two.");
+    let mut coeffs = intt(evals);
+    let mut shift = coset_shift.one();
+    for c in coeffs.iter_mut() {
+        *c = *c * shift;
+        shift = shift * coset_shift;
+    }
+    coeffs.resize(evals.len() * blowup, coset_shift.zero());
+    ntt(&coeffs)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn elements(nums: &[u64], order: u64) -> Vec<FieldElement<u64>> {
+        nums.iter().map(|&n| FieldElement::new(n, order)).collect()
+    }
+
+    fn evaluate(coeffs: &[FieldElement<u64>], x: FieldElement<u64>) -> FieldElement<u64> {
+        coeffs.iter().rev().fold(x.zero(), |acc, &c| acc * x + c)
+    }
+
+    #[test]
+    fn root_of_unity_works() {
+        let w = root_of_unity(8, 97u64).unwrap();
+        assert_eq!(w.pow(8), w.one());
+        assert_ne!(w.pow(4), w.one());
+        assert!(root_of_unity(64, 97u64).is_none());
+    }
+
+    #[test]
+    fn ntt_round_trip_works() {
+        let coeffs = elements(&[3, 1, 4, 1, 5, 9, 2, 6], 97);
+        let evals = ntt(&coeffs);
+        let w = root_of_unity(8, 97u64).unwrap();
+        for (i, e) in evals.iter().enumerate() {
+            assert_eq!(*e, evaluate(&coeffs, w.pow(i as u64)));
+        }
+        assert_eq!(intt(&evals), coeffs);
+    }
+
+    #[test]
+    fn lde_works() {
+        let coeffs = elements(&[7, 0, 12, 5], 97);
+        let evals = ntt(&coeffs);
+        let shift = generator(97u64);
+        let extended = lde(&evals, 4, shift);
+        assert_eq!(extended.len(), 16);
+
+        let w = root_of_unity(16, 97u64).unwrap();
+        for (i, e) in extended.iter().enumerate() {
+            assert_eq!(*e, evaluate(&coeffs, shift * w.pow(i as u64)));
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! The Gao–Mateer additive FFT over `GF(2^M)`.
+//!
+//! Binary fields have no multiplicative subgroups of power-of-two size, so
+//! polynomials are evaluated over an additive subgroup instead: the span
+//! of `k` linearly independent basis elements, which has `2^k` points.
+//! Both directions take `O(n log² n)` field operations.
+
+use crate::BinaryFieldElement;
+
+type E<const M: usize> = BinaryFieldElement<M>;
+
+/// Returns the points of the span of `basis` in transform order: point `i`
+/// is the sum of the `basis[j]` for which bit `j` of `i` is set. `zero`
+/// fixes the field when the basis is empty.
+pub fn span<const M: usize>(basis: &[E<M>], zero: E<M>) -> Vec<E<M>> {
+    let mut points = vec![zero];
+    for &b in basis {
+        let shifted: Vec<_> = points.iter().map(|&p| p + b).collect();
+        points.extend(shifted);
+    }
+    points
+}
+
+/// Rewrites `f` in place as its expansion `Σ (f[2i] + f[2i + 1]·x)(x² + x)^i`.
+fn taylor<const M: usize>(f: &mut [E<M>]) {
+    let n = f.len();
+    if n <= 2 {
+        return;
+    }
+    // f = a + x^m·b + x^2m·c + x^3m·d = (a + x^m·(b + c + d)) + (x^2m + x^m)·(c + d + x^m·d)
+    let m = n / 4;
+    for i in 0..m {
+        f[2 * m + i] = f[2 * m + i] + f[3 * m + i];
+        f[m + i] = f[m + i] + f[2 * m + i];
+    }
+    let (lo, hi) = f.split_at_mut(2 * m);
+    taylor(lo);
+    taylor(hi);
+}
+
+fn untaylor<const M: usize>(f: &mut [E<M>]) {
+    let n = f.len();
+    if n <= 2 {
+        return;
+    }
+    let m = n / 4;
+    let (lo, hi) = f.split_at_mut(2 * m);
+    untaylor(lo);
+    untaylor(hi);
+    for i in 0..m {
+        f[m + i] = f[m + i] + f[2 * m + i];
+        f[2 * m + i] = f[2 * m + i] + f[3 * m + i];
+    }
+}
+
+/// Scales the basis so its last element is one, returning the scale and
+/// the bases `γ` over which the halves are evaluated and `δ = γ² + γ` over
+/// which the recursion runs.
+fn split_basis<const M: usize>(basis: &[E<M>]) -> (E<M>, Vec<E<M>>, Vec<E<M>>) {
+    let (&last, rest) = basis.split_last().unwrap();
+    let inv = last.inverse().expect("The basis must be linearly independent.");
+    let gamma: Vec<_> = rest.iter().map(|&b| b * inv).collect();
+    let delta = gamma.iter().map(|&g| g * g + g).collect();
+    (last, gamma, delta)
+}
+
+/// Evaluates the polynomial with the given coefficients over the span of
+/// `basis`, in the order of [`span`]. There must be `2^basis.len()`
+/// coefficients, and the basis must be linearly independent.
+///
+/// With `g(x) = f(β·x)` for the last basis element `β`, and
+/// `g = g₀(x² + x) + x·g₁(x² + x)`, both halves are evaluated recursively
+/// over the image of the span under `x ↦ x² + x`; since that map is
+/// two-to-one with kernel `{0, 1}`, `g(u)` and `g(u + 1)` share them.
+pub fn fft<const M: usize>(coeffs: &[E<M>], basis: &[E<M>]) -> Vec<E<M>> {
+    assert_eq!(coeffs.len(), 1 << basis.len(), "Need one coefficient per point of the span.");
+    if basis.is_empty() {
+        return coeffs.to_vec();
+    }
+    let (beta, gamma, delta) = split_basis(basis);
+    let mut scale = beta.one();
+    let mut g: Vec<_> = coeffs.iter().map(|&c| {
+        let s = c * scale;
+        scale = scale * beta;
+        s
+    }).collect();
+    taylor(&mut g);
+    let g0: Vec<_> = g.iter().step_by(2).copied().collect();
+    let g1: Vec<_> = g.iter().skip(1).step_by(2).copied().collect();
+    let (e0, e1) = (fft(&g0, &delta), fft(&g1, &delta));
+
+    let half = g0.len();
+    let points = span(&gamma, beta.zero());
+    let mut evals = vec![beta.zero(); 2 * half];
+    for j in 0..half {
+        evals[j] = e0[j] + points[j] * e1[j];
+        evals[j + half] = evals[j] + e1[j];
+    }
+    evals
+}
+
+/// Interpolates evaluations over the span of `basis`, in the order of
+/// [`span`], back into coefficients; the inverse of [`fft`].
+pub fn ifft<const M: usize>(evals: &[E<M>], basis: &[E<M>]) -> Vec<E<M>> {
+    assert_eq!(evals.len(), 1 << basis.len(), "Need one evaluation per point of the span.");
+    if basis.is_empty() {
+        return evals.to_vec();
+    }
+    let (beta, gamma, delta) = split_basis(basis);
+    let half = evals.len() / 2;
+    let points = span(&gamma, beta.zero());
+    let e1: Vec<_> = (0..half).map(|j| evals[j + half] - evals[j]).collect();
+    let e0: Vec<_> = (0..half).map(|j| evals[j] - points[j] * e1[j]).collect();
+    let (g0, g1) = (ifft(&e0, &delta), ifft(&e1, &delta));
+
+    let mut g: Vec<_> = g0.into_iter().zip(g1).flat_map(|(a, b)| [a, b]).collect();
+    untaylor(&mut g);
+    let inv = beta.inverse().unwrap();
+    let mut scale = beta.one();
+    for c in g.iter_mut() {
+        *c = *c * scale;
+        scale = scale * inv;
+    }
+    g
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Polynomial;
+
+    fn elements(nums: &[u64]) -> Vec<E<8>> {
+        nums.iter().map(|&n| BinaryFieldElement::new(n, 0x11b)).collect()
+    }
+
+    #[test]
+    fn fft_matches_naive_evaluation() {
+        let coeffs = elements(&[3, 1, 4, 1, 5, 9, 2, 6, 0x53, 0xca, 0xff, 0, 7, 0x80, 0x11, 0x2a]);
+        let poly = Polynomial::new(coeffs.clone());
+        for basis in [elements(&[1, 2, 4, 8]), elements(&[0x53, 0xca, 0x1f, 0x80])] {
+            let points = span(&basis, coeffs[0].zero());
+            let evals = fft(&coeffs, &basis);
+            assert_eq!(evals, poly.evaluate_many(&points));
+            assert_eq!(ifft(&evals, &basis), coeffs);
+        }
+
+        let coeffs = elements(&(0..256).collect::<Vec<_>>());
+        let basis = elements(&[1, 2, 4, 8, 16, 32, 64, 128]);
+        let points = span(&basis, coeffs[0].zero());
+        let evals = fft(&coeffs, &basis);
+        assert_eq!(evals, Polynomial::new(coeffs.clone()).evaluate_many(&points));
+        assert_eq!(ifft(&evals, &basis), coeffs);
+    }
+
+    #[test]
+    fn trivial_sizes_work() {
+        let c = elements(&[0x42]);
+        assert_eq!(fft(&c, &[]), c);
+        assert_eq!(ifft(&c, &[]), c);
+        let c = elements(&[5, 9]);
+        let basis = elements(&[0x53]);
+        assert_eq!(fft(&c, &basis), vec![c[0], c[0] + c[1] * basis[0]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "linearly independent")]
+    fn dependent_basis_panics() {
+        fft(&elements(&[1, 2, 3, 4]), &elements(&[3, 3]));
+    }
+}
@@ -0,0 +1,235 @@
+//! Four-step NTT for transforms that do not fit in memory.
+//!
+//! The data is viewed as a `rows × cols` matrix stored row-major in a
+//! [`ChunkStore`]. Only one row, one column or one square tile is held in
+//! memory at a time; strided column access is replaced by tiled transposes.
+
+use std::{fmt::Debug, fs::File, io::{self, Read, Seek, SeekFrom, Write}, marker::PhantomData};
+use num::NumCast;
+use crate::{FieldElement, Widening};
+use super::{cast, root_of_unity, transform};
+
+/// Random-access storage for field elements, e.g. an in-memory slice or a
+/// file on disk.
+pub trait ChunkStore<T> {
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn read(&mut self, offset: usize, buf: &mut [FieldElement<T>]) -> io::Result<()>;
+
+    fn write(&mut self, offset: usize, buf: &[FieldElement<T>]) -> io::Result<()>;
+}
+
+impl<T: Copy> ChunkStore<T> for [FieldElement<T>] {
+    fn len(&self) -> usize {
+        <[FieldElement<T>]>::len(self)
+    }
+
+    fn read(&mut self, offset: usize, buf: &mut [FieldElement<T>]) -> io::Result<()> {
+        buf.copy_from_slice(&self[offset..offset + buf.len()]);
+        Ok(())
+    }
+
+    fn write(&mut self, offset: usize, buf: &[FieldElement<T>]) -> io::Result<()> {
+        self[offset..offset + buf.len()].copy_from_slice(buf);
+        Ok(())
+    }
+}
+
+/// A file of fixed-width little-endian representatives.
+#[derive(Debug)]
+pub struct FileStore<T> {
+    file: File,
+    len: usize,
+    order: T,
+    width: PhantomData<T>,
+}
+
+impl<T: Widening> FileStore<T> {
+    /// Wraps a file holding `len` elements of the field of the given order.
+    pub fn new(file: File, len: usize, order: T) -> io::Result<Self> {
+        file.set_len((len * std::mem::size_of::<T>()) as u64)?;
+        Ok(FileStore {
+            file,
+            len,
+            order,
+            width: PhantomData,
+        })
+    }
+
+    pub fn into_inner(self) -> File {
+        self.file
+    }
+}
+
+impl<T: Widening> ChunkStore<T> for FileStore<T> {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn read(&mut self, offset: usize, buf: &mut [FieldElement<T>]) -> io::Result<()> {
+        let width = std::mem::size_of::<T>();
+        let mut bytes = vec![0u8; buf.len() * width];
+        self.file.seek(SeekFrom::Start((offset * width) as u64))?;
+        self.file.read_exact(&mut bytes)?;
+        for (e, chunk) in buf.iter_mut().zip(bytes.chunks(width)) {
+            let mut word = [0u8; 16];
+            word[..width].copy_from_slice(chunk);
+            let n = NumCast::from(u128::from_le_bytes(word))
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "value out of range"))?;
+            if n >= self.order {
+                return Err(io::Error::new(io::ErrorKind::InvalidData, "value is not reduced"));
+            }
+            *e = FieldElement::new(n, self.order);
+        }
+        Ok(())
+    }
+
+    fn write(&mut self, offset: usize, buf: &[FieldElement<T>]) -> io::Result<()> {
+        let width = std::mem::size_of::<T>();
+        let mut bytes = Vec::with_capacity(buf.len() * width);
+        for e in buf {
+            bytes.extend_from_slice(&e.get_num().to_u128().unwrap().to_le_bytes()[..width]);
+        }
+        self.file.seek(SeekFrom::Start((offset * width) as u64))?;
+        self.file.write_all(&bytes)
+    }
+}
+
+/// Transposes the `rows × cols` matrix in `src` into `dst`, one
+/// `tile × tile` block at a time.
+fn transpose<T, S>(src: &mut S, dst: &mut S, rows: usize, cols: usize, tile: usize, zero: FieldElement<T>) -> io::Result<()>
+    where T: Copy,
+          S: ChunkStore<T> + ?Sized,
+{
+    let mut block = vec![zero; tile * tile];
+    let mut line = vec![zero; tile];
+    for r0 in (0..rows).step_by(tile) {
+        for c0 in (0..cols).step_by(tile) {
+            let h = tile.min(rows - r0);
+            let w = tile.min(cols - c0);
+            for r in 0..h {
+                src.read((r0 + r) * cols + c0, &mut block[r * w..(r + 1) * w])?;
+            }
+            for c in 0..w {
+                for r in 0..h {
+                    line[r] = block[r * w + c];
+                }
+                dst.write((c0 + c) * rows + r0, &line[..h])?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Computes the NTT of the `rows * cols` elements in `input` with the
+/// four-step algorithm and writes it to `output`, in natural order.
+///
+/// `input` is used as scratch space and clobbered. Both dimensions must be
+/// powers of two; memory use is `O(rows + cols + tile²)` elements.
+pub fn streaming_ntt<T, S>(input: &mut S, output: &mut S, rows: usize, tile: usize, order: T) -> io::Result<()>
+    where T: Widening + Debug,
+          S: ChunkStore<T> + ?Sized,
+{
+    let n = input.len();
+    assert_eq!(output.len(), n);
+    assert!(rows.is_power_of_two() && n % rows == 0, "Rows must be a power of two dividing the length.");
+    assert!(tile > 0);
+    let cols = n / rows;
+    assert!(cols.is_power_of_two(), "Transform size must be a power of two.");
+    let zero = FieldElement::new(T::zero(), order);
+    let omega = root_of_unity(n, order).expect("The field has no subgroup of this size.");
+    // the `rows`- and `cols`-point roots, found once rather than per line
+    let (omega_rows, omega_cols) = (omega.pow(cast(cols as u64)), omega.pow(cast(rows as u64)));
+    let line_ntt = |line: &mut [FieldElement<T>], root| {
+        if line.len() > 1 {
+            transform(line, root);
+        }
+    };
+
+    // Columns of the input become rows of `output`, where each gets a
+    // `rows`-point transform followed by the twiddle factors ω^(j·k).
+    transpose(input, output, rows, cols, tile, zero)?;
+    let mut line = vec![zero; rows];
+    let mut w_j = zero.one();
+    for j in 0..cols {
+        output.read(j * rows, &mut line)?;
+        line_ntt(&mut line, omega_rows);
+        let mut twiddle = zero.one();
+        for x in line.iter_mut() {
+            *x = *x * twiddle;
+            twiddle = twiddle * w_j;
+        }
+        output.write(j * rows, &line)?;
+        w_j = w_j * omega;
+    }
+
+    // Back to `rows × cols`, then a `cols`-point transform per row.
+    transpose(output, input, cols, rows, tile, zero)?;
+    let mut line = vec![zero; cols];
+    for k in 0..rows {
+        input.read(k * cols, &mut line)?;
+        line_ntt(&mut line, omega_cols);
+        input.write(k * cols, &line)?;
+    }
+
+    // Result index k1 + rows·k2 lives at row k1, column k2.
+    transpose(input, output, rows, cols, tile, zero)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ntt::ntt;
+
+    fn data(order: u64, len: usize) -> Vec<FieldElement<u64>> {
+        (0..len as u64).map(|i| FieldElement::new((i * i + 7) % order, order)).collect()
+    }
+
+    #[test]
+    fn streaming_ntt_matches_ntt() {
+        let mut input = data(193, 64);
+        let expected = ntt(&input);
+        let mut output = input.clone();
+        streaming_ntt(&mut input[..], &mut output[..], 8, 3, 193u64).unwrap();
+        assert_eq!(output, expected);
+
+        let mut input = data(193, 64);
+        streaming_ntt(&mut input[..], &mut output[..], 2, 4, 193u64).unwrap();
+        assert_eq!(output, expected);
+
+        // single-row and single-column layouts
+        for rows in [1, 64] {
+            let mut input = data(193, 64);
+            streaming_ntt(&mut input[..], &mut output[..], rows, 5, 193u64).unwrap();
+            assert_eq!(output, expected);
+        }
+    }
+
+    #[test]
+    fn file_store_works() {
+        let dir = std::env::temp_dir();
+        let input_path = dir.join(format!("finite_field_ntt_in_{}", std::process::id()));
+        let output_path = dir.join(format!("finite_field_ntt_out_{}", std::process::id()));
+        let open = |path: &std::path::Path| {
+            File::options().read(true).write(true).create(true).truncate(true).open(path).unwrap()
+        };
+
+        let values = data(7681, 256);
+        let mut input = FileStore::new(open(&input_path), 256, 7681u64).unwrap();
+        let mut output = FileStore::new(open(&output_path), 256, 7681u64).unwrap();
+        input.write(0, &values).unwrap();
+        streaming_ntt(&mut input, &mut output, 16, 8, 7681u64).unwrap();
+
+        let mut result = values.clone();
+        output.read(0, &mut result).unwrap();
+        assert_eq!(result, ntt(&values));
+
+        std::fs::remove_file(input_path).unwrap();
+        std::fs::remove_file(output_path).unwrap();
+    }
+}
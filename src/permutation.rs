@@ -0,0 +1,139 @@
+//! Grand-product accumulators for PLONK-style permutation arguments.
+
+use std::{fmt::Debug};
+use crate::{FieldElement, FiniteField, Widening};
+
+/// Builds the running-product column `z` with `z[0] = one` and
+/// `z[i + 1] = z[i] * numerators[i] / denominators[i]`.
+///
+/// The returned column has one more entry than the inputs; its last entry
+/// is the full product, so empty inputs give `[one]`.
+pub fn running_product<T>(
+    one: FieldElement<T>,
+    numerators: &[FieldElement<T>],
+    denominators: &[FieldElement<T>],
+) -> Vec<FieldElement<T>>
+    where T: Widening + Debug,
+{
+    assert_eq!(numerators.len(), denominators.len());
+    let mut z = Vec::with_capacity(numerators.len() + 1);
+    let mut acc = one;
+    z.push(acc);
+    for (&num, &den) in numerators.iter().zip(denominators) {
+        acc = acc * num / den;
+        z.push(acc);
+    }
+    z
+}
+
+/// Builds the copy-constraint accumulator for the wire `columns`.
+///
+/// Cell `i` of column `j` is labelled by its flat index `j * n + i`, and
+/// `sigma[j][i]` is the flat index of the cell it is wired to. Each row
+/// contributes `Π (w + β·id + γ) / Π (w + β·σ + γ)` over the columns.
+///
+/// The labels are reduced modulo the order, so they are only distinct when
+/// there are at most as many cells as field elements; panics otherwise.
+pub fn copy_constraint_product<T>(
+    columns: &[Vec<FieldElement<T>>],
+    sigma: &[Vec<usize>],
+    beta: FieldElement<T>,
+    gamma: FieldElement<T>,
+) -> Vec<FieldElement<T>>
+    where T: Widening + Debug,
+{
+    assert_eq!(columns.len(), sigma.len());
+    let n = columns.first().map_or(0, |c| c.len());
+    let cells = columns.len() * n;
+    assert!(
+        beta.get_order().to_u64().is_none_or(|p| cells as u64 <= p),
+        "{} cells need more distinct labels than the field has", cells,
+    );
+    let label = |k: usize| {
+        assert!(k < cells, "sigma refers to cell {} of {}", k, cells);
+        beta.from_u64(k as u64)
+    };
+
+    let mut numerators = vec![beta.one(); n];
+    let mut denominators = vec![beta.one(); n];
+    for (j, (column, perm)) in columns.iter().zip(sigma).enumerate() {
+        assert_eq!(column.len(), n);
+        assert_eq!(perm.len(), n);
+        for i in 0..n {
+            let w = column[i];
+            numerators[i] = numerators[i] * (w + beta * label(j * n + i) + gamma);
+            denominators[i] = denominators[i] * (w + beta * label(perm[i]) + gamma);
+        }
+    }
+    running_product(beta.one(), &numerators, &denominators)
+}
+
+/// Checks that the wire values respect the permutation `sigma`, i.e. that
+/// the copy-constraint accumulator closes at one.
+pub fn verify_copy_constraints<T>(
+    columns: &[Vec<FieldElement<T>>],
+    sigma: &[Vec<usize>],
+    beta: FieldElement<T>,
+    gamma: FieldElement<T>,
+) -> bool
+    where T: Widening + Debug,
+{
+    let z = copy_constraint_product(columns, sigma, beta, gamma);
+    z.last() == Some(&beta.one())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column(nums: &[u64]) -> Vec<FieldElement<u64>> {
+        nums.iter().map(|&n| FieldElement::new(n, 10007)).collect()
+    }
+
+    #[test]
+    fn running_product_works() {
+        let num = column(&[2, 3, 5]);
+        let den = column(&[3, 5, 2]);
+        let one = num[0].one();
+        let z = running_product(one, &num, &den);
+        assert_eq!(z.len(), 4);
+        assert_eq!(z[0], one);
+        assert_eq!(z[1], num[0] / den[0]);
+        assert_eq!(z[3], one);
+        assert_eq!(running_product(one, &[], &[]), vec![one]);
+    }
+
+    #[test]
+    fn copy_constraints_work() {
+        let beta = FieldElement::new(17, 10007);
+        let gamma = FieldElement::new(1234, 10007);
+        // a0 = b2 and a1 = b0
+        let sigma = vec![vec![5, 3, 2], vec![1, 4, 0]];
+
+        let columns = vec![column(&[1, 2, 3]), column(&[2, 5, 1])];
+        assert!(verify_copy_constraints(&columns, &sigma, beta, gamma));
+
+        let columns = vec![column(&[1, 2, 3]), column(&[2, 5, 4])];
+        assert!(!verify_copy_constraints(&columns, &sigma, beta, gamma));
+    }
+
+    #[test]
+    fn labels_fill_a_small_field() {
+        // 2 × 3 cells take labels 0..6 in GF(7)
+        let small = |nums: &[u8]| nums.iter().map(|&n| FieldElement::new(n, 7)).collect::<Vec<_>>();
+        let (beta, gamma) = (FieldElement::new(2u8, 7), FieldElement::new(1, 7));
+        let sigma = vec![vec![5, 1, 2], vec![3, 4, 0]];
+        assert!(verify_copy_constraints(&[small(&[1, 2, 3]), small(&[4, 6, 1])], &sigma, beta, gamma));
+        assert!(!verify_copy_constraints(&[small(&[1, 2, 3]), small(&[4, 6, 2])], &sigma, beta, gamma));
+    }
+
+    #[test]
+    #[should_panic(expected = "8 cells need more distinct labels than the field has")]
+    fn too_many_cells_panics() {
+        let small = |nums: &[u8]| nums.iter().map(|&n| FieldElement::new(n, 7)).collect::<Vec<_>>();
+        let sigma = vec![vec![0, 1, 2, 3], vec![4, 5, 6, 7]];
+        let columns = [small(&[1, 2, 3, 4]), small(&[1, 2, 3, 4])];
+        copy_constraint_product(&columns, &sigma, FieldElement::new(3, 7), FieldElement::new(5, 7));
+    }
+}
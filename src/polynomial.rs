@@ -0,0 +1,387 @@
+//! Univariate polynomials over any [`FiniteField`].
+
+use std::ops::{Add, Div, Mul, Rem, Sub};
+use crate::{FiniteField, Frobenius};
+
+pub(crate) fn trim<F: FiniteField>(mut a: Vec<F>) -> Vec<F> {
+    while a.last().is_some_and(|c| c.is_zero()) {
+        a.pop();
+    }
+    a
+}
+
+pub(crate) fn mul_poly<F: FiniteField>(a: &[F], b: &[F]) -> Vec<F> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut prod = vec![a[0].zero(); a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            prod[i + j] = prod[i + j] + x * y;
+        }
+    }
+    prod
+}
+
+pub(crate) fn sub_poly<F: FiniteField>(a: &[F], b: &[F]) -> Vec<F> {
+    let mut out = a.to_vec();
+    if let Some(&c) = b.first() {
+        out.resize(a.len().max(b.len()), c.zero());
+    }
+    for (x, &y) in out.iter_mut().zip(b) {
+        *x = *x - y;
+    }
+    trim(out)
+}
+
+/// Divides `a` by the nonzero, trimmed `b`, returning quotient and
+/// remainder.
+pub(crate) fn div_rem_poly<F: FiniteField>(a: &[F], b: &[F]) -> (Vec<F>, Vec<F>) {
+    let mut rem = trim(a.to_vec());
+    let m = b.len() - 1;
+    if rem.len() <= m {
+        return (Vec::new(), rem);
+    }
+    let lead_inv = b[m].inverse().expect("divisor must not be zero");
+    let mut quot = vec![b[0].zero(); rem.len() - m];
+    for k in (0..quot.len()).rev() {
+        let q = rem[k + m] * lead_inv;
+        quot[k] = q;
+        for (i, &c) in b.iter().enumerate() {
+            rem[k + i] = rem[k + i] - q * c;
+        }
+    }
+    rem.truncate(m);
+    (quot, trim(rem))
+}
+
+pub(crate) fn mul_mod<E: FiniteField>(a: &[E], b: &[E], f: &[E]) -> Vec<E> {
+    div_rem_poly(&mul_poly(a, b), f).1
+}
+
+/// Raises `a` modulo `f` to the power given as little-endian 64-bit words.
+fn pow_mod<E: FiniteField>(a: &[E], exp: &[u64], f: &[E]) -> Vec<E> {
+    let mut result = vec![f[0].one()];
+    for &word in exp.iter().rev() {
+        for i in (0..64).rev() {
+            result = mul_mod(&result, &result, f);
+            if word >> i & 1 == 1 {
+                result = mul_mod(&result, a, f);
+            }
+        }
+    }
+    trim(result)
+}
+
+pub(crate) fn gcd<E: FiniteField>(a: Vec<E>, b: Vec<E>) -> Vec<E> {
+    let (mut a, mut b) = (trim(a), trim(b));
+    while !b.is_empty() {
+        let (_, r) = div_rem_poly(&a, &b);
+        (a, b) = (b, r);
+    }
+    a
+}
+
+/// Returns the distinct roots in `E` of the nonzero `f`, in no particular
+/// order.
+///
+/// The roots in `E` of order `q = p^n` are split off as `gcd(f, y^q − y)`,
+/// and factors are then separated by `gcd(g, h)` for
+/// `h = (y + δ)^((q − 1)/2) − 1` in odd characteristic, or the trace
+/// `h = Σ_{i<n} (δy)^(2^i)` in even, for `δ = delta(1), delta(2), …` until
+/// every factor is linear. This terminates once the `δ` tried separate
+/// every pair of roots, as they do when they run through all of `E`.
+pub(crate) fn distinct_roots<E: Frobenius>(f: Vec<E>, delta: impl Fn(u64) -> E) -> Vec<E> {
+    let f = trim(f);
+    let (zero, one) = (f[0].zero(), f[0].one());
+    let p = zero.characteristic();
+    let n = zero.absolute_degree();
+
+    let y = vec![zero, one];
+    let frobenius = (0..n).fold(y.clone(), |t, _| pow_mod(&t, &p, &f));
+    let mut pending = vec![gcd(f, sub_poly(&frobenius, &y))];
+    // (p − 1)/2 for odd p
+    let half: Vec<u64> = (0..p.len()).map(|i| p[i] >> 1 | p.get(i + 1).map_or(0, |w| w << 63)).collect();
+    let mut roots = Vec::new();
+    let mut j = 0;
+    while let Some(f) = pending.pop() {
+        if f.len() < 3 {
+            if f.len() == 2 {
+                roots.push(zero - f[0] / f[1]);
+            }
+            continue;
+        }
+        loop {
+            j += 1;
+            let d = delta(j);
+            let h = if p == [2] {
+                let mut t = div_rem_poly(&[zero, d], &f).1;
+                let mut trace = t.clone();
+                for _ in 1..n {
+                    t = mul_mod(&t, &t, &f);
+                    // subtraction is addition in characteristic 2
+                    trace = sub_poly(&trace, &t);
+                }
+                trace
+            } else {
+                // (y + δ)^((q − 1)/2) as b^(1 + p + … + p^(n−1)), b = (y + δ)^((p − 1)/2)
+                let mut t = pow_mod(&[d, one], &half, &f);
+                let mut norm = t.clone();
+                for _ in 1..n {
+                    t = pow_mod(&t, &p, &f);
+                    norm = mul_mod(&norm, &t, &f);
+                }
+                sub_poly(&norm, &[one])
+            };
+            let g = gcd(f.clone(), h);
+            if g.len() > 1 && g.len() < f.len() {
+                pending.push(div_rem_poly(&f, &g).0);
+                pending.push(g);
+                break;
+            }
+        }
+    }
+    roots
+}
+
+/// A polynomial stored as its coefficients from the constant term upward.
+/// Trailing zero coefficients are trimmed, so the zero polynomial has no
+/// coefficients.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial<F> {
+    coeffs: Vec<F>,
+}
+
+impl<F: FiniteField> Polynomial<F> {
+    pub fn new(mut coeffs: Vec<F>) -> Self {
+        while coeffs.last().is_some_and(|c| c.is_zero()) {
+            coeffs.pop();
+        }
+        Polynomial { coeffs }
+    }
+
+    pub fn coefficients(&self) -> &[F] {
+        &self.coeffs
+    }
+
+    /// Returns `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    /// Evaluates at `x` by Horner's rule, with one multiplication and one
+    /// addition per coefficient.
+    pub fn evaluate(&self, x: F) -> F {
+        self.coeffs.iter().rev().fold(x.zero(), |acc, &c| acc * x + c)
+    }
+
+    /// Evaluates at each of `xs` in turn.
+    pub fn evaluate_many(&self, xs: &[F]) -> Vec<F> {
+        xs.iter().map(|&x| self.evaluate(x)).collect()
+    }
+
+    /// Divides by `divisor`, returning the quotient and a remainder of
+    /// lower degree than `divisor`. Panics if `divisor` is zero.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        assert!(!divisor.is_zero(), "Zero is not valid denominator.");
+        let (quot, rem) = div_rem_poly(&self.coeffs, &divisor.coeffs);
+        (Polynomial::new(quot), Polynomial::new(rem))
+    }
+}
+
+impl<F: Frobenius> Polynomial<F> {
+    /// Returns the distinct roots in `F`, in no particular order, by
+    /// splitting off `gcd(f, x^p − x)` and separating its linear factors
+    /// with `gcd(f, (x + δ)^((p − 1)/2) − 1)` for `δ = 1, 2, …`. Panics for
+    /// the zero polynomial, or if `F` is not a prime field.
+    pub fn roots(&self) -> Vec<F> {
+        assert!(!self.is_zero(), "the zero polynomial vanishes everywhere");
+        assert_eq!(self.coeffs[0].absolute_degree(), 1, "roots are found over prime fields");
+        let zero = self.coeffs[0].zero();
+        distinct_roots(self.coeffs.clone(), |j| zero.from_u64(j))
+    }
+}
+
+impl<F: FiniteField> Add for Polynomial<F> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let (mut long, short) = if self.coeffs.len() >= rhs.coeffs.len() {
+            (self.coeffs, rhs.coeffs)
+        } else {
+            (rhs.coeffs, self.coeffs)
+        };
+        for (a, b) in long.iter_mut().zip(short) {
+            *a = *a + b;
+        }
+        Polynomial::new(long)
+    }
+}
+
+impl<F: FiniteField> Sub for Polynomial<F> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let mut coeffs = self.coeffs;
+        if let Some(&c) = rhs.coeffs.first() {
+            coeffs.resize(coeffs.len().max(rhs.coeffs.len()), c.zero());
+        }
+        for (a, b) in coeffs.iter_mut().zip(rhs.coeffs) {
+            *a = *a - b;
+        }
+        Polynomial::new(coeffs)
+    }
+}
+
+impl<F: FiniteField> Mul for Polynomial<F> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.is_zero() || rhs.is_zero() {
+            return Polynomial { coeffs: Vec::new() };
+        }
+        let zero = self.coeffs[0].zero();
+        let mut coeffs = vec![zero; self.coeffs.len() + rhs.coeffs.len() - 1];
+        for (i, &a) in self.coeffs.iter().enumerate() {
+            for (j, &b) in rhs.coeffs.iter().enumerate() {
+                coeffs[i + j] = coeffs[i + j] + a * b;
+            }
+        }
+        Polynomial::new(coeffs)
+    }
+}
+
+impl<F: FiniteField> Div for Polynomial<F> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).0
+    }
+}
+
+impl<F: FiniteField> Rem for Polynomial<F> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).1
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::FieldElement;
+
+    fn poly(nums: &[u32]) -> Polynomial<FieldElement<u32>> {
+        Polynomial::new(nums.iter().map(|&n| FieldElement::new(n, 7)).collect())
+    }
+
+    #[test]
+    fn new_trims_zeros() {
+        let p = poly(&[1, 2, 0, 0]);
+        assert_eq!(p.degree(), Some(1));
+        assert!(poly(&[0, 0]).is_zero());
+        assert_eq!(poly(&[]).degree(), None);
+    }
+
+    #[test]
+    fn add_sub_works() {
+        assert_eq!(poly(&[1, 2, 3]) + poly(&[6, 5]), poly(&[0, 0, 3]));
+        assert_eq!(poly(&[1, 2, 3]) - poly(&[1, 2, 3]), poly(&[]));
+        assert_eq!(poly(&[1]) - poly(&[0, 1]), poly(&[1, 6]));
+    }
+
+    #[test]
+    fn bigint_coefficients_work() {
+        use crate::FieldElementBig;
+        use crypto_bigint::U256;
+
+        let c = |n: u8| FieldElementBig::new(U256::from(n), U256::from(7u8));
+        let p = Polynomial::new(vec![c(1), c(1)]);
+        let q = Polynomial::new(vec![c(6), c(1)]);
+        assert_eq!(p * q, Polynomial::new(vec![c(6), c(0), c(1)]));
+    }
+
+    #[test]
+    fn mul_works() {
+        // (x + 1)(x + 6) = x^2 - 1
+        assert_eq!(poly(&[1, 1]) * poly(&[6, 1]), poly(&[6, 0, 1]));
+        assert!((poly(&[1, 1]) * poly(&[])).is_zero());
+    }
+
+    #[test]
+    fn evaluate_works() {
+        let c = |n| FieldElement::new(n, 7);
+        // 3x² + 2x + 1
+        let p = poly(&[1, 2, 3]);
+        assert_eq!(p.evaluate(c(0)), c(1));
+        assert_eq!(p.evaluate(c(2)), c(3));
+        assert_eq!(poly(&[]).evaluate(c(5)), c(0));
+        let xs: Vec<_> = (0..7).map(c).collect();
+        let ys = p.evaluate_many(&xs);
+        assert_eq!(ys, [1, 6, 3, 6, 1, 2, 2].map(c));
+        // the remainder mod (x − a) is p(a)
+        assert_eq!(p.clone() % poly(&[4, 1]), poly(&[ys[3].get_num()]));
+    }
+
+    #[test]
+    fn roots_work() {
+        let sorted = |p: Polynomial<FieldElement<u32>>| {
+            let mut roots: Vec<_> = p.roots().iter().map(|r| r.get_num()).collect();
+            roots.sort();
+            roots
+        };
+        // (x − 1)²(x − 3)(x − 5)(x² + 1), as x² + 1 is irreducible mod 7
+        let p = poly(&[6, 1]) * poly(&[6, 1]) * poly(&[4, 1]) * poly(&[2, 1]) * poly(&[1, 0, 1]);
+        assert_eq!(sorted(p), [1, 3, 5]);
+        assert_eq!(sorted(poly(&[0, 0, 3])), [0]);
+        assert_eq!(sorted(poly(&[1, 0, 1])), [] as [u32; 0]);
+        assert_eq!(sorted(poly(&[4])), [] as [u32; 0]);
+        // x⁷ − x vanishes on all of GF(7)
+        assert_eq!(sorted(poly(&[0, 6, 0, 0, 0, 0, 0, 1])), [0, 1, 2, 3, 4, 5, 6]);
+        // x² + x over GF(2)
+        let roots = Polynomial::new(vec![FieldElement::new(0, 2u8), FieldElement::new(1, 2), FieldElement::new(1, 2)]).roots();
+        assert_eq!(roots.len(), 2);
+    }
+
+    #[test]
+    fn roots_over_large_field_work() {
+        use crate::FieldElementBig;
+        use crypto_bigint::U256;
+
+        // (x − 2)(x − 3)(x − 5) modulo 2^255 − 19
+        let p = U256::from_be_hex("7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed");
+        let c = |n: u8| FieldElementBig::new(U256::from(n), p);
+        let f = Polynomial::new(vec![c(0) - c(30), c(31), c(0) - c(10), c(1)]);
+        let mut roots: Vec<_> = f.roots().iter().map(|r| r.get_num()).collect();
+        roots.sort();
+        assert_eq!(roots, [2u8, 3, 5].map(U256::from));
+    }
+
+    #[test]
+    fn div_rem_works() {
+        // x³ + 2x + 3 = (x² + 3x + 4)(x + 4) + 1 over GF(7)
+        let (q, r) = poly(&[3, 2, 0, 1]).div_rem(&poly(&[4, 1]));
+        assert_eq!((q.clone(), r.clone()), (poly(&[4, 3, 1]), poly(&[1])));
+        assert_eq!(q * poly(&[4, 1]) + r, poly(&[3, 2, 0, 1]));
+        // non-monic divisors and short dividends
+        let (a, b) = (poly(&[1, 2, 3, 4, 5]), poly(&[6, 0, 3]));
+        assert_eq!(a.clone() / b.clone() * b.clone() + a.clone() % b.clone(), a);
+        assert!((a.clone() % b.clone()).degree() < b.degree());
+        assert_eq!(b.clone() / a.clone(), poly(&[]));
+        assert_eq!(b.clone() % a, b);
+        assert!((poly(&[]) % poly(&[2])).is_zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "Zero is not valid denominator.")]
+    fn div_by_zero_panics() {
+        let _ = poly(&[1, 1]) / poly(&[]);
+    }
+}
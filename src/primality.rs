@@ -0,0 +1,66 @@
+//! Miller–Rabin primality testing for moduli, behind the `primality`
+//! feature.
+
+use crypto_bigint::modular::runtime_mod::{DynResidue, DynResidueParams};
+use crypto_bigint::{NonZero, Uint};
+
+const BASES: [u8; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Miller–Rabin to the first twelve prime bases. This is deterministic below
+/// 3.3·10²⁴ and a strong probable-prime test beyond.
+pub(crate) fn is_prime<const LIMBS: usize>(n: &Uint<LIMBS>) -> bool {
+    if *n < Uint::from(2u8) {
+        return false;
+    }
+    for b in BASES {
+        let b = Uint::from(b);
+        if *n == b {
+            return true;
+        }
+        if n.rem(&NonZero::new(b).unwrap()) == Uint::ZERO {
+            return false;
+        }
+    }
+
+    // n − 1 = d·2^s with d odd
+    let n_minus_one = n.wrapping_sub(&Uint::ONE);
+    let s = n_minus_one.trailing_zeros();
+    let d = n_minus_one.shr_vartime(s);
+    let params = DynResidueParams::new(n);
+    let one = DynResidue::one(params);
+    let minus_one = DynResidue::new(&n_minus_one, params);
+    'bases: for b in BASES {
+        let mut x = DynResidue::new(&Uint::from(b), params).pow(&d);
+        if x == one || x == minus_one {
+            continue;
+        }
+        for _ in 1..s {
+            x = x * x;
+            if x == minus_one {
+                continue 'bases;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crypto_bigint::{U128, U256};
+
+    #[test]
+    fn is_prime_works() {
+        let small: Vec<u64> = (0..60).filter(|&n| is_prime(&U128::from(n))).collect();
+        assert_eq!(small, vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59]);
+        // Carmichael numbers and strong pseudoprimes to small bases
+        for n in [561u64, 41041, 3215031751, 3825123056546413051] {
+            assert!(!is_prime(&U128::from(n)));
+        }
+        assert!(is_prime(&U128::from(18446744073709551557u64)));
+        assert!(is_prime(&U256::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f")));
+        assert!(!is_prime(&U256::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2d")));
+    }
+}
@@ -0,0 +1,118 @@
+use std::{fmt::Debug, ops::{Add, Mul, Sub}};
+use crate::{FieldElement, FieldError, Widening};
+
+/// An element of `Z/nZ` for an arbitrary, possibly composite, modulus.
+///
+/// Addition, subtraction and multiplication share the [`FieldElement`]
+/// code. There is no `Div`, since Fermat inversion is only valid for prime
+/// moduli; [`RingElement::invert`] uses the extended Euclidean algorithm
+/// and fails for non-units.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub struct RingElement<T> {
+    inner: FieldElement<T>,
+}
+
+impl<T> RingElement<T>
+    where T: Widening + Debug,
+{
+    pub fn new(n: T, order: T) -> Self {
+        RingElement { inner: FieldElement::new(n, order) }
+    }
+
+    pub fn try_new(n: T, order: T) -> Result<Self, FieldError> {
+        FieldElement::try_new(n, order).map(|inner| RingElement { inner })
+    }
+
+    pub fn get_num(&self) -> T {
+        self.inner.get_num()
+    }
+
+    pub fn get_order(&self) -> T {
+        self.inner.get_order()
+    }
+
+    /// Returns the inverse, or `None` unless `gcd(n, order) = 1`.
+    pub fn invert(&self) -> Option<Self> {
+        self.inner.inverse().map(|inner| RingElement { inner })
+    }
+
+    pub fn is_unit(&self) -> bool {
+        self.invert().is_some()
+    }
+
+    /// Raises the element to `exp` by square-and-multiply. Unlike
+    /// [`FieldElement::pow`], the exponent is not reduced modulo `order − 1`.
+    pub fn pow(self, mut exp: u64) -> Self {
+        let mut result = RingElement { inner: self.inner.one() };
+        let mut base = self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        result
+    }
+}
+
+impl<T> Add for RingElement<T>
+    where T: Widening + Debug,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        RingElement { inner: self.inner + rhs.inner }
+    }
+}
+
+impl<T> Sub for RingElement<T>
+    where T: Widening + Debug,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        RingElement { inner: self.inner - rhs.inner }
+    }
+}
+
+impl<T> Mul for RingElement<T>
+    where T: Widening,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        RingElement { inner: self.inner * rhs.inner }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic_works() {
+        let a: RingElement<u32> = RingElement::new(7, 12);
+        let b: RingElement<u32> = RingElement::new(8, 12);
+        assert_eq!(a + b, RingElement::new(3, 12));
+        assert_eq!(a - b, RingElement::new(11, 12));
+        assert_eq!(a * b, RingElement::new(8, 12));
+    }
+
+    #[test]
+    fn invert_works() {
+        let units: Vec<u32> = (0..12).filter(|&n| RingElement::new(n, 12u32).is_unit()).collect();
+        assert_eq!(units, vec![1, 5, 7, 11]);
+        assert_eq!(RingElement::new(5u32, 12).invert(), Some(RingElement::new(5, 12)));
+        assert_eq!(RingElement::new(4u32, 12).invert(), None);
+    }
+
+    #[test]
+    fn pow_works() {
+        // 2^14 = 16384 ≡ 4 mod 15, whereas reducing the exponent mod 14 gives 1
+        let a: RingElement<u64> = RingElement::new(2, 15);
+        assert_eq!(a.pow(14), RingElement::new(4, 15));
+        assert_eq!(a.pow(0), RingElement::new(1, 15));
+    }
+}
@@ -0,0 +1,137 @@
+use std::ops::{Add, Mul, Sub};
+use num::{BigUint, One, ToPrimitive, Zero};
+use crate::FieldElement;
+
+/// A value represented by its residues modulo several pairwise coprime
+/// machine-word primes (a residue number system).
+///
+/// Residues multiply at double width, so the moduli may take the full 64
+/// bits.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RnsElement {
+    residues: Vec<FieldElement<u64>>,
+}
+
+impl RnsElement {
+    pub fn new(value: &BigUint, moduli: &[u64]) -> Self {
+        let residues = moduli.iter()
+            .map(|&m| {
+                let r = (value % m).to_u64().unwrap();
+                FieldElement::new(r, m)
+            })
+            .collect();
+        RnsElement { residues }
+    }
+
+    pub fn from_u64(value: u64, moduli: &[u64]) -> Self {
+        Self::new(&BigUint::from(value), moduli)
+    }
+
+    pub fn residues(&self) -> &[FieldElement<u64>] {
+        &self.residues
+    }
+
+    pub fn moduli(&self) -> Vec<u64> {
+        self.residues.iter().map(|r| r.get_order()).collect()
+    }
+
+    /// The product of the moduli, i.e. the size of the representable range.
+    pub fn dynamic_range(&self) -> BigUint {
+        self.residues.iter().fold(BigUint::one(), |acc, r| acc * r.get_order())
+    }
+
+    /// Recovers the value in `[0, M)` by the Chinese remainder theorem.
+    pub fn reconstruct(&self) -> BigUint {
+        let range = self.dynamic_range();
+        let mut value = BigUint::zero();
+        for r in &self.residues {
+            let m = r.get_order();
+            let partial = &range / m;
+            let partial_mod = FieldElement::new((&partial % m).to_u64().unwrap(), m);
+            let coefficient = *r / partial_mod;
+            value += partial * coefficient.get_num();
+        }
+        value % range
+    }
+
+    /// Expresses the same value, taken in `[0, M)`, over a new set of moduli.
+    pub fn extend_basis(&self, moduli: &[u64]) -> Self {
+        Self::new(&self.reconstruct(), moduli)
+    }
+}
+
+impl Add for RnsElement {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.residues.len(), rhs.residues.len());
+        let residues = self.residues.iter().zip(&rhs.residues).map(|(&a, &b)| a + b).collect();
+        RnsElement { residues }
+    }
+}
+
+impl Sub for RnsElement {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.residues.len(), rhs.residues.len());
+        let residues = self.residues.iter().zip(&rhs.residues).map(|(&a, &b)| a - b).collect();
+        RnsElement { residues }
+    }
+}
+
+impl Mul for RnsElement {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.residues.len(), rhs.residues.len());
+        let residues = self.residues.iter().zip(&rhs.residues).map(|(&a, &b)| a * b).collect();
+        RnsElement { residues }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODULI: [u64; 3] = [4294967291, 4294967279, 4294967231];
+
+    #[test]
+    fn reconstruct_works() {
+        let value = BigUint::parse_bytes(b"123456789012345678901234567", 10).unwrap();
+        let x = RnsElement::new(&value, &MODULI);
+        assert_eq!(x.residues()[0].get_num(), (&value % MODULI[0]).to_u64().unwrap());
+        assert_eq!(x.reconstruct(), value);
+    }
+
+    #[test]
+    fn arithmetic_works() {
+        let a = BigUint::from(987654321987654321u64);
+        let b = BigUint::from(12345678u64);
+        let x = RnsElement::new(&a, &MODULI);
+        let y = RnsElement::new(&b, &MODULI);
+        assert_eq!((x.clone() * y.clone()).reconstruct(), &a * &b);
+        assert_eq!((x.clone() + y.clone()).reconstruct(), &a + &b);
+        assert_eq!((x - y).reconstruct(), &a - &b);
+    }
+
+    #[test]
+    fn full_width_moduli_work() {
+        let moduli = [18446744073709551557, 18446744073709551533, 18446744073709551521];
+        let a = BigUint::from(u64::MAX) * BigUint::from(u64::MAX - 1);
+        let b = BigUint::from(u64::MAX - 2) * BigUint::from(3u8);
+        let x = RnsElement::new(&a, &moduli);
+        let y = RnsElement::new(&b, &moduli);
+        let range = x.dynamic_range();
+        assert_eq!((x * y).reconstruct(), (&a * &b) % range);
+    }
+
+    #[test]
+    fn extend_basis_works() {
+        let x = RnsElement::from_u64(1_000_000_007, &MODULI[..2]);
+        let y = x.extend_basis(&[65521, 65519, 65497]);
+        assert_eq!(y.moduli(), vec![65521, 65519, 65497]);
+        assert_eq!(y.reconstruct(), BigUint::from(1_000_000_007u64));
+    }
+}
@@ -0,0 +1,158 @@
+//! String forms understood by SageMath (`GF(17)(14)`) and PARI/GP
+//! (`Mod(14, 17)`), for round-tripping values with notebooks.
+
+use std::{fmt::{Debug, Display}};
+use num::NumCast;
+use crate::{FieldElement, FieldError, Polynomial, Widening};
+
+fn parse_element<T: Widening>(n: &str, p: &str) -> Result<FieldElement<T>, FieldError> {
+    let order = T::from_str_radix(p.trim(), 10).map_err(|_| FieldError::Parse)?;
+    if order == T::zero() {
+        return Err(FieldError::ZeroModulus);
+    }
+    if order <= T::one() {
+        return Err(FieldError::NonPrimeModulus);
+    }
+    let n: i128 = n.trim().parse().map_err(|_| FieldError::Parse)?;
+    if order.to_i128().is_some() {
+        return Ok(FieldElement::from_signed(n, order));
+    }
+    // an order above i128::MAX exceeds |n|, so only the sign needs reducing
+    let m: T = NumCast::from(n.unsigned_abs()).unwrap();
+    Ok(FieldElement::new(if n < 0 { order - m } else { m }, order))
+}
+
+/// Splits `Mod(a, p), Mod(b, p), ...` into its `Mod(...)` terms.
+fn split_mods(mut s: &str) -> Result<Vec<&str>, FieldError> {
+    let mut terms = Vec::new();
+    loop {
+        s = s.trim_start();
+        if s.is_empty() {
+            return Ok(terms);
+        }
+        let end = s.find(')').ok_or(FieldError::Parse)? + 1;
+        terms.push(&s[..end]);
+        s = s[end..].trim_start();
+        s = s.strip_prefix(',').unwrap_or(s);
+    }
+}
+
+impl<T> FieldElement<T>
+    where T: Widening + Display,
+{
+    pub fn to_sage_string(&self) -> String {
+        format!("GF({})({})", self.get_order(), self.get_num())
+    }
+
+    pub fn to_pari_string(&self) -> String {
+        format!("Mod({}, {})", self.get_num(), self.get_order())
+    }
+
+    /// Parses either the Sage form `GF(p)(n)` or the PARI form `Mod(n, p)`.
+    /// The representative may be negative or unreduced, as in both systems.
+    pub fn from_sage_string(s: &str) -> Result<Self, FieldError> {
+        let s = s.trim();
+        if let Some(rest) = s.strip_prefix("GF(") {
+            let (p, n) = rest.strip_suffix(')').and_then(|r| r.split_once(")(")).ok_or(FieldError::Parse)?;
+            parse_element(n, p)
+        } else if let Some(rest) = s.strip_prefix("Mod(") {
+            let (n, p) = rest.strip_suffix(')').and_then(|r| r.split_once(',')).ok_or(FieldError::Parse)?;
+            parse_element(n, p)
+        } else {
+            Err(FieldError::Parse)
+        }
+    }
+}
+
+impl<T> Polynomial<FieldElement<T>>
+    where T: Widening + Display + Debug,
+{
+    /// Renders the polynomial as `GF(p)['x']([c0, c1, ...])`, or `0` for the
+    /// zero polynomial.
+    pub fn to_sage_string(&self) -> String {
+        match self.coefficients().first() {
+            None => "0".to_string(),
+            Some(c) => {
+                let coeffs: Vec<String> = self.coefficients().iter().map(|c| c.get_num().to_string()).collect();
+                format!("GF({})['x']([{}])", c.get_order(), coeffs.join(", "))
+            }
+        }
+    }
+
+    /// Renders the polynomial as `Pol([Mod(c_d, p), ..., Mod(c0, p)])`, with
+    /// the leading coefficient first as PARI expects.
+    pub fn to_pari_string(&self) -> String {
+        let coeffs: Vec<String> = self.coefficients().iter().rev().map(|c| c.to_pari_string()).collect();
+        format!("Pol([{}])", coeffs.join(", "))
+    }
+
+    /// Parses the output of either [`Polynomial::to_sage_string`] or
+    /// [`Polynomial::to_pari_string`].
+    pub fn from_sage_string(s: &str) -> Result<Self, FieldError> {
+        let s = s.trim();
+        if s == "0" {
+            return Ok(Polynomial::new(Vec::new()));
+        }
+        if let Some(rest) = s.strip_prefix("GF(") {
+            let (p, rest) = rest.split_once(')').ok_or(FieldError::Parse)?;
+            let list = rest.trim_start()
+                .strip_prefix('[')
+                .and_then(|r| r.split_once(']'))
+                .and_then(|(_, r)| r.trim().strip_prefix("(["))
+                .and_then(|r| r.strip_suffix("])"))
+                .ok_or(FieldError::Parse)?;
+            let coeffs = list.split(',')
+                .filter(|c| !c.trim().is_empty())
+                .map(|c| parse_element(c, p))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(Polynomial::new(coeffs))
+        } else if let Some(rest) = s.strip_prefix("Pol([") {
+            let list = rest.strip_suffix("])").ok_or(FieldError::Parse)?;
+            let mut coeffs = split_mods(list)?
+                .into_iter()
+                .map(FieldElement::from_sage_string)
+                .collect::<Result<Vec<_>, _>>()?;
+            coeffs.reverse();
+            Ok(Polynomial::new(coeffs))
+        } else {
+            Err(FieldError::Parse)
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn element_strings_work() {
+        let a: FieldElement<u32> = FieldElement::new(14, 17);
+        assert_eq!(a.to_sage_string(), "GF(17)(14)");
+        assert_eq!(a.to_pari_string(), "Mod(14, 17)");
+        assert_eq!(FieldElement::from_sage_string("GF(17)(14)"), Ok(a));
+        assert_eq!(FieldElement::from_sage_string(" Mod(14,17) "), Ok(a));
+        assert_eq!(FieldElement::from_sage_string("GF(17)(-3)"), Ok(a));
+        assert_eq!(FieldElement::<u32>::from_sage_string("GF(17)"), Err(FieldError::Parse));
+        assert_eq!(FieldElement::<u32>::from_sage_string("Mod(x, 17)"), Err(FieldError::Parse));
+        assert_eq!(FieldElement::<u32>::from_sage_string("Mod(1, 0)"), Err(FieldError::ZeroModulus));
+        assert_eq!(FieldElement::<u32>::from_sage_string("GF(1)(0)"), Err(FieldError::NonPrimeModulus));
+        let q = u128::MAX - 158;
+        assert_eq!(FieldElement::from_sage_string(&format!("Mod(-1, {})", q)), Ok(FieldElement::new(q - 1, q)));
+    }
+
+    #[test]
+    fn polynomial_strings_work() {
+        let coeffs = [3u32, 0, 5].iter().map(|&n| FieldElement::new(n, 7)).collect();
+        let p = Polynomial::new(coeffs);
+        assert_eq!(p.to_sage_string(), "GF(7)['x']([3, 0, 5])");
+        assert_eq!(p.to_pari_string(), "Pol([Mod(5, 7), Mod(0, 7), Mod(3, 7)])");
+        assert_eq!(Polynomial::from_sage_string(&p.to_sage_string()), Ok(p.clone()));
+        assert_eq!(Polynomial::from_sage_string(&p.to_pari_string()), Ok(p));
+
+        let zero: Polynomial<FieldElement<u32>> = Polynomial::new(Vec::new());
+        assert_eq!(Polynomial::from_sage_string(&zero.to_sage_string()), Ok(zero));
+        assert_eq!(Polynomial::<FieldElement<u32>>::from_sage_string("GF(7)['x'](3"), Err(FieldError::Parse));
+        assert_eq!(Polynomial::<FieldElement<u32>>::from_sage_string("Pol([Mod(1, 0)])"), Err(FieldError::ZeroModulus));
+    }
+}
@@ -0,0 +1,379 @@
+//! Point counting on short Weierstrass curves `y² = x³ + ax + b` over prime
+//! fields of characteristic greater than 3, by Schoof's algorithm.
+//!
+//! The trace of Frobenius `t` is recovered modulo small primes `l` from
+//! the action of Frobenius on the `l`-torsion, and the residues are combined
+//! by the CRT until they pin `t` down inside the Hasse interval. Each prime
+//! is handled independently by `trace_mod_l`, which is the step an
+//! Elkies–Atkin (SEA) variant would replace for the larger primes.
+
+use std::{fmt::Debug};
+use primes::is_prime;
+use crate::{FieldElement, Widening};
+
+type Poly<T> = Vec<FieldElement<T>>;
+
+fn trim<T: Widening>(mut a: Poly<T>) -> Poly<T> {
+    while a.last().is_some_and(|c| c.get_num().is_zero()) {
+        a.pop();
+    }
+    a
+}
+
+fn add<T>(a: &[FieldElement<T>], b: &[FieldElement<T>], zero: FieldElement<T>) -> Poly<T>
+    where T: Widening + Debug,
+{
+    let n = a.len().max(b.len());
+    trim((0..n).map(|i| *a.get(i).unwrap_or(&zero) + *b.get(i).unwrap_or(&zero)).collect())
+}
+
+fn sub<T>(a: &[FieldElement<T>], b: &[FieldElement<T>], zero: FieldElement<T>) -> Poly<T>
+    where T: Widening + Debug,
+{
+    let n = a.len().max(b.len());
+    trim((0..n).map(|i| *a.get(i).unwrap_or(&zero) - *b.get(i).unwrap_or(&zero)).collect())
+}
+
+fn mul<T>(a: &[FieldElement<T>], b: &[FieldElement<T>], zero: FieldElement<T>) -> Poly<T>
+    where T: Widening + Debug,
+{
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut prod = vec![zero; a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            prod[i + j] = prod[i + j] + x * y;
+        }
+    }
+    trim(prod)
+}
+
+fn scale<T>(a: &[FieldElement<T>], c: FieldElement<T>) -> Poly<T>
+    where T: Widening + Debug,
+{
+    trim(a.iter().map(|&x| x * c).collect())
+}
+
+fn div_rem<T>(a: &[FieldElement<T>], b: &[FieldElement<T>]) -> (Poly<T>, Poly<T>)
+    where T: Widening + Debug,
+{
+    let db = b.len() - 1;
+    let inv = b[db].one() / b[db];
+    let mut rem = a.to_vec();
+    let mut quot = vec![b[0].zero(); a.len().saturating_sub(db)];
+    for k in (db..rem.len()).rev() {
+        let c = rem[k] * inv;
+        quot[k - db] = c;
+        for i in 0..=db {
+            rem[k - db + i] = rem[k - db + i] - c * b[i];
+        }
+    }
+    rem.truncate(db);
+    (trim(quot), trim(rem))
+}
+
+fn monic<T>(a: &[FieldElement<T>]) -> Poly<T>
+    where T: Widening + Debug,
+{
+    let lead = a[a.len() - 1];
+    scale(a, lead.one() / lead)
+}
+
+fn gcd<T>(a: &[FieldElement<T>], b: &[FieldElement<T>]) -> Poly<T>
+    where T: Widening + Debug,
+{
+    let (mut a, mut b) = (a.to_vec(), b.to_vec());
+    while !b.is_empty() {
+        let r = div_rem(&a, &b).1;
+        a = b;
+        b = r;
+    }
+    monic(&a)
+}
+
+/// Inverts a non-zero `a` modulo `h`, or returns the proper factor of `h`
+/// that it shares.
+fn inverse_mod<T>(a: &[FieldElement<T>], h: &[FieldElement<T>]) -> Result<Poly<T>, Poly<T>>
+    where T: Widening + Debug,
+{
+    let zero = h[0].zero();
+    let (mut r0, mut r1) = (h.to_vec(), div_rem(a, h).1);
+    let (mut s0, mut s1) = (Vec::new(), vec![zero.one()]);
+    while !r1.is_empty() {
+        let (q, r) = div_rem(&r0, &r1);
+        let s = sub(&s0, &mul(&q, &s1, zero), zero);
+        (r0, r1) = (r1, r);
+        (s0, s1) = (s1, s);
+    }
+    if r0.len() == 1 {
+        Ok(div_rem(&scale(&s0, r0[0].one() / r0[0]), h).1)
+    } else {
+        Err(monic(&r0))
+    }
+}
+
+/// Returns the division polynomial `ψ_l` for odd `l`, as a polynomial in `x`.
+fn division_polynomial<T>(l: usize, a: FieldElement<T>, b: FieldElement<T>) -> Poly<T>
+    where T: Widening + Debug,
+{
+    let order = a.get_order();
+    let zero = a.zero();
+    let c = |n: i128| FieldElement::from_signed(n, order);
+    let f = trim(vec![b, a, zero, zero.one()]);
+    let f2 = mul(&f, &f, zero);
+    let cube = |p: &Poly<T>| mul(&mul(p, p, zero), p, zero);
+    let square = |p: &Poly<T>| mul(p, p, zero);
+
+    // ψ_n for odd n, and ψ_n / y for even n
+    let mut g: Vec<Poly<T>> = vec![
+        Vec::new(),
+        vec![c(1)],
+        trim(vec![c(2)]),
+        trim(vec![zero - a * a, c(12) * b, c(6) * a, zero, c(3)]),
+        scale(&trim(vec![zero - c(8) * b * b - a * a * a, zero - c(4) * a * b, zero - c(5) * a * a, c(20) * b, c(5) * a, zero, c(1)]), c(4)),
+    ];
+    for n in 5..=l {
+        let m = n / 2;
+        let next = if n % 2 == 1 {
+            let (left, right) = (mul(&g[m + 2], &cube(&g[m]), zero), mul(&g[m - 1], &cube(&g[m + 1]), zero));
+            if m % 2 == 0 {
+                sub(&mul(&f2, &left, zero), &right, zero)
+            } else {
+                sub(&left, &mul(&f2, &right, zero), zero)
+            }
+        } else {
+            let inner = sub(
+                &mul(&g[m + 2], &square(&g[m - 1]), zero),
+                &mul(&g[m - 2], &square(&g[m + 1]), zero),
+                zero,
+            );
+            scale(&mul(&g[m], &inner, zero), c(2).one() / c(2))
+        };
+        g.push(next);
+    }
+    g.swap_remove(l)
+}
+
+/// A point `(X(x), y·Y(x))` on the curve over `F_p[x, y] / (h(x), y² − f(x))`.
+#[derive(Debug, Clone, PartialEq)]
+enum Point<T> {
+    Infinity,
+    Affine(Poly<T>, Poly<T>),
+}
+
+/// Arithmetic in the torsion ring modulo a factor `h` of a division
+/// polynomial. Operations that hit a zero divisor return the factor of `h`
+/// they exposed, so that the caller can restart on the smaller ring.
+struct Torsion<T> {
+    h: Poly<T>,
+    f: Poly<T>,
+    a: FieldElement<T>,
+}
+
+impl<T> Torsion<T>
+    where T: Widening + Debug,
+{
+    fn zero(&self) -> FieldElement<T> {
+        self.a.zero()
+    }
+
+    fn reduce(&self, p: &[FieldElement<T>]) -> Poly<T> {
+        div_rem(p, &self.h).1
+    }
+
+    fn mul(&self, p: &[FieldElement<T>], q: &[FieldElement<T>]) -> Poly<T> {
+        self.reduce(&mul(p, q, self.zero()))
+    }
+
+    fn pow(&self, base: &[FieldElement<T>], mut exp: u128) -> Poly<T> {
+        let mut result = self.reduce(&[self.zero().one()]);
+        let mut base = self.reduce(base);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = self.mul(&result, &base);
+            }
+            base = self.mul(&base, &base);
+            exp >>= 1;
+        }
+        result
+    }
+
+    fn add_points(&self, p: &Point<T>, q: &Point<T>) -> Result<Point<T>, Poly<T>> {
+        let zero = self.zero();
+        let ((x1, y1), (x2, y2)) = match (p, q) {
+            (Point::Infinity, _) => return Ok(q.clone()),
+            (_, Point::Infinity) => return Ok(p.clone()),
+            (Point::Affine(x1, y1), Point::Affine(x2, y2)) => ((x1, y1), (x2, y2)),
+        };
+        let dx = sub(x1, x2, zero);
+        let (num, den) = if !dx.is_empty() {
+            (sub(y1, y2, zero), dx)
+        } else if add(y1, y2, zero).is_empty() {
+            return Ok(Point::Infinity);
+        } else if y1 == y2 {
+            // λ = (3x² + a) / 2y = y·(3x² + a) / (2·f·Y)
+            let three = FieldElement::from_signed(3, zero.get_order());
+            let num = add(&scale(&self.mul(x1, x1), three), &[self.a], zero);
+            let den = self.mul(&self.f, &scale(y1, three - three.one()));
+            if den.is_empty() {
+                return Ok(Point::Infinity);
+            }
+            (num, den)
+        } else {
+            // P = Q over part of the ring and P = −Q over the rest
+            return Err(gcd(&add(y1, y2, zero), &self.h));
+        };
+        let lambda = self.mul(&num, &inverse_mod(&den, &self.h)?);
+        let x3 = sub(&sub(&self.mul(&self.f, &self.mul(&lambda, &lambda)), x1, zero), x2, zero);
+        let y3 = sub(&self.mul(&lambda, &sub(x1, &x3, zero)), y1, zero);
+        Ok(Point::Affine(x3, y3))
+    }
+
+    fn scalar_mul(&self, mut k: usize, p: &Point<T>) -> Result<Point<T>, Poly<T>> {
+        let mut result = Point::Infinity;
+        let mut base = p.clone();
+        while k > 0 {
+            if k & 1 == 1 {
+                result = self.add_points(&result, &base)?;
+            }
+            base = self.add_points(&base, &base)?;
+            k >>= 1;
+        }
+        Ok(result)
+    }
+
+    /// Finds `τ` with `π²(P) + [p mod l](P) = [τ]π(P)` for the generic
+    /// point `P` of the ring.
+    fn frobenius_trace(&self, p: u64, l: usize) -> Result<usize, Poly<T>> {
+        let zero = self.zero();
+        let x = [zero, zero.one()];
+        let p = p as u128;
+        let generic = Point::Affine(self.reduce(&x), self.reduce(&[zero.one()]));
+        let frob = Point::Affine(self.pow(&x, p), self.pow(&self.f, (p - 1) / 2));
+        let frob2 = Point::Affine(self.pow(&x, p * p), self.pow(&self.f, (p * p - 1) / 2));
+
+        let q = self.scalar_mul((p % l as u128) as usize, &generic)?;
+        let lhs = self.add_points(&frob2, &q)?;
+        let mut multiple = Point::Infinity;
+        for tau in 0..l {
+            if multiple == lhs {
+                return Ok(tau);
+            }
+            multiple = self.add_points(&multiple, &frob)?;
+        }
+        unreachable!("Frobenius must satisfy its characteristic equation")
+    }
+}
+
+fn trace_mod_l<T>(l: usize, a: FieldElement<T>, b: FieldElement<T>) -> usize
+    where T: Widening + Debug,
+{
+    let zero = a.zero();
+    let p = a.get_order().to_u64().expect("order does not fit in u64");
+    let mut ring = Torsion {
+        h: division_polynomial(l, a, b),
+        f: trim(vec![b, a, zero, zero.one()]),
+        a,
+    };
+    loop {
+        match ring.frobenius_trace(p, l) {
+            Ok(tau) => return tau,
+            Err(factor) => {
+                let cofactor = div_rem(&ring.h, &factor).0;
+                ring.h = if factor.len() <= cofactor.len() { factor } else { monic(&cofactor) };
+            }
+        }
+    }
+}
+
+/// Returns the trace of Frobenius `t = p + 1 − #E(F_p)` of the curve
+/// `y² = x³ + ax + b`.
+pub fn frobenius_trace<T>(a: FieldElement<T>, b: FieldElement<T>) -> i64
+    where T: Widening + Debug,
+{
+    assert_eq!(a.get_order(), b.get_order());
+    let zero = a.zero();
+    let p = a.get_order().to_u64().expect("order does not fit in u64");
+    assert!(p > 3, "Short Weierstrass form needs characteristic greater than 3.");
+    let discriminant = FieldElement::from_signed(4, a.get_order()) * a * a * a
+        + FieldElement::from_signed(27, a.get_order()) * b * b;
+    assert!(discriminant != zero, "Curve is singular.");
+
+    // t is even exactly when the cubic has a root, i.e. a point of order 2
+    let f = trim(vec![b, a, zero, zero.one()]);
+    let x = Torsion { h: f.clone(), f: f.clone(), a };
+    let xp = sub(&x.pow(&[zero, zero.one()], p as u128), &[zero, zero.one()], zero);
+    let mut t = if xp.is_empty() || gcd(&xp, &f).len() > 1 { 0 } else { 1 };
+    let mut modulus: u128 = 2;
+
+    let mut l = 3;
+    while modulus * modulus <= 16 * p as u128 {
+        if is_prime(l) && l != p {
+            let tau = trace_mod_l(l as usize, a, b) as u128;
+            let l = l as u128;
+            let inv = (1..l).find(|k| modulus * k % l == 1).unwrap();
+            let k = (tau + l - t % l) % l * inv % l;
+            t += modulus * k;
+            modulus *= l;
+        }
+        l += 2;
+    }
+    if t > modulus / 2 {
+        t as i64 - modulus as i64
+    } else {
+        t as i64
+    }
+}
+
+/// Returns the number of points `#E(F_p)` on `y² = x³ + ax + b`, including
+/// the point at infinity.
+pub fn count_points<T>(a: FieldElement<T>, b: FieldElement<T>) -> u64
+    where T: Widening + Debug,
+{
+    let p = a.get_order().to_u64().expect("order does not fit in u64");
+    (p as i64 + 1 - frobenius_trace(a, b)) as u64
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive_count(a: u64, b: u64, p: u64) -> u64 {
+        let e = |n: u64| FieldElement::new(n, p);
+        let mut count = 1;
+        for x in 0..p {
+            let rhs = e(x) * e(x) * e(x) + e(a) * e(x) + e(b);
+            count += match rhs.get_num() {
+                0 => 1,
+                _ if rhs.pow((p - 1) / 2).get_num() == 1 => 2,
+                _ => 0,
+            };
+        }
+        count
+    }
+
+    #[test]
+    fn small_fields_match_naive_count() {
+        for p in [5u64, 7, 11, 13, 101, 1009] {
+            for (a, b) in [(1, 1), (0, 3), (2, 0), (4, 6), (p - 1, 1)] {
+                let (a, b) = (a % p, b % p);
+                if (4 * a * a * a + 27 * b * b) % p == 0 {
+                    continue;
+                }
+                let count = count_points(FieldElement::new(a, p), FieldElement::new(b, p));
+                assert_eq!(count, naive_count(a, b, p), "a = {}, b = {}, p = {}", a, b, p);
+            }
+        }
+    }
+
+    #[test]
+    fn larger_field_works() {
+        let p = 100003u64;
+        let (a, b) = (FieldElement::new(7, p), FieldElement::new(12, p));
+        let t = frobenius_trace(a, b);
+        assert!(t * t <= 4 * p as i64);
+        assert_eq!(count_points(a, b), naive_count(7, 12, p));
+    }
+}
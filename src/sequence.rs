@@ -0,0 +1,195 @@
+//! Primitive polynomials over small prime fields, and the maximal-length
+//! sequences and Gold codes generated from them.
+
+use std::{fmt::Debug};
+use num::NumCast;
+use primes::factors_uniq;
+use crate::{FieldElement, Polynomial, Widening};
+use crate::lfsr::Lfsr;
+
+pub(crate) fn mul_mod<T>(a: &[FieldElement<T>], b: &[FieldElement<T>], f: &[FieldElement<T>]) -> Vec<FieldElement<T>>
+    where T: Widening + Debug,
+{
+    let zero = f[0].zero();
+    let m = f.len() - 1;
+    let mut prod = vec![zero; a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            prod[i + j] = prod[i + j] + x * y;
+        }
+    }
+    // f is monic, so the leading term can be cancelled without division
+    for k in (m..prod.len()).rev() {
+        let lead = prod[k];
+        for i in 0..m {
+            prod[k - m + i] = prod[k - m + i] - lead * f[i];
+        }
+    }
+    prod.truncate(m);
+    prod
+}
+
+fn x_pow_mod<T>(mut exp: u64, f: &[FieldElement<T>]) -> Vec<FieldElement<T>>
+    where T: Widening + Debug,
+{
+    let m = f.len() - 1;
+    let zero = f[0].zero();
+    let mut result = vec![zero; m];
+    result[0] = zero.one();
+    let mut base = vec![zero; m.max(2)];
+    base[1] = zero.one();
+    let mut base = mul_mod(&base, &[zero.one()], f);
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(&result, &base, f);
+        }
+        base = mul_mod(&base, &base, f);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Tests whether `poly` is primitive, i.e. monic with `x` generating the
+/// multiplicative group of `GF(p)[x]/(poly)`.
+pub fn is_primitive<T>(poly: &Polynomial<FieldElement<T>>) -> bool
+    where T: Widening + Debug,
+{
+    let f = poly.coefficients();
+    let m = match poly.degree() {
+        Some(m) if m > 0 => m,
+        _ => return false,
+    };
+    if f[m] != f[m].one() || f[0] == f[0].zero() {
+        return false;
+    }
+    let p = f[0].get_order().to_u64().unwrap();
+    let group_order = match p.checked_pow(m as u32) {
+        Some(q) => q - 1,
+        None => return false,
+    };
+
+    let mut one = vec![f[0].zero(); m];
+    one[0] = f[0].one();
+    x_pow_mod(group_order, f) == one
+        && factors_uniq(group_order).iter().all(|q| x_pow_mod(group_order / q, f) != one)
+}
+
+/// Iterates over all primitive polynomials of the given degree over the
+/// prime field of order `order`.
+pub fn primitive_polynomials<T>(degree: usize, order: T) -> impl Iterator<Item = Polynomial<FieldElement<T>>>
+    where T: Widening + Debug,
+{
+    let p = order.to_u64().unwrap();
+    let count = p.checked_pow(degree as u32).expect("search space is too large");
+    (0..count).filter_map(move |k| {
+        let mut rest = k;
+        let mut coeffs = Vec::with_capacity(degree + 1);
+        for _ in 0..degree {
+            coeffs.push(FieldElement::new(NumCast::from(rest % p).unwrap(), order));
+            rest /= p;
+        }
+        coeffs.push(FieldElement::new(T::one(), order));
+        let poly = Polynomial::new(coeffs);
+        if is_primitive(&poly) {
+            Some(poly)
+        } else {
+            None
+        }
+    })
+}
+
+/// Returns the first primitive polynomial of the given degree.
+pub fn find_primitive_polynomial<T>(degree: usize, order: T) -> Option<Polynomial<FieldElement<T>>>
+    where T: Widening + Debug,
+{
+    primitive_polynomials(degree, order).next()
+}
+
+/// Returns one period of the maximal-length sequence generated by the
+/// monic polynomial `poly`, seeded with `1, 0, ..., 0`.
+pub fn m_sequence<T>(poly: &Polynomial<FieldElement<T>>) -> Vec<FieldElement<T>>
+    where T: Widening + Debug,
+{
+    let f = poly.coefficients();
+    let m = poly.degree().expect("polynomial must not be zero");
+    let zero = f[0].zero();
+    let taps = (1..=m).map(|i| zero - f[m - i]).collect();
+    let mut seed = vec![zero; m];
+    seed[0] = zero.one();
+    let lfsr = Lfsr::new(taps, seed);
+    let period = lfsr.max_period().expect("period does not fit in u64");
+    lfsr.take(period as usize).collect()
+}
+
+/// Builds the Gold code family `{u, v, u + shift_k(v)}` from two primitive
+/// polynomials of the same degree, which should form a preferred pair.
+pub fn gold_codes<T>(a: &Polynomial<FieldElement<T>>, b: &Polynomial<FieldElement<T>>) -> Vec<Vec<FieldElement<T>>>
+    where T: Widening + Debug,
+{
+    assert_eq!(a.degree(), b.degree(), "Polynomials must have the same degree.");
+    let u = m_sequence(a);
+    let v = m_sequence(b);
+    let n = u.len();
+
+    let mut codes = Vec::with_capacity(n + 2);
+    for k in 0..n {
+        codes.push((0..n).map(|i| u[i] + v[(i + k) % n]).collect());
+    }
+    codes.push(u);
+    codes.push(v);
+    codes
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn poly(nums: &[u8], order: u8) -> Polynomial<FieldElement<u8>> {
+        Polynomial::new(nums.iter().map(|&n| FieldElement::new(n, order)).collect())
+    }
+
+    fn correlation(a: &[FieldElement<u8>], b: &[FieldElement<u8>], shift: usize) -> i32 {
+        let n = a.len();
+        (0..n).map(|i| if a[i] == b[(i + shift) % n] { 1 } else { -1 }).sum()
+    }
+
+    #[test]
+    fn is_primitive_works() {
+        assert!(is_primitive(&poly(&[1, 1, 0, 0, 1], 2)));
+        assert!(is_primitive(&poly(&[1, 0, 0, 1, 1], 2)));
+        assert!(!is_primitive(&poly(&[1, 1, 1, 1, 1], 2)));
+        assert!(!is_primitive(&poly(&[1, 0, 0, 0, 1], 2)));
+    }
+
+    #[test]
+    fn primitive_search_works() {
+        assert_eq!(find_primitive_polynomial(4, 2u8), Some(poly(&[1, 1, 0, 0, 1], 2)));
+        assert_eq!(primitive_polynomials(4, 2u8).count(), 2);
+        assert_eq!(primitive_polynomials(5, 2u8).count(), 6);
+        let over_f3: Vec<_> = primitive_polynomials(2, 3u8).collect();
+        assert_eq!(over_f3, vec![poly(&[2, 1, 1], 3), poly(&[2, 2, 1], 3)]);
+    }
+
+    #[test]
+    fn m_sequence_works() {
+        let seq = m_sequence(&poly(&[1, 0, 1, 0, 0, 1], 2));
+        assert_eq!(seq.len(), 31);
+        assert_eq!(seq.iter().filter(|e| e.get_num() == 1).count(), 16);
+        for shift in 1..31 {
+            assert_eq!(correlation(&seq, &seq, shift), -1);
+        }
+    }
+
+    #[test]
+    fn gold_codes_work() {
+        let a = poly(&[1, 0, 1, 0, 0, 1], 2);
+        let b = poly(&[1, 0, 1, 1, 1, 1], 2);
+        let codes = gold_codes(&a, &b);
+        assert_eq!(codes.len(), 33);
+        for shift in 0..31 {
+            let c = correlation(&codes[31], &codes[32], shift);
+            assert!([-1, -9, 7].contains(&c));
+        }
+    }
+}
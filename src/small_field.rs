@@ -0,0 +1,281 @@
+use std::{fmt, ops::{Add, Div, Mul, Neg, Sub}};
+use crate::{FieldElement, FieldError, FiniteField};
+use crate::ntt::generator;
+
+/// A prime field of order below `2¹⁶` with discrete log and exponential
+/// tables for a generator `g`, so that multiplication and division are two
+/// lookups and an addition of logarithms.
+///
+/// `exp` holds `g^i` for `0 ≤ i < 2(p − 1)`, long enough that a sum of two
+/// logarithms never needs reducing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SmallField {
+    order: u16,
+    log: Vec<u16>,
+    exp: Vec<u16>,
+}
+
+/// An element of a [`SmallField`], borrowing its tables.
+#[derive(Clone, Copy)]
+pub struct SmallElement<'a> {
+    n: u16,
+    field: &'a SmallField,
+}
+
+impl SmallField {
+    /// Builds the tables for a prime order, which takes `O(p)` time and
+    /// `6p` bytes.
+    pub fn new(order: u16) -> Result<Self, FieldError> {
+        if order == 0 {
+            return Err(FieldError::ZeroModulus);
+        }
+        if !primes::is_prime(order as u64) {
+            return Err(FieldError::NonPrimeModulus);
+        }
+        let g = generator(order);
+        let mut log = vec![0; order as usize];
+        let group = order as usize - 1;
+        let mut exp = Vec::with_capacity(2 * group);
+        let mut x = g.one();
+        for i in 0..2 * group {
+            if i < group {
+                log[x.get_num() as usize] = i as u16;
+            }
+            exp.push(x.get_num());
+            x = x * g;
+        }
+        Ok(SmallField { order, log, exp })
+    }
+
+    pub fn order(&self) -> u16 {
+        self.order
+    }
+
+    /// Returns the generator the tables are built on.
+    pub fn generator(&self) -> SmallElement<'_> {
+        self.wrap(self.exp[1 % self.exp.len()])
+    }
+
+    /// Reduces `n` and pairs it with a borrow of the tables.
+    pub fn element(&self, n: u16) -> SmallElement<'_> {
+        self.wrap(n % self.order)
+    }
+
+    pub fn zero(&self) -> SmallElement<'_> {
+        self.wrap(0)
+    }
+
+    pub fn one(&self) -> SmallElement<'_> {
+        self.element(1)
+    }
+
+    /// Borrows the tables for an existing element, failing if its order
+    /// differs.
+    pub fn import(&self, e: &FieldElement<u16>) -> Result<SmallElement<'_>, FieldError> {
+        if e.get_order() != self.order {
+            return Err(FieldError::MismatchedOrders);
+        }
+        Ok(self.wrap(e.get_num()))
+    }
+
+    fn wrap(&self, n: u16) -> SmallElement<'_> {
+        SmallElement { n, field: self }
+    }
+
+    /// Looks up `g^(a + b)` for two logarithms below `p − 1`.
+    fn exp_sum(&self, a: u16, b: u16) -> SmallElement<'_> {
+        self.wrap(self.exp[a as usize + b as usize])
+    }
+}
+
+impl<'a> SmallElement<'a> {
+    pub fn get_num(&self) -> u16 {
+        self.n
+    }
+
+    pub fn get_order(&self) -> u16 {
+        self.field.order
+    }
+
+    pub fn field(&self) -> &'a SmallField {
+        self.field
+    }
+
+    /// Returns the discrete logarithm to the field's generator, or `None`
+    /// for zero.
+    pub fn log(&self) -> Option<u16> {
+        if self.n == 0 {
+            None
+        } else {
+            Some(self.field.log[self.n as usize])
+        }
+    }
+
+    pub fn pow(self, exp: u64) -> Self {
+        match self.log() {
+            None if exp == 0 => self.field.one(),
+            None => self,
+            Some(log) => {
+                let group = self.get_order() as u64 - 1;
+                self.field.wrap(self.field.exp[(log as u64 * (exp % group) % group) as usize])
+            }
+        }
+    }
+
+    /// Returns `g^(p − 1 − log)`, or `None` for zero.
+    pub fn inverse(&self) -> Option<Self> {
+        let log = self.log()?;
+        Some(self.field.wrap(self.field.exp[(self.get_order() - 1 - log) as usize]))
+    }
+
+    pub fn to_field_element(&self) -> FieldElement<u16> {
+        FieldElement::new(self.n, self.get_order())
+    }
+
+    fn check_field(&self, rhs: &Self) {
+        assert!(std::ptr::eq(self.field, rhs.field) || self.field.order == rhs.field.order);
+    }
+}
+
+/// Elements are equal when their fields have the same order, without
+/// comparing the tables.
+impl PartialEq for SmallElement<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.n == other.n && self.get_order() == other.get_order()
+    }
+}
+
+impl Eq for SmallElement<'_> {}
+
+impl fmt::Debug for SmallElement<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SmallElement")
+            .field("n", &self.n)
+            .field("order", &self.get_order())
+            .finish()
+    }
+}
+
+impl Add for SmallElement<'_> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.check_field(&rhs);
+        let p = self.get_order();
+        let n = if p - self.n > rhs.n { self.n + rhs.n } else { rhs.n - (p - self.n) };
+        self.field.wrap(n)
+    }
+}
+
+impl Sub for SmallElement<'_> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.check_field(&rhs);
+        let n = if self.n >= rhs.n { self.n - rhs.n } else { self.get_order() - (rhs.n - self.n) };
+        self.field.wrap(n)
+    }
+}
+
+impl Mul for SmallElement<'_> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.check_field(&rhs);
+        match (self.log(), rhs.log()) {
+            (Some(a), Some(b)) => self.field.exp_sum(a, b),
+            _ => self.field.zero(),
+        }
+    }
+}
+
+impl Div for SmallElement<'_> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        self.check_field(&rhs);
+        let b = rhs.log().expect("Zero is not valid denominator.");
+        match self.log() {
+            Some(a) => self.field.exp_sum(a, self.get_order() - 1 - b),
+            None => self,
+        }
+    }
+}
+
+impl Neg for SmallElement<'_> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.field.zero() - self
+    }
+}
+
+impl FiniteField for SmallElement<'_> {
+    fn zero(&self) -> Self {
+        self.field.zero()
+    }
+
+    fn one(&self) -> Self {
+        self.field.one()
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        SmallElement::inverse(self)
+    }
+
+    fn from_u64(&self, n: u64) -> Self {
+        self.field.wrap((n % self.get_order() as u64) as u16)
+    }
+}
+
+impl fmt::Display for SmallElement<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (mod {})", self.n, self.get_order())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic_matches_field_element() {
+        for p in [2u16, 3, 251, 65521] {
+            let field = SmallField::new(p).unwrap();
+            let xs = [0, 1, 2, p / 2, p - 1];
+            for &a in &xs {
+                for &b in &xs {
+                    let (sa, sb) = (field.element(a), field.element(b));
+                    let (fa, fb) = (FieldElement::new(a % p, p), FieldElement::new(b % p, p));
+                    assert_eq!((sa * sb).to_field_element(), fa * fb);
+                    assert_eq!((sa + sb).to_field_element(), fa + fb);
+                    assert_eq!((sa - sb).to_field_element(), fa - fb);
+                    if b % p != 0 {
+                        assert_eq!((sa / sb).to_field_element(), fa / fb);
+                    }
+                }
+            }
+            let x = field.element(p - 1);
+            assert_eq!(x.pow(p as u64 - 1), field.one());
+            assert_eq!(field.zero().pow(0), field.one());
+            assert_eq!(field.import(&x.to_field_element()), Ok(x));
+        }
+    }
+
+    #[test]
+    fn tables_work() {
+        let field = SmallField::new(17).unwrap();
+        let g = field.generator();
+        assert_eq!(g.log(), Some(1));
+        assert_eq!(field.zero().log(), None);
+        assert_eq!((1..17).map(|n| field.element(n).log().unwrap()).max(), Some(15));
+        let x = field.element(5);
+        assert_eq!(x * x.inverse().unwrap(), field.one());
+        assert_eq!(field.zero().inverse(), None);
+        assert_eq!(x.to_string(), "5 (mod 17)");
+        assert_eq!(SmallField::new(0), Err(FieldError::ZeroModulus));
+        assert_eq!(SmallField::new(15), Err(FieldError::NonPrimeModulus));
+        assert_eq!(field.import(&FieldElement::new(1, 7)), Err(FieldError::MismatchedOrders));
+    }
+}
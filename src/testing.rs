@@ -0,0 +1,32 @@
+use std::ops::Neg;
+use crate::{ExtensionField, FieldElement, FiniteField, Polynomial};
+
+/// Checks a backend's arithmetic on `x` and `y` against a reference
+/// implementation, with `lift` converting the backend's elements to it.
+/// `y` must be nonzero.
+pub(crate) fn check_arithmetic<E, R>(x: E, y: E, lift: impl Fn(E) -> R)
+    where E: FiniteField + Neg<Output = E>, R: FiniteField,
+{
+    let (rx, ry) = (lift(x), lift(y));
+    assert_eq!(lift(x + y), rx + ry);
+    assert_eq!(lift(x - y), rx - ry);
+    assert_eq!(lift(x * y), rx * ry);
+    assert_eq!(lift(x / y), rx / ry);
+    assert_eq!(lift(-y), ry.zero() - ry);
+    assert_eq!(y * y.inverse().unwrap(), y.one());
+    assert_eq!(y.zero().inverse(), None);
+}
+
+/// The polynomial over `GF(p)` with coefficients `nums`, lowest first.
+pub(crate) fn modulus(nums: &[u32], p: u32) -> Polynomial<FieldElement<u32>> {
+    Polynomial::new(nums.iter().map(|&n| FieldElement::new(n, p)).collect())
+}
+
+/// Every element of `GF(p)[x]/(f)`, ordered by reading the coefficients
+/// as the base-`p` digits of `0, 1, …, p^N − 1`.
+pub(crate) fn elements<const N: usize>(f: &Polynomial<FieldElement<u32>>) -> Vec<ExtensionField<FieldElement<u32>, N>> {
+    let p = f.coefficients()[0].get_order();
+    (0..p.pow(N as u32)).map(|k| {
+        ExtensionField::new(std::array::from_fn(|i| FieldElement::new(k / p.pow(i as u32) % p, p)), f)
+    }).collect()
+}
@@ -0,0 +1,359 @@
+//! The 2-3-2 tower `Fp → Fp2 → Fp6 → Fp12` of BN and BLS pairings.
+//!
+//! Each level is a generic extension of the one below, so the tower is
+//! composed rather than hard-coded: [`Fp3`] adjoins a cube root `v` of a
+//! non-residue `ξ`, `Fp6 = Fp3<Fp2>`, and `Fp12 = Fp2<Fp6>` adjoins `w`
+//! with `w² = v`, i.e. `w⁶ = ξ`.
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use crate::{ExtensionField, FiniteField, Fp2, Polynomial};
+
+/// An element `c₀ + c₁v + c₂v²` of the cubic extension `F(v)`, `v³ = ξ`,
+/// for a non-cube `ξ` of the base field, carried by each element like the
+/// non-residue of [`Fp2`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fp3<F> {
+    c0: F,
+    c1: F,
+    c2: F,
+    non_residue: F,
+}
+
+/// The sextic extension `Fp2(v)`, `v³ = ξ`.
+pub type Fp6<F> = Fp3<Fp2<F>>;
+
+/// The degree twelve extension `Fp6(w)`, `w² = v`.
+pub type Fp12<F> = Fp2<Fp6<F>>;
+
+impl<F: FiniteField> Fp3<F> {
+    pub fn new(c0: F, c1: F, c2: F, non_residue: F) -> Self {
+        Fp3 { c0, c1, c2, non_residue }
+    }
+
+    /// Embeds a base field element as a constant.
+    pub fn from_base(&self, c: F) -> Self {
+        Fp3 { c0: c, c1: c.zero(), c2: c.zero(), non_residue: self.non_residue }
+    }
+
+    pub fn c0(&self) -> F {
+        self.c0
+    }
+
+    pub fn c1(&self) -> F {
+        self.c1
+    }
+
+    pub fn c2(&self) -> F {
+        self.c2
+    }
+
+    pub fn non_residue(&self) -> F {
+        self.non_residue
+    }
+
+    pub fn zero(self) -> Self {
+        self.from_base(self.c0.zero())
+    }
+
+    pub fn one(self) -> Self {
+        self.from_base(self.c0.one())
+    }
+
+    /// Returns `v`.
+    pub fn v(self) -> Self {
+        Fp3 { c1: self.c0.one(), ..self.zero() }
+    }
+
+    /// Multiplies every coefficient by a base field element.
+    pub fn mul_by_base(self, c: F) -> Self {
+        Fp3 { c0: self.c0 * c, c1: self.c1 * c, c2: self.c2 * c, non_residue: self.non_residue }
+    }
+
+    /// Multiplies by `v`, which only rotates the coefficients.
+    pub fn mul_by_nonresidue(self) -> Self {
+        Fp3 { c0: self.c2 * self.non_residue, c1: self.c0, c2: self.c1, non_residue: self.non_residue }
+    }
+
+    /// Multiplies by the sparse `b₁v`.
+    pub fn mul_by_1(self, b1: F) -> Self {
+        Fp3 { c0: self.c2 * b1 * self.non_residue, c1: self.c0 * b1, c2: self.c1 * b1, non_residue: self.non_residue }
+    }
+
+    /// Multiplies by the sparse `b₀ + b₁v` with five base multiplications.
+    pub fn mul_by_01(self, b0: F, b1: F) -> Self {
+        let v0 = self.c0 * b0;
+        let v1 = self.c1 * b1;
+        Fp3 {
+            c0: ((self.c1 + self.c2) * b1 - v1) * self.non_residue + v0,
+            c1: (self.c0 + self.c1) * (b0 + b1) - v0 - v1,
+            c2: (self.c0 + self.c2) * b0 - v0 + v1,
+            non_residue: self.non_residue,
+        }
+    }
+
+    pub fn pow(self, mut exp: u64) -> Self {
+        let mut result = self.one();
+        let mut base = self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base;
+            }
+            base = base.square();
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// Squares by Chung and Hasan's SQR2, with two multiplications and
+    /// three squarings in the base field.
+    pub fn square(self) -> Self {
+        let (a0, a1, a2, xi) = (self.c0, self.c1, self.c2, self.non_residue);
+        let s0 = a0 * a0;
+        let ab = a0 * a1;
+        let s1 = ab + ab;
+        let t = a0 - a1 + a2;
+        let s2 = t * t;
+        let bc = a1 * a2;
+        let s3 = bc + bc;
+        let s4 = a2 * a2;
+        Fp3 { c0: s0 + xi * s3, c1: s1 + xi * s4, c2: s1 + s2 + s3 - s0 - s4, non_residue: xi }
+    }
+
+    /// The adjugate `t₀ = a₀² − ξa₁a₂`, `t₁ = ξa₂² − a₀a₁`, `t₂ = a₁² − a₀a₂`,
+    /// the product of the two other conjugates, so `a·t` is the norm.
+    fn adjugate(&self) -> (F, F, F) {
+        let (a0, a1, a2, xi) = (self.c0, self.c1, self.c2, self.non_residue);
+        (a0 * a0 - xi * a1 * a2, xi * a2 * a2 - a0 * a1, a1 * a1 - a0 * a2)
+    }
+
+    /// Returns the norm to the base field, `a₀t₀ + ξ(a₂t₁ + a₁t₂)` with `t`
+    /// the adjugate.
+    pub fn norm(&self) -> F {
+        let (t0, t1, t2) = self.adjugate();
+        self.c0 * t0 + self.non_residue * (self.c2 * t1 + self.c1 * t2)
+    }
+
+    /// Returns the trace to the base field, `3c₀`.
+    pub fn trace(&self) -> F {
+        self.c0 + self.c0 + self.c0
+    }
+
+    /// Returns the adjugate over the norm, or `None` for zero.
+    pub fn inverse(&self) -> Option<Self> {
+        let (t0, t1, t2) = self.adjugate();
+        let d = self.norm().inverse()?;
+        Some(Fp3 { c0: t0 * d, c1: t1 * d, c2: t2 * d, non_residue: self.non_residue })
+    }
+}
+
+impl<F: FiniteField> Add for Fp3<F> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        assert_eq!(self.non_residue, rhs.non_residue);
+        Fp3 { c0: self.c0 + rhs.c0, c1: self.c1 + rhs.c1, c2: self.c2 + rhs.c2, non_residue: self.non_residue }
+    }
+}
+
+impl<F: FiniteField> Sub for Fp3<F> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        assert_eq!(self.non_residue, rhs.non_residue);
+        Fp3 { c0: self.c0 - rhs.c0, c1: self.c1 - rhs.c1, c2: self.c2 - rhs.c2, non_residue: self.non_residue }
+    }
+}
+
+impl<F: FiniteField> Mul for Fp3<F> {
+    type Output = Self;
+
+    /// Karatsuba with six base multiplications.
+    fn mul(self, rhs: Self) -> Self {
+        assert_eq!(self.non_residue, rhs.non_residue);
+        let (a0, a1, a2) = (self.c0, self.c1, self.c2);
+        let (b0, b1, b2) = (rhs.c0, rhs.c1, rhs.c2);
+        let v0 = a0 * b0;
+        let v1 = a1 * b1;
+        let v2 = a2 * b2;
+        let xi = self.non_residue;
+        Fp3 {
+            c0: v0 + xi * ((a1 + a2) * (b1 + b2) - v1 - v2),
+            c1: (a0 + a1) * (b0 + b1) - v0 - v1 + xi * v2,
+            c2: (a0 + a2) * (b0 + b2) - v0 - v2 + v1,
+            non_residue: xi,
+        }
+    }
+}
+
+impl<F: FiniteField> Div for Fp3<F> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Mul::mul(self, rhs.inverse().expect("Zero is not valid denominator."))
+    }
+}
+
+impl<F: FiniteField> Neg for Fp3<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.zero() - self
+    }
+}
+
+impl<F: FiniteField> FiniteField for Fp3<F> {
+    fn zero(&self) -> Self {
+        Fp3::zero(*self)
+    }
+
+    fn one(&self) -> Self {
+        Fp3::one(*self)
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        Fp3::inverse(self)
+    }
+
+    fn from_u64(&self, n: u64) -> Self {
+        self.from_base(self.c0.from_u64(n))
+    }
+}
+
+/// Views the element in the generic representation modulo `x³ − ξ`.
+impl<F: FiniteField> From<Fp3<F>> for ExtensionField<F, 3> {
+    fn from(e: Fp3<F>) -> Self {
+        let (zero, one) = (e.c0.zero(), e.c0.one());
+        let modulus = Polynomial::new(vec![zero - e.non_residue, zero, zero, one]);
+        ExtensionField::new([e.c0, e.c1, e.c2], &modulus)
+    }
+}
+
+impl<F: FiniteField> Fp12<F> {
+    /// Builds `c₀ + c₁w` with `w² = v`.
+    pub fn from_fp6(c0: Fp6<F>, c1: Fp6<F>) -> Self {
+        Fp2::new(c0, c1, c0.v())
+    }
+
+    /// Multiplies by a line evaluation with only the coefficients of `1`,
+    /// `v` and `vw` (indices 0, 1 and 4) set, as produced by M-type twists
+    /// like BLS12-381's.
+    pub fn mul_by_014(self, c0: Fp2<F>, c1: Fp2<F>, c4: Fp2<F>) -> Self {
+        let aa = self.c0().mul_by_01(c0, c1);
+        let bb = self.c1().mul_by_1(c4);
+        let e = (self.c0() + self.c1()).mul_by_01(c0, c1 + c4);
+        Self::from_fp6(bb.mul_by_nonresidue() + aa, e - aa - bb)
+    }
+
+    /// Multiplies by a line evaluation with only the coefficients of `1`,
+    /// `w` and `vw` (indices 0, 3 and 4) set, as produced by D-type twists
+    /// like BN254's.
+    pub fn mul_by_034(self, c0: Fp2<F>, c3: Fp2<F>, c4: Fp2<F>) -> Self {
+        let a = self.c0().mul_by_base(c0);
+        let b = self.c1().mul_by_01(c3, c4);
+        let e = (self.c0() + self.c1()).mul_by_01(c0 + c3, c4);
+        Self::from_fp6(b.mul_by_nonresidue() + a, e - a - b)
+    }
+
+    /// Squares an element of the cyclotomic subgroup, the norm-one elements
+    /// of order dividing `p⁴ − p² + 1` left after the easy part of a final
+    /// exponentiation, by Granger and Scott's method: three `Fp4` squarings
+    /// instead of a full `Fp12` one. The result is wrong for other elements.
+    pub fn cyclotomic_square(self) -> Self {
+        let xi = self.c0().non_residue();
+        let (z0, z4, z3) = (self.c0().c0(), self.c0().c1(), self.c0().c2());
+        let (z2, z1, z5) = (self.c1().c0(), self.c1().c1(), self.c1().c2());
+        // (a + by)² in Fp4 = Fp2(y), y² = ξ
+        let fp4_square = |a: Fp2<F>, b: Fp2<F>| {
+            let t = a * b;
+            ((a + b) * (b * xi + a) - t - t * xi, t + t)
+        };
+        let (t0, t1) = fp4_square(z0, z1);
+        let (t2, t3) = fp4_square(z2, z3);
+        let (t4, t5) = fp4_square(z4, z5);
+        let triple_minus_double = |t: Fp2<F>, z: Fp2<F>| (t - z) + (t - z) + t;
+        let triple_plus_double = |t: Fp2<F>, z: Fp2<F>| (t + z) + (t + z) + t;
+        let c0 = Fp3::new(triple_minus_double(t0, z0), triple_minus_double(t2, z4), triple_minus_double(t4, z3), xi);
+        let c1 = Fp3::new(triple_plus_double(t5 * xi, z2), triple_plus_double(t1, z1), triple_plus_double(t3, z5), xi);
+        Self::from_fp6(c0, c1)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FieldElement;
+
+    type F7 = FieldElement<u32>;
+
+    fn fp2(a: u32, b: u32) -> Fp2<F7> {
+        Fp2::new(F7::new(a, 7), F7::new(b, 7), F7::new(6, 7))
+    }
+
+    // ξ = 2 + u is neither a square nor a cube in GF(49)
+    fn fp6(c: [u32; 6]) -> Fp6<F7> {
+        Fp3::new(fp2(c[0], c[1]), fp2(c[2], c[3]), fp2(c[4], c[5]), fp2(2, 1))
+    }
+
+    fn fp12(c: [u32; 12]) -> Fp12<F7> {
+        Fp12::from_fp6(fp6(c[..6].try_into().unwrap()), fp6(c[6..].try_into().unwrap()))
+    }
+
+    #[test]
+    fn fp3_matches_extension_field() {
+        // 2 is not a cube mod 7
+        let beta = F7::new(2, 7);
+        let xs: Vec<_> = (0..343).map(|k| Fp3::new(F7::new(k % 7, 7), F7::new(k / 7 % 7, 7), F7::new(k / 49, 7), beta)).collect();
+        for &a in xs.iter().step_by(5) {
+            for &b in xs.iter().step_by(7) {
+                assert_eq!(ExtensionField::from(a * b), ExtensionField::from(a) * ExtensionField::from(b));
+            }
+            assert_eq!(a.square(), a * a);
+            if a != a.zero() {
+                assert_eq!(a * a.inverse().unwrap(), a.one());
+            }
+        }
+        let (b0, b1) = (F7::new(3, 7), F7::new(5, 7));
+        let a = xs[200];
+        assert_eq!(a.mul_by_01(b0, b1), a * Fp3::new(b0, b1, b0.zero(), beta));
+        assert_eq!(a.mul_by_1(b1), a * Fp3::new(b0.zero(), b1, b0.zero(), beta));
+        assert_eq!(a.mul_by_nonresidue(), a * a.v());
+        assert_eq!(a.norm(), ExtensionField::from(a).norm());
+        assert_eq!(a.trace(), ExtensionField::from(a).trace());
+    }
+
+    #[test]
+    fn tower_works() {
+        let f = fp12([1, 2, 3, 4, 5, 6, 0, 1, 2, 3, 4, 5]);
+        let w = f.u();
+        // w⁶ = ξ
+        assert_eq!(w.pow(6), f.from_base(f.c0().from_base(fp2(2, 1))));
+        assert_eq!(f * f.inverse().unwrap(), f.one());
+        assert_eq!(f.pow(7u64.pow(12) - 1), f.one());
+        // w ↦ −w is the Frobenius p⁶
+        assert_eq!(f.conjugate(), f.pow(7u64.pow(6)));
+    }
+
+    #[test]
+    fn sparse_mul_works() {
+        let f = fp12([1, 2, 3, 4, 5, 6, 0, 1, 2, 3, 4, 5]);
+        let (a, b, c) = (fp2(3, 1), fp2(4, 5), fp2(6, 2));
+        let z = fp2(0, 0);
+        let line_014 = Fp12::from_fp6(Fp3::new(a, b, z, fp2(2, 1)), Fp3::new(z, c, z, fp2(2, 1)));
+        assert_eq!(f.mul_by_014(a, b, c), f * line_014);
+        let line_034 = Fp12::from_fp6(Fp3::new(a, z, z, fp2(2, 1)), Fp3::new(b, c, z, fp2(2, 1)));
+        assert_eq!(f.mul_by_034(a, b, c), f * line_034);
+    }
+
+    #[test]
+    fn cyclotomic_square_works() {
+        let f = fp12([1, 2, 3, 4, 5, 6, 0, 1, 2, 3, 4, 5]);
+        // the easy part f^((p⁶ − 1)(p² + 1)) lands in the cyclotomic subgroup
+        let g = f.conjugate() / f;
+        let g = g.pow(49) * g;
+        assert_ne!(g, g.one());
+        assert_eq!(g.pow(7u64.pow(4) - 49 + 1), g.one());
+        assert_eq!(g.cyclotomic_square(), g.square());
+        assert_eq!(g.cyclotomic_square().cyclotomic_square(), g.pow(4));
+    }
+}
@@ -0,0 +1,140 @@
+//! Small helpers shared by verifier-side code.
+
+use std::{collections::HashMap, fmt::Debug, hash::Hash};
+use num::NumCast;
+use crate::{FieldElement, Widening};
+
+/// Iterator over `1, x, x², ...`, created by [`powers_of`].
+#[derive(Debug, Clone)]
+pub struct Powers<T> {
+    current: FieldElement<T>,
+    base: FieldElement<T>,
+    remaining: usize,
+}
+
+impl<T> Iterator for Powers<T>
+    where T: Widening,
+{
+    type Item = FieldElement<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let item = self.current;
+        self.current = self.current * self.base;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: Widening> ExactSizeIterator for Powers<T> {}
+
+/// Returns the first `n` powers `1, x, ..., x^(n-1)`.
+pub fn powers_of<T: Widening>(x: FieldElement<T>, n: usize) -> Powers<T> {
+    Powers {
+        current: x.one(),
+        base: x,
+        remaining: n,
+    }
+}
+
+/// Collects [`powers_of`] into a vector.
+pub fn powers_of_vec<T: Widening>(x: FieldElement<T>, n: usize) -> Vec<FieldElement<T>> {
+    powers_of(x, n).collect()
+}
+
+/// Evaluates `1 + x + ... + x^(n-1)` in closed form as `(xⁿ − 1)/(x − 1)`.
+pub fn geometric_sum<T>(x: FieldElement<T>, n: usize) -> FieldElement<T>
+    where T: Widening + Debug,
+{
+    let one = x.one();
+    let order = x.get_order();
+    let len: T = NumCast::from(n).expect("length does not fit in the field type");
+    if x == one {
+        return FieldElement::new(len % order, order);
+    }
+    if x == x.zero() {
+        return if n == 0 { x.zero() } else { one };
+    }
+    (x.pow(len) - one) / (x - one)
+}
+
+/// Solves `base^k = target` for the smallest `k ≥ 0` with the baby-step
+/// giant-step algorithm, in `O(√p)` time and memory.
+pub fn discrete_log<T>(base: FieldElement<T>, target: FieldElement<T>) -> Option<u64>
+    where T: Widening + Debug + Hash,
+{
+    assert_eq!(base.get_order(), target.get_order());
+    let zero = base.zero();
+    if base == zero {
+        return match target {
+            t if t == base.one() => Some(0),
+            t if t == zero => Some(1),
+            _ => None,
+        };
+    }
+    let group_order = base.get_order().to_u64().expect("order does not fit in u64") - 1;
+    let m = num::integer::sqrt(group_order) + 1;
+
+    let mut table = HashMap::new();
+    let mut baby = base.one();
+    for j in 0..m {
+        table.entry(baby.get_num()).or_insert(j);
+        baby = baby * base;
+    }
+
+    let giant = base.one() / base.pow(NumCast::from(m).unwrap());
+    let mut gamma = target;
+    for i in 0..m {
+        if let Some(j) = table.get(&gamma.get_num()) {
+            return Some(i * m + j);
+        }
+        gamma = gamma * giant;
+    }
+    None
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn powers_of_works() {
+        let x: FieldElement<u64> = FieldElement::new(3, 31);
+        let powers = powers_of_vec(x, 5);
+        assert_eq!(powers.len(), 5);
+        assert_eq!(powers[0], x.one());
+        assert_eq!(powers[4], FieldElement::new(19, 31));
+        assert_eq!(powers_of(x, 0).count(), 0);
+    }
+
+    #[test]
+    fn discrete_log_works() {
+        let g: FieldElement<u64> = FieldElement::new(5, 10007);
+        let target = g.pow(4321);
+        let k = discrete_log(g, target).unwrap();
+        assert_eq!(g.pow(k), target);
+        assert_eq!(k, 4321);
+        assert_eq!(discrete_log(g, g.one()), Some(0));
+
+        // 4 generates the squares only
+        let h: FieldElement<u64> = FieldElement::new(4, 11);
+        assert_eq!(discrete_log(h, FieldElement::new(2, 11)), None);
+    }
+
+    #[test]
+    fn geometric_sum_works() {
+        let x: FieldElement<u64> = FieldElement::new(3, 31);
+        let expected = powers_of(x, 7).fold(x.zero(), |acc, p| acc + p);
+        assert_eq!(geometric_sum(x, 7), expected);
+        assert_eq!(geometric_sum(x.one(), 40), FieldElement::new(9, 31));
+        assert_eq!(geometric_sum(x, 0), x.zero());
+        assert_eq!(geometric_sum(x.zero(), 30), x.one());
+    }
+}
@@ -0,0 +1,146 @@
+use num::PrimInt;
+
+/// Primitive integers paired with a type twice as wide, so that the product
+/// of two reduced elements is formed without overflow before reducing.
+///
+/// `u128` and `i128` have no wider type and multiply by double-and-add
+/// instead.
+pub trait Widening: PrimInt {
+    type Wide: PrimInt;
+
+    fn widen(self) -> Self::Wide;
+
+    /// Converts back a value known to fit in `Self`.
+    fn narrow(wide: Self::Wide) -> Self;
+
+    /// Returns `self * rhs mod modulus` for `0 ≤ self, rhs < modulus`.
+    /// Moduli of the form `2^k − 1` are reduced by shifts and adds
+    /// instead of a division.
+    fn mul_mod(self, rhs: Self, modulus: Self) -> Self {
+        let product = self.widen() * rhs.widen();
+        if is_mersenne(modulus) {
+            Self::narrow(fold_mersenne(product, modulus.widen()))
+        } else {
+            Self::narrow(product % modulus.widen())
+        }
+    }
+}
+
+/// Whether `m` has the form `2^k − 1`.
+pub(crate) fn is_mersenne<T: PrimInt>(m: T) -> bool {
+    m > T::zero() && m.count_zeros() == m.leading_zeros()
+}
+
+/// `x mod m` for `m = 2^k − 1` and `x < 2^(2k)`: since `2^k ≡ 1`, the bits
+/// above `k` are folded onto the low ones.
+fn fold_mersenne<T: PrimInt>(x: T, m: T) -> T {
+    let k = m.count_ones() as usize;
+    let x = (x & m) + (x >> k);
+    let x = (x & m) + (x >> k);
+    if x >= m {
+        x - m
+    } else {
+        x
+    }
+}
+
+macro_rules! impl_widening {
+    ($($t:ty => $wide:ty),*) => {
+        $(
+            impl Widening for $t {
+                type Wide = $wide;
+
+                fn widen(self) -> $wide {
+                    self as $wide
+                }
+
+                fn narrow(wide: $wide) -> Self {
+                    wide as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_widening!(
+    u8 => u16, u16 => u32, u32 => u64, u64 => u128, usize => u128,
+    i8 => i16, i16 => i32, i32 => i64, i64 => i128, isize => i128
+);
+
+/// `a + b mod m` for `0 ≤ a, b < m`, without forming `a + b`.
+fn add_mod<T: PrimInt>(a: T, b: T, m: T) -> T {
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+/// Double-and-add over the bits of `b`, for types without a wider type.
+fn shift_add_mul_mod<T: PrimInt>(a: T, b: T, m: T) -> T {
+    let bits = T::zero().count_zeros() - b.leading_zeros();
+    let mut result = T::zero();
+    for i in (0..bits).rev() {
+        result = add_mod(result, result, m);
+        if (b >> i as usize) & T::one() == T::one() {
+            result = add_mod(result, a, m);
+        }
+    }
+    result
+}
+
+macro_rules! impl_widening_shift_add {
+    ($($t:ty),*) => {
+        $(
+            impl Widening for $t {
+                type Wide = $t;
+
+                fn widen(self) -> $t {
+                    self
+                }
+
+                fn narrow(wide: $t) -> Self {
+                    wide
+                }
+
+                fn mul_mod(self, rhs: Self, modulus: Self) -> Self {
+                    shift_add_mul_mod(self, rhs, modulus)
+                }
+            }
+        )*
+    };
+}
+
+impl_widening_shift_add!(u128, i128);
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mul_mod_works() {
+        assert_eq!(250u8.mul_mod(250, 251), 1);
+        assert_eq!(100i8.mul_mod(120, 127), (100 * 120 % 127) as i8);
+        let p = 18446744073709551557u64;
+        assert_eq!((p - 1).mul_mod(p - 1, p), 1);
+        let q = u128::MAX - 158;
+        assert_eq!((q - 1).mul_mod(q - 1, q), 1);
+        assert_eq!((q - 1).mul_mod(2, q), q - 2);
+    }
+
+    #[test]
+    fn mersenne_mul_mod_works() {
+        assert!(is_mersenne(127i8) && is_mersenne(u64::MAX) && !is_mersenne(251u8) && !is_mersenne(0u8));
+        for m in [1u8, 3, 127, 255] {
+            for a in 0..m {
+                for b in [0, 1, a, m - 1] {
+                    assert_eq!(a.mul_mod(b, m) as u16, a as u16 * b as u16 % m as u16);
+                }
+            }
+        }
+        let p = (1u64 << 61) - 1;
+        assert_eq!((p - 1).mul_mod(p - 1, p), 1);
+        assert_eq!(0xdeadbeefu64.mul_mod(p - 2, p), ((p - 2) as u128 * 0xdeadbeef % p as u128) as u64);
+    }
+}